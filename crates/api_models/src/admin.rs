@@ -681,6 +681,10 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The event classes for which no webhook messages are posted
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["disputes"]))]
+    pub disabled_event_classes: Option<HashSet<api_enums::EventClass>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
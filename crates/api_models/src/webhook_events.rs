@@ -1,228 +1,529 @@
-use std::collections::HashSet;
-
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
-use masking::Secret;
+pub mod analytics;
+pub mod export;
+
+mod constraints;
+mod content;
+mod list;
+mod requests;
+mod retrieve;
+mod retry;
+mod stats;
+#[cfg(test)]
+mod test_utils;
+
+use common_enums::{EventClass, WebhookDeliveryAttempt, WebhookOutcome, WebhookRetryTrigger};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
-use utoipa::ToSchema;
 
-/// The constraints to apply when filtering events.
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
-pub struct EventListConstraints {
-    /// Filter events created after the specified time.
-    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
-    pub created_after: Option<PrimitiveDateTime>,
+pub use self::constraints::*;
+pub use self::content::*;
+pub use self::list::*;
+pub use self::requests::*;
+pub use self::retrieve::*;
+pub use self::retry::*;
+pub use self::stats::*;
 
-    /// Filter events created before the specified time.
-    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
-    pub created_before: Option<PrimitiveDateTime>,
+/// The number of events listed in a single request when `limit` is not specified.
+pub const DEFAULT_EVENT_LIST_LIMIT: u16 = 20;
 
-    /// Include at most the specified number of events.
-    pub limit: Option<u16>,
+/// The maximum number of events that can be listed in a single request.
+pub const MAX_EVENT_LIST_LIMIT: u16 = 100;
 
-    /// Include events after the specified offset.
-    pub offset: Option<u16>,
+/// The maximum offset that can be specified when listing events. Use `starting_after` or
+/// `ending_before` to paginate beyond this offset.
+pub const MAX_EVENT_LIST_OFFSET: u16 = 10000;
 
-    /// Filter all events associated with the specified object identifier (Payment Intent ID,
-    /// Refund ID, etc.)
-    pub object_id: Option<String>,
+/// The most recent part of the time window within which events are counted when the total count
+/// of events is estimated, before extrapolating the count to the entire time window.
+pub const ESTIMATED_TOTAL_COUNT_SAMPLE_WINDOW: time::Duration = time::Duration::hours(24);
 
-    /// Filter all events associated with the specified business profile ID.
-    #[schema(value_type = Option<String>)]
-    pub profile_id: Option<common_utils::id_type::ProfileId>,
+/// The maximum number of object identifiers that can be specified in `object_ids`.
+pub const MAX_EVENT_LIST_OBJECT_IDS: usize = 50;
 
-    /// Filter events by their class.
-    pub event_classes: Option<HashSet<EventClass>>,
+/// The maximum number of business profile IDs that can be specified in `profile_ids`.
+pub const MAX_EVENT_LIST_PROFILE_IDS: usize = 50;
 
-    /// Filter events by their type.
-    pub event_types: Option<HashSet<EventType>>,
-    /// Filter all events by `is_overall_delivery_successful` field of the event.
-    pub is_delivered: Option<bool>,
-}
+/// The minimum number of characters that must be specified in `object_id_contains`, so that
+/// searches are selective enough.
+pub const MIN_OBJECT_ID_CONTAINS_LENGTH: usize = 4;
 
-#[derive(Debug)]
-pub enum EventListConstraintsInternal {
-    GenericFilter {
-        created_after: Option<PrimitiveDateTime>,
-        created_before: Option<PrimitiveDateTime>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-        event_classes: Option<HashSet<EventClass>>,
-        event_types: Option<HashSet<EventType>>,
-        is_delivered: Option<bool>,
-    },
-    ObjectIdFilter {
-        object_id: String,
-    },
-}
+/// The minimum number of characters that must be specified in `error_message_contains`, so that
+/// searches are selective enough.
+pub const MIN_ERROR_MESSAGE_CONTAINS_LENGTH: usize = 4;
 
-/// The response body for each item when listing events.
-#[derive(Debug, Serialize, ToSchema)]
-pub struct EventListItemResponse {
-    /// The identifier for the Event.
-    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
-    pub event_id: String,
+/// The request header carrying the key that merchant endpoints can use to deduplicate webhooks
+/// delivered more than once.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "x-idempotency-key";
 
-    /// The identifier for the Merchant Account.
-    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
-    pub merchant_id: common_utils::id_type::MerchantId,
+/// The request header carrying the schema version of the webhook payload, which merchant
+/// endpoints can use to pick the parsing logic for the payload.
+pub const PAYLOAD_VERSION_HEADER: &str = "x-webhook-version";
 
-    /// The identifier for the Business Profile.
-    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
-    pub profile_id: common_utils::id_type::ProfileId,
+/// The width (in seconds) of the buckets the creation time of events is grouped into when
+/// computing their deduplication key.
+pub const EVENT_DEDUP_BUCKET_SECONDS: i64 = 300;
 
-    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
-    #[schema(max_length = 64, example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
-    pub object_id: String,
+/// The time (in milliseconds) within which merchant endpoints are expected to respond to the
+/// webhooks delivered to them. Deliveries taking longer than this are considered to breach the
+/// delivery SLA.
+pub const WEBHOOK_DELIVERY_SLA_MS: u32 = 30_000;
 
-    /// Specifies the type of event, which includes the object and its status.
-    pub event_type: EventType,
+/// The response headers retained when storing the response received for a webhook. Other
+/// headers, such as `set-cookie`, may carry sensitive data and are dropped.
+pub const DEFAULT_RESPONSE_HEADER_ALLOWLIST: [&str; 4] =
+    ["content-type", "content-length", "date", "server"];
 
-    /// Specifies the class of event (the type of object: Payment, Refund, etc.)
-    pub event_class: EventClass,
+/// The placeholder that masked values are replaced with, such as secret values masked by
+/// [`EventRetrieveResponseView`] and personally identifiable information in stored webhook bodies.
+pub const MASKED_VALUE_PLACEHOLDER: &str = "***";
 
-    /// Indicates whether the webhook was ultimately delivered or not.
-    pub is_delivery_successful: Option<bool>,
+/// The prefix of the identifiers of events and of their delivery attempts.
+pub const EVENT_ID_PREFIX: &str = "evt_";
 
-    /// The identifier for the initial delivery attempt. This will be the same as `event_id` for
-    /// the initial delivery attempt.
-    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
-    pub initial_attempt_id: String,
+/// The maximum length of the identifiers of events and of their delivery attempts.
+pub const EVENT_ID_MAX_LENGTH: usize = 64;
 
-    /// Time at which the event was created.
-    #[schema(example = "2022-09-10T10:11:12Z")]
-    #[serde(with = "common_utils::custom_serde::iso8601")]
-    pub created: PrimitiveDateTime,
-}
+/// The maximum number of events that can be retrieved at once.
+pub const MAX_EVENT_BATCH_RETRIEVE_IDS: usize = 20;
+
+/// The maximum number of tags that can be added to an event.
+pub const MAX_EVENT_TAGS: usize = 10;
+
+/// The maximum length of each tag added to an event.
+pub const MAX_EVENT_TAG_LENGTH: usize = 64;
+
+/// The number of seconds within which retries of an event with the same idempotency key are
+/// deduplicated.
+pub const RETRY_IDEMPOTENCY_WINDOW_SECONDS: i64 = 600;
+
+/// The maximum age of the events that can be retried, when no maximum age is specified in the
+/// retry request.
+pub const DEFAULT_RETRY_MAX_EVENT_AGE: time::Duration = time::Duration::days(3);
+
+/// The maximum number of delivery attempts of an event, including its initial delivery attempt,
+/// after which the event can no longer be retried.
+pub const MAX_EVENT_DELIVERY_ATTEMPTS: usize = 30;
+
+/// The time window over which events are listed, when none of `created_after`, `created_before`
+/// and `time_range` are specified, so that listing does not scan the entire history of events.
+pub const DEFAULT_EVENT_LIST_TIME_WINDOW: time::Duration = time::Duration::days(30);
+
+/// The identifier of an event, or of one of its delivery attempts. The identifier is validated
+/// to be at most [`EVENT_ID_MAX_LENGTH`] characters long and to start with [`EVENT_ID_PREFIX`],
+/// and is serialized as a plain string.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct EventId(String);
+
+impl EventId {
+    /// Wraps an identifier which has already been stored, without validating it. Only identifiers
+    /// provided by users are validated, so that events stored before the identifier was validated
+    /// (or with identifiers generated otherwise) can still be returned.
+    pub fn from_stored(event_id: String) -> Self {
+        Self(event_id)
+    }
 
-/// The response body of list initial delivery attempts api call.
-#[derive(Debug, Serialize, ToSchema)]
-pub struct TotalEventsResponse {
-    /// The list of events
-    pub events: Vec<EventListItemResponse>,
-    /// Count of total events
-    pub total_count: i64,
+    /// Returns the identifier as a string slice.
+    pub fn get_string_repr(&self) -> &str {
+        &self.0
+    }
 }
 
-impl TotalEventsResponse {
-    pub fn new(total_count: i64, events: Vec<EventListItemResponse>) -> Self {
-        Self {
-            events,
-            total_count,
+impl TryFrom<String> for EventId {
+    type Error = EventIdError;
+
+    fn try_from(event_id: String) -> Result<Self, Self::Error> {
+        if event_id.len() > EVENT_ID_MAX_LENGTH {
+            return Err(EventIdError::TooLong {
+                length: event_id.len(),
+                max_length: EVENT_ID_MAX_LENGTH,
+            });
         }
+        if !event_id
+            .strip_prefix(EVENT_ID_PREFIX)
+            .is_some_and(|suffix| !suffix.is_empty())
+        {
+            return Err(EventIdError::InvalidPrefix);
+        }
+
+        Ok(Self(event_id))
     }
 }
 
-impl common_utils::events::ApiEventMetric for TotalEventsResponse {
-    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
-        Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.events.first().map(|event| event.merchant_id.clone())?,
-        })
+impl TryFrom<&str> for EventId {
+    type Error = EventIdError;
+
+    fn try_from(event_id: &str) -> Result<Self, Self::Error> {
+        Self::try_from(event_id.to_owned())
     }
 }
 
-/// The response body for retrieving an event.
-#[derive(Debug, Serialize, ToSchema)]
-pub struct EventRetrieveResponse {
-    #[serde(flatten)]
-    pub event_information: EventListItemResponse,
-
-    /// The request information (headers and body) sent in the webhook.
-    pub request: OutgoingWebhookRequestContent,
+impl From<EventId> for String {
+    fn from(event_id: EventId) -> Self {
+        event_id.0
+    }
+}
 
-    /// The response information (headers, body and status code) received for the webhook sent.
-    pub response: OutgoingWebhookResponseContent,
+impl std::fmt::Display for EventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
 
-    /// Indicates the type of delivery attempt.
-    pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+/// The errors encountered when validating an [`EventId`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventIdError {
+    /// The identifier is longer than [`EVENT_ID_MAX_LENGTH`].
+    TooLong { length: usize, max_length: usize },
+    /// The identifier does not start with [`EVENT_ID_PREFIX`], or has nothing after it.
+    InvalidPrefix,
 }
 
-impl common_utils::events::ApiEventMetric for EventRetrieveResponse {
-    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
-        Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.event_information.merchant_id.clone(),
-        })
+impl std::fmt::Display for EventIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooLong { length, max_length } => write!(
+                f,
+                "event ID must be at most {max_length} characters long, but is {length} \
+                 characters long"
+            ),
+            Self::InvalidPrefix => write!(f, "event ID must start with `{EVENT_ID_PREFIX}`"),
+        }
     }
 }
 
-/// The request information (headers and body) sent in the webhook.
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct OutgoingWebhookRequestContent {
-    /// The request body sent in the webhook.
-    #[schema(value_type = String)]
-    #[serde(alias = "payload")]
-    pub body: Secret<String>,
-
-    /// The request headers sent in the webhook.
-    #[schema(
-        value_type = Vec<(String, String)>,
-        example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
-    ]
-    pub headers: Vec<(String, Secret<String>)>,
+impl std::error::Error for EventIdError {}
+
+/// Returns the duration events of the specified class are retained for, after which they are
+/// purged. Disputes and mandates are retained longer than the other classes, since they may be
+/// referred to long after the object was created.
+pub fn retention_for(event_class: EventClass) -> time::Duration {
+    match event_class {
+        EventClass::Payments | EventClass::Refunds => time::Duration::days(180),
+        EventClass::Disputes | EventClass::Mandates => time::Duration::days(365),
+        #[cfg(feature = "payouts")]
+        EventClass::Payouts => time::Duration::days(180),
+    }
 }
 
-/// The response information (headers, body and status code) received for the webhook sent.
-#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
-pub struct OutgoingWebhookResponseContent {
-    /// The response body received for the webhook sent.
-    #[schema(value_type = Option<String>)]
-    #[serde(alias = "payload")]
-    pub body: Option<Secret<String>>,
-
-    /// The response headers received for the webhook sent.
-    #[schema(
-        value_type = Option<Vec<(String, String)>>,
-        example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
-    ]
-    pub headers: Option<Vec<(String, Secret<String>)>>,
-
-    /// The HTTP status code for the webhook sent.
-    #[schema(example = 200)]
-    pub status_code: Option<u16>,
-
-    /// Error message in case any error occurred when trying to deliver the webhook.
-    #[schema(example = 200)]
-    pub error_message: Option<String>,
+/// Returns the time at which an event of the specified class created at `created` is purged, as
+/// per [`retention_for`].
+pub fn get_expires_at(
+    event_class: EventClass,
+    created: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    created.checked_add(retention_for(event_class))
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct EventListRequestInternal {
-    pub merchant_id: common_utils::id_type::MerchantId,
-    pub constraints: EventListConstraints,
+/// Returns the time (in milliseconds) from `primary_object_updated_at`, the last change of the
+/// object of an event, to `created`, the creation of the event. Returns `None` if the change time
+/// of the object is unknown, or is later than the creation of the event.
+pub fn get_event_delay_ms(
+    created: PrimitiveDateTime,
+    primary_object_updated_at: Option<PrimitiveDateTime>,
+) -> Option<u64> {
+    primary_object_updated_at.and_then(|primary_object_updated_at| {
+        u64::try_from((created - primary_object_updated_at).whole_milliseconds()).ok()
+    })
 }
 
-impl common_utils::events::ApiEventMetric for EventListRequestInternal {
-    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
-        Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.merchant_id.clone(),
-        })
-    }
+/// Returns the source that triggered a delivery attempt, preferring the source recorded for the
+/// delivery attempt. Delivery attempts recorded before their source was stored fall back to the
+/// source implied by their type, which is unknown for manual retries, since retries triggered from
+/// the dashboard and using the API cannot be told apart. Initial delivery attempts have no source.
+pub fn get_retry_trigger(
+    delivery_attempt: Option<WebhookDeliveryAttempt>,
+    triggered_by: Option<WebhookRetryTrigger>,
+) -> Option<WebhookRetryTrigger> {
+    triggered_by.or(match delivery_attempt {
+        Some(WebhookDeliveryAttempt::AutomaticRetry) => Some(WebhookRetryTrigger::Scheduler),
+        Some(WebhookDeliveryAttempt::ManualReplay) => Some(WebhookRetryTrigger::Replay),
+        Some(WebhookDeliveryAttempt::InitialAttempt | WebhookDeliveryAttempt::ManualRetry)
+        | None => None,
+    })
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct WebhookDeliveryAttemptListRequestInternal {
-    pub merchant_id: common_utils::id_type::MerchantId,
-    pub initial_attempt_id: String,
+/// The time as of which the outcome of the delivery of events is determined, along with the
+/// duration after the creation of an event within which its failed deliveries are retried
+/// automatically, as per the retry configuration of the merchant.
+#[derive(Clone, Copy, Debug)]
+pub struct WebhookOutcomeContext {
+    pub now: PrimitiveDateTime,
+    pub automatic_retry_window: time::Duration,
 }
 
-impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptListRequestInternal {
-    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
-        Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.merchant_id.clone(),
-        })
+/// Returns the outcome of the delivery of an event created at `created`, as of `context.now`.
+/// Failed initial and automatic retry attempts are considered to be pending until the automatic
+/// retry window has elapsed since the event was created, after which their retries are considered
+/// to be exhausted.
+pub fn get_webhook_outcome(
+    is_delivery_successful: Option<bool>,
+    delivery_attempt: Option<WebhookDeliveryAttempt>,
+    created: PrimitiveDateTime,
+    context: WebhookOutcomeContext,
+) -> WebhookOutcome {
+    match (is_delivery_successful, delivery_attempt) {
+        (Some(true), _) => WebhookOutcome::Delivered,
+        (None, _) => WebhookOutcome::Pending,
+        (
+            Some(false),
+            Some(WebhookDeliveryAttempt::ManualRetry | WebhookDeliveryAttempt::ManualReplay),
+        ) => WebhookOutcome::Failed,
+        (
+            Some(false),
+            Some(WebhookDeliveryAttempt::InitialAttempt | WebhookDeliveryAttempt::AutomaticRetry)
+            | None,
+        ) => {
+            if created
+                .checked_add(context.automatic_retry_window)
+                .is_some_and(|retries_exhausted_at| retries_exhausted_at <= context.now)
+            {
+                WebhookOutcome::Abandoned
+            } else {
+                WebhookOutcome::Pending
+            }
+        }
     }
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct WebhookDeliveryRetryRequestInternal {
-    pub merchant_id: common_utils::id_type::MerchantId,
-    pub event_id: String,
-}
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::test_utils::{date_time, list_item, to_event_id};
+
+    #[test]
+    fn test_event_id_validation() {
+        assert_eq!(
+            to_event_id("evt_018e31720d1b7a2b82677d3032cab959").get_string_repr(),
+            "evt_018e31720d1b7a2b82677d3032cab959"
+        );
+        assert_eq!(
+            EventId::try_from(format!("evt_{}", "a".repeat(60))).map(String::from),
+            Ok(format!("evt_{}", "a".repeat(60)))
+        );
+        assert_eq!(
+            EventId::try_from(format!("evt_{}", "a".repeat(61))),
+            Err(EventIdError::TooLong {
+                length: 65,
+                max_length: EVENT_ID_MAX_LENGTH,
+            })
+        );
+        for invalid_event_id in ["", "evt_", "evt1", "pay_018e31720d1b", "EVT_1"] {
+            assert_eq!(
+                EventId::try_from(invalid_event_id),
+                Err(EventIdError::InvalidPrefix),
+                "{invalid_event_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_event_id_serde_is_plain_string() {
+        let event_id = to_event_id("evt_1");
+        assert_eq!(serde_json::to_value(&event_id).unwrap(), "evt_1");
+        assert_eq!(
+            serde_json::from_value::<EventId>(serde_json::json!("evt_1")).unwrap(),
+            event_id
+        );
+
+        let error = serde_json::from_value::<EventId>(serde_json::json!("pay_1")).unwrap_err();
+        assert!(error.to_string().contains("must start with `evt_`"));
+    }
 
-impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInternal {
-    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
-        Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.merchant_id.clone(),
+    #[test]
+    fn test_event_delay_ms() {
+        let created = date_time(2);
+
+        assert_eq!(
+            get_event_delay_ms(created, Some(created - time::Duration::milliseconds(1500))),
+            Some(1500)
+        );
+        assert_eq!(get_event_delay_ms(created, Some(created)), Some(0));
+        assert_eq!(get_event_delay_ms(created, Some(date_time(3))), None);
+        assert_eq!(get_event_delay_ms(created, None), None);
+
+        let value = serde_json::to_value(EventListItemResponse {
+            event_delay_ms: Some(1500),
+            ..list_item()
         })
+        .unwrap();
+        assert_eq!(value.get("event_delay_ms"), Some(&serde_json::json!(1500)));
+
+        let constraints =
+            serde_json::from_str::<EventListConstraints>(r#"{"eventDelayMsGte":5000}"#).unwrap();
+        assert_eq!(constraints.event_delay_ms_gte, Some(5000));
+    }
+
+    #[test]
+    fn test_retention_by_event_class() {
+        assert_eq!(
+            retention_for(EventClass::Payments),
+            time::Duration::days(180)
+        );
+        assert_eq!(
+            retention_for(EventClass::Refunds),
+            time::Duration::days(180)
+        );
+        assert_eq!(
+            retention_for(EventClass::Disputes),
+            time::Duration::days(365)
+        );
+        assert_eq!(
+            retention_for(EventClass::Mandates),
+            time::Duration::days(365)
+        );
+
+        let created = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+        assert_eq!(
+            get_expires_at(EventClass::Payments, created),
+            Some(PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::June, 29).unwrap(),
+                time::Time::MIDNIGHT,
+            ))
+        );
+        assert_eq!(
+            get_expires_at(EventClass::Disputes, created),
+            Some(PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::December, 31).unwrap(),
+                time::Time::MIDNIGHT,
+            ))
+        );
+        assert_eq!(
+            get_expires_at(EventClass::Payments, PrimitiveDateTime::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_trigger_of_delivery_attempts() {
+        for (delivery_attempt, triggered_by, expected) in [
+            (Some(WebhookDeliveryAttempt::InitialAttempt), None, None),
+            (
+                Some(WebhookDeliveryAttempt::AutomaticRetry),
+                None,
+                Some(WebhookRetryTrigger::Scheduler),
+            ),
+            (Some(WebhookDeliveryAttempt::ManualRetry), None, None),
+            (
+                Some(WebhookDeliveryAttempt::ManualRetry),
+                Some(WebhookRetryTrigger::ManualDashboard),
+                Some(WebhookRetryTrigger::ManualDashboard),
+            ),
+            (
+                Some(WebhookDeliveryAttempt::ManualRetry),
+                Some(WebhookRetryTrigger::Api),
+                Some(WebhookRetryTrigger::Api),
+            ),
+            (
+                Some(WebhookDeliveryAttempt::ManualReplay),
+                None,
+                Some(WebhookRetryTrigger::Replay),
+            ),
+            (None, None, None),
+        ] {
+            assert_eq!(
+                get_retry_trigger(delivery_attempt, triggered_by),
+                expected,
+                "delivery_attempt: {delivery_attempt:?}, triggered_by: {triggered_by:?}"
+            );
+        }
+
+        let constraints = EventListConstraints::builder()
+            .triggered_by([WebhookRetryTrigger::ManualDashboard])
+            .build()
+            .unwrap();
+        assert!(!constraints.get_only_initial_attempts());
+    }
+
+    #[test]
+    fn test_webhook_outcome_from_delivery_state() {
+        let created = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+        let automatic_retry_window = time::Duration::days(1);
+        let within_retry_window = created + time::Duration::hours(1);
+        let after_retry_window = created + automatic_retry_window;
+
+        for (is_delivery_successful, delivery_attempt, now, expected) in [
+            (
+                Some(true),
+                Some(WebhookDeliveryAttempt::InitialAttempt),
+                after_retry_window,
+                WebhookOutcome::Delivered,
+            ),
+            (
+                Some(true),
+                Some(WebhookDeliveryAttempt::ManualRetry),
+                within_retry_window,
+                WebhookOutcome::Delivered,
+            ),
+            (
+                None,
+                Some(WebhookDeliveryAttempt::InitialAttempt),
+                after_retry_window,
+                WebhookOutcome::Pending,
+            ),
+            (
+                Some(false),
+                Some(WebhookDeliveryAttempt::InitialAttempt),
+                within_retry_window,
+                WebhookOutcome::Pending,
+            ),
+            (
+                Some(false),
+                Some(WebhookDeliveryAttempt::AutomaticRetry),
+                within_retry_window,
+                WebhookOutcome::Pending,
+            ),
+            (
+                Some(false),
+                Some(WebhookDeliveryAttempt::ManualRetry),
+                within_retry_window,
+                WebhookOutcome::Failed,
+            ),
+            (
+                Some(false),
+                Some(WebhookDeliveryAttempt::ManualReplay),
+                after_retry_window,
+                WebhookOutcome::Failed,
+            ),
+            (
+                Some(false),
+                Some(WebhookDeliveryAttempt::InitialAttempt),
+                after_retry_window,
+                WebhookOutcome::Abandoned,
+            ),
+            (
+                Some(false),
+                None,
+                after_retry_window,
+                WebhookOutcome::Abandoned,
+            ),
+        ] {
+            assert_eq!(
+                get_webhook_outcome(
+                    is_delivery_successful,
+                    delivery_attempt,
+                    created,
+                    WebhookOutcomeContext {
+                        now,
+                        automatic_retry_window,
+                    }
+                ),
+                expected,
+                "is_delivery_successful: {is_delivery_successful:?}, \
+                 delivery_attempt: {delivery_attempt:?}, now: {now}"
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_value(WebhookOutcome::Abandoned).unwrap(),
+            serde_json::json!("abandoned")
+        );
     }
 }
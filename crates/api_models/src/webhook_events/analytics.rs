@@ -0,0 +1,213 @@
+//! Aggregation of event lists, such as for charting the number of events over time or the
+//! distribution of delivery latencies.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use common_enums::EventType;
+use time::PrimitiveDateTime;
+
+use super::{EventListItemResponse, EventRetrieveResponse};
+
+/// Counts the events created within each hour, keyed by the start of the hour. The creation times
+/// of events are in UTC, so every hour is exactly an hour long, without any daylight saving time
+/// transitions. Hours without any events are not included.
+pub fn bucket_by_hour(events: &[EventListItemResponse]) -> BTreeMap<PrimitiveDateTime, usize> {
+    bucket_by(events, |created| {
+        created.replace_time(time::Time::MIDNIGHT) + time::Duration::hours(created.hour().into())
+    })
+}
+
+/// Counts the events created within each UTC calendar day, keyed by the start of the day. Days
+/// without any events are not included.
+pub fn bucket_by_day(events: &[EventListItemResponse]) -> BTreeMap<PrimitiveDateTime, usize> {
+    bucket_by(events, |created| created.replace_time(time::Time::MIDNIGHT))
+}
+
+/// Counts the delivery attempts into latency buckets, using the recorded `response_latency_ms` of
+/// each attempt. `buckets_ms` are the inclusive upper bounds of the buckets, and each attempt is
+/// counted in the smallest bucket its latency fits in. Latencies above the largest bound are
+/// counted in a final bucket with the upper bound [`u32::MAX`], which is always included.
+///
+/// The histogram is returned along with the number of attempts without a recorded latency, such
+/// as attempts which are yet to receive a response.
+pub fn latency_histogram(
+    events: &[EventRetrieveResponse],
+    buckets_ms: &[u32],
+) -> (Vec<(u32, usize)>, usize) {
+    let mut upper_bounds = buckets_ms.to_vec();
+    upper_bounds.push(u32::MAX);
+    upper_bounds.sort_unstable();
+    upper_bounds.dedup();
+
+    let mut histogram = upper_bounds
+        .into_iter()
+        .map(|upper_bound| (upper_bound, 0))
+        .collect::<Vec<_>>();
+    let mut unknown = 0;
+    for event in events {
+        match event.response.response_latency_ms {
+            Some(latency_ms) => {
+                if let Some((_, count)) = histogram
+                    .iter_mut()
+                    .find(|(upper_bound, _)| latency_ms <= *upper_bound)
+                {
+                    *count += 1;
+                }
+            }
+            None => unknown += 1,
+        }
+    }
+
+    (histogram, unknown)
+}
+
+/// Returns the distinct types of the events listed for the object with the specified identifier,
+/// such as to check whether a payment has had a `payment_succeeded` webhook fired yet. Events of
+/// other objects in the listing are ignored, and retries of the same event are counted once.
+pub fn event_types_for_object(
+    events: &[EventListItemResponse],
+    object_id: &str,
+) -> BTreeSet<EventType> {
+    events
+        .iter()
+        .filter(|event| event.object_id == object_id)
+        .map(|event| event.event_type)
+        .collect()
+}
+
+fn bucket_by(
+    events: &[EventListItemResponse],
+    bucket_start: impl Fn(PrimitiveDateTime) -> PrimitiveDateTime,
+) -> BTreeMap<PrimitiveDateTime, usize> {
+    events.iter().fold(BTreeMap::new(), |mut buckets, event| {
+        *buckets.entry(bucket_start(event.created)).or_default() += 1;
+        buckets
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::{test_utils, OutgoingWebhookResponseContent};
+
+    fn date_time(day: u8, hour: u8, minute: u8, second: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+            time::Time::from_hms(hour, minute, second).unwrap(),
+        )
+    }
+
+    fn list_item(event_id: &str, created: PrimitiveDateTime) -> EventListItemResponse {
+        EventListItemResponse {
+            event_id: test_utils::to_event_id(event_id),
+            initial_attempt_id: test_utils::to_event_id(event_id),
+            created,
+            ..test_utils::list_item()
+        }
+    }
+
+    fn delivery_attempt(event_id: &str, response_latency_ms: Option<u32>) -> EventRetrieveResponse {
+        let delivery_attempt = test_utils::delivery_attempt(event_id, 1);
+        EventRetrieveResponse {
+            response: OutgoingWebhookResponseContent {
+                status_code: response_latency_ms.map(|_| 200),
+                response_latency_ms,
+                ..delivery_attempt.response
+            },
+            ..delivery_attempt
+        }
+    }
+
+    #[test]
+    fn test_latency_histogram_buckets() {
+        let events = vec![
+            delivery_attempt("evt_1", Some(50)),
+            delivery_attempt("evt_2", Some(100)),
+            delivery_attempt("evt_3", Some(101)),
+            delivery_attempt("evt_4", Some(750)),
+            delivery_attempt("evt_5", Some(45_000)),
+            delivery_attempt("evt_6", None),
+            delivery_attempt("evt_7", None),
+        ];
+
+        let (histogram, unknown) = latency_histogram(&events, &[1000, 100, 500]);
+        assert_eq!(
+            histogram,
+            vec![(100, 2), (500, 1), (1000, 1), (u32::MAX, 1)]
+        );
+        assert_eq!(unknown, 2);
+    }
+
+    #[test]
+    fn test_latency_histogram_without_events() {
+        let (histogram, unknown) = latency_histogram(&[], &[100]);
+        assert_eq!(histogram, vec![(100, 0), (u32::MAX, 0)]);
+        assert_eq!(unknown, 0);
+    }
+
+    #[test]
+    fn test_bucket_by_hour_across_hour_boundary() {
+        let events = vec![
+            list_item("evt_1", date_time(1, 9, 15, 0)),
+            list_item("evt_2", date_time(1, 9, 59, 59)),
+            list_item("evt_3", date_time(1, 10, 0, 0)),
+            list_item("evt_4", date_time(1, 10, 45, 0)),
+            list_item("evt_5", date_time(1, 12, 30, 0)),
+        ];
+
+        assert_eq!(
+            bucket_by_hour(&events),
+            BTreeMap::from([
+                (date_time(1, 9, 0, 0), 2),
+                (date_time(1, 10, 0, 0), 2),
+                (date_time(1, 12, 0, 0), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_bucket_by_day_across_day_boundary() {
+        let events = vec![
+            list_item("evt_1", date_time(1, 23, 59, 59)),
+            list_item("evt_2", date_time(2, 0, 0, 0)),
+            list_item("evt_3", date_time(2, 18, 0, 0)),
+        ];
+
+        let expected_buckets =
+            BTreeMap::from([(date_time(1, 0, 0, 0), 1), (date_time(2, 0, 0, 0), 2)]);
+        assert_eq!(bucket_by_day(&events), expected_buckets);
+        assert!(bucket_by_day(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_event_types_for_object() {
+        let event =
+            |event_id: &str, object_id: &str, event_type: EventType| EventListItemResponse {
+                object_id: object_id.to_string(),
+                event_type,
+                ..list_item(event_id, date_time(1, 9, 0, 0))
+            };
+        let events = vec![
+            event("evt_1", "pay_1", EventType::PaymentProcessing),
+            event("evt_2", "pay_1", EventType::PaymentSucceeded),
+            event("evt_3", "pay_1", EventType::PaymentSucceeded),
+            event("evt_4", "pay_2", EventType::PaymentFailed),
+            event("evt_5", "pay_1", EventType::DisputeOpened),
+        ];
+
+        assert_eq!(
+            event_types_for_object(&events, "pay_1"),
+            BTreeSet::from([
+                EventType::PaymentSucceeded,
+                EventType::PaymentProcessing,
+                EventType::DisputeOpened,
+            ])
+        );
+        assert_eq!(
+            event_types_for_object(&events, "pay_2"),
+            BTreeSet::from([EventType::PaymentFailed])
+        );
+        assert!(event_types_for_object(&events, "pay_3").is_empty());
+    }
+}
@@ -0,0 +1,2137 @@
+//! The constraints for filtering events, along with their validation and their encoding into
+//! URL query strings.
+
+use std::collections::{HashMap, HashSet};
+
+use common_enums::{
+    CountMode, EventClass, EventResponseMode, EventSortBy, EventTimeRangePreset, EventType,
+    WebhookDeliveryAttempt, WebhookDeliveryErrorCategory, WebhookDeliveryState,
+    WebhookRetryTrigger,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use super::{
+    DEFAULT_EVENT_LIST_LIMIT, DEFAULT_EVENT_LIST_TIME_WINDOW, MAX_EVENT_LIST_LIMIT,
+    MAX_EVENT_LIST_OBJECT_IDS, MAX_EVENT_LIST_OFFSET, MAX_EVENT_LIST_PROFILE_IDS,
+    MIN_ERROR_MESSAGE_CONTAINS_LENGTH, MIN_OBJECT_ID_CONTAINS_LENGTH,
+};
+
+/// The constraints that can be specified along with `orphaned` when listing events.
+const ORPHANED_COMBINABLE_CONSTRAINTS: &[&str] = &[
+    "orphaned",
+    "created_after",
+    "created_before",
+    "time_range",
+    "limit",
+    "starting_after",
+    "sort_by",
+    "response_mode",
+    "count_mode",
+    "dropped_filters",
+];
+
+/// The multi-valued fields of [`EventListConstraints`], which are encoded as repeated keys in query
+/// strings.
+const EVENT_LIST_REPEATED_QUERY_KEYS: [&str; 11] = [
+    "object_ids",
+    "profile_ids",
+    "delivery_attempt",
+    "event_classes",
+    "event_types",
+    "delivery_state",
+    "error_category",
+    "created_hour_of_day",
+    "created_day_of_week",
+    "tags",
+    "triggered_by",
+];
+
+/// The constraints to apply when filtering events.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(remote = "Self")]
+pub struct EventListConstraints {
+    /// Filter events created after the specified time. When none of `created_after`,
+    /// `created_before` and `time_range` are specified, only the events created within the past
+    /// 30 days are listed.
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        alias = "createdAfter"
+    )]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter events created before the specified time.
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        alias = "createdBefore"
+    )]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Filter events that expire, and are purged, before the specified time. The expiry of an
+    /// event is determined by the retention duration of its class.
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        alias = "expiringBefore"
+    )]
+    pub expiring_before: Option<PrimitiveDateTime>,
+
+    /// Filter events persisted after the specified time. Unlike `created_after`, this filters by
+    /// the time the event was persisted, which helps detect ingestion lag. Events persisted before
+    /// the ingestion time was recorded are excluded.
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        alias = "ingestedAfter"
+    )]
+    pub ingested_after: Option<PrimitiveDateTime>,
+
+    /// Filter events persisted before the specified time. Events persisted before the ingestion
+    /// time was recorded are excluded.
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        alias = "ingestedBefore"
+    )]
+    pub ingested_before: Option<PrimitiveDateTime>,
+
+    /// Filter events created at least the specified number of milliseconds after their object was
+    /// last changed, which surfaces slow event generation separately from slow delivery. Events
+    /// whose object change time was not recorded are excluded.
+    #[schema(example = 5000)]
+    #[serde(alias = "eventDelayMsGte")]
+    pub event_delay_ms_gte: Option<u64>,
+
+    /// Filter events created within the specified time range, ending at the current time unless
+    /// `created_before` is specified. Cannot be used along with `created_after`.
+    #[serde(alias = "timeRange")]
+    pub time_range: Option<EventTimeRangePreset>,
+
+    /// Filter events created during any of the specified hours of the day (UTC), each ranging from
+    /// 0 to 23.
+    #[schema(value_type = Option<Vec<u8>>, example = json!([9, 10, 11]))]
+    #[serde(alias = "createdHourOfDay")]
+    pub created_hour_of_day: Option<HashSet<u8>>,
+
+    /// Filter events created on any of the specified days of the week (UTC), each ranging from 0
+    /// (Sunday) to 6 (Saturday).
+    #[schema(value_type = Option<Vec<u8>>, example = json!([1, 2, 3, 4, 5]))]
+    #[serde(alias = "createdDayOfWeek")]
+    pub created_day_of_week: Option<HashSet<u8>>,
+
+    /// Filter events tagged with any of the specified tags.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["under-investigation"]))]
+    pub tags: Option<HashSet<String>>,
+
+    /// Include at most the specified number of events. Defaults to 20 events when not specified,
+    /// and is clamped to 100 events if a larger value is specified.
+    pub limit: Option<u16>,
+
+    /// Include events after the specified offset.
+    pub offset: Option<u16>,
+
+    /// A cursor for use in pagination. `starting_after` is the `event_id` of an event that
+    /// defines your place in the list. The events listed will be the ones that follow this event
+    /// in the list. Cannot be used along with `offset`.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    #[serde(alias = "startingAfter")]
+    pub starting_after: Option<String>,
+
+    /// A cursor for use in pagination. `ending_before` is the `event_id` of an event that defines
+    /// your place in the list. The events listed will be the ones that precede this event in the
+    /// list. Cannot be used along with `offset`.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    #[serde(alias = "endingBefore")]
+    pub ending_before: Option<String>,
+
+    /// Filter all events associated with the specified object identifier (Payment Intent ID,
+    /// Refund ID, etc.)
+    #[serde(alias = "objectId")]
+    pub object_id: Option<String>,
+
+    /// Filter all events associated with any of the specified object identifiers (Payment Intent
+    /// IDs, Refund IDs, etc.) At most 50 object identifiers can be specified. Cannot be used along
+    /// with `object_id`.
+    #[schema(example = json!(["pay_mbabizu24mvu3mela5njyhpit4", "pay_ghuj6vi4nntdlzzmzcuqxcpvdi"]))]
+    #[serde(alias = "objectIds")]
+    pub object_ids: Option<Vec<String>>,
+
+    /// Filter all events whose object identifier contains the specified substring, for finding
+    /// events when only part of the object identifier is known. Must be at least 4 characters
+    /// long. Cannot be used along with `object_id` or `object_ids`. The matching events are
+    /// listed a page at a time using `limit` and `offset`, within the time range specified by
+    /// `created_after` and `created_before`, or the default time window otherwise.
+    #[schema(min_length = 4, example = "mbabizu24")]
+    #[serde(alias = "objectIdContains")]
+    pub object_id_contains: Option<String>,
+
+    /// Filter all delivery attempts sharing the specified initial delivery attempt, which includes
+    /// the initial attempt and all of its retries. Cannot be used along with `object_id`.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    #[serde(alias = "initialAttemptId")]
+    pub initial_attempt_id: Option<String>,
+
+    /// Look up the event with the specified identifier. When specified, all the other constraints
+    /// are ignored, and at most one event is listed.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    #[serde(alias = "eventId")]
+    pub event_id: Option<String>,
+
+    /// Filter all events associated with the specified business profile ID.
+    #[schema(value_type = Option<String>)]
+    #[serde(alias = "profileId")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Filter all events associated with any of the specified business profile IDs. Cannot be
+    /// specified along with `profile_id`.
+    #[schema(value_type = Option<Vec<String>>)]
+    #[serde(alias = "profileIds")]
+    pub profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+
+    /// Filter all events associated with the specified merchant connector account ID. Events that
+    /// are not associated with a merchant connector account are excluded.
+    #[schema(value_type = Option<String>, example = "mca_sAD3OZLATetvjLOYhUSy")]
+    #[serde(alias = "merchantConnectorId")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// Filter all events associated with the specified connector. Events that are not associated
+    /// with a connector are excluded.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// Filter delivery attempts by their type. Only initial delivery attempts are listed when not
+    /// specified, while retries of the specified types are included when specified.
+    #[serde(alias = "deliveryAttempt")]
+    pub delivery_attempt: Option<HashSet<WebhookDeliveryAttempt>>,
+
+    /// Filter retries and replays by the source that triggered them, such as to audit the retries
+    /// triggered by operators. Initial delivery attempts, and manual retries recorded before their
+    /// source was stored, are excluded.
+    #[serde(alias = "triggeredBy")]
+    pub triggered_by: Option<HashSet<WebhookRetryTrigger>>,
+
+    /// List only initial delivery attempts, excluding retries. Defaults to `true` when neither
+    /// `delivery_attempt` nor `triggered_by` is specified, and to `false` otherwise.
+    #[serde(alias = "onlyInitialAttempts")]
+    pub only_initial_attempts: Option<bool>,
+
+    /// Filter events by their class. Can also be used along with `object_id`, to list only the
+    /// events of the specified classes associated with the object.
+    #[serde(alias = "eventClasses")]
+    pub event_classes: Option<HashSet<EventClass>>,
+
+    /// Filter events by their type.
+    #[serde(alias = "eventTypes")]
+    pub event_types: Option<HashSet<EventType>>,
+    /// Filter all events by `is_overall_delivery_successful` field of the event.
+    #[serde(alias = "isDelivered")]
+    pub is_delivered: Option<bool>,
+
+    /// Filter events whose delivery chain has no successful delivery attempt. Unlike
+    /// `is_delivered: false`, events with a later successful retry are excluded.
+    #[serde(alias = "neverDelivered")]
+    pub never_delivered: Option<bool>,
+
+    /// Filter events whose delivery chain has at least the specified number of delivery
+    /// attempts, including the initial attempt. This surfaces endpoints which need several retries
+    /// before accepting webhooks, even if the delivery eventually succeeds.
+    #[schema(example = 2)]
+    #[serde(alias = "minAttempts")]
+    pub min_attempts: Option<u16>,
+
+    /// Filter events by the state of their delivery. When specified along with `is_delivered`,
+    /// only events matching both the filters are included.
+    #[serde(alias = "deliveryState")]
+    pub delivery_state: Option<HashSet<WebhookDeliveryState>>,
+
+    /// Filter events whose delivery attempt received a response with an HTTP status code greater
+    /// than or equal to the specified value. Events that did not receive a response are excluded.
+    #[schema(example = 500)]
+    #[serde(alias = "responseStatusCodeGte")]
+    pub response_status_code_gte: Option<u16>,
+
+    /// Filter events whose delivery attempt received a response with an HTTP status code less
+    /// than or equal to the specified value. Events that did not receive a response are excluded.
+    #[schema(example = 599)]
+    #[serde(alias = "responseStatusCodeLte")]
+    pub response_status_code_lte: Option<u16>,
+
+    /// Filter events whose delivery attempt took at least the specified number of milliseconds
+    /// to receive a response. Events without a recorded latency are excluded.
+    #[schema(example = 1000)]
+    #[serde(alias = "latencyMsGte")]
+    pub latency_ms_gte: Option<u32>,
+
+    /// Filter events by whether their delivery attempt took longer than
+    /// [`WEBHOOK_DELIVERY_SLA_MS`](super::WEBHOOK_DELIVERY_SLA_MS) to receive a response. Events
+    /// without a recorded latency are excluded.
+    #[serde(alias = "slaBreached")]
+    pub sla_breached: Option<bool>,
+
+    /// Filter events whose delivery attempt received a response body of at least the specified
+    /// number of bytes. Events without a response body are excluded.
+    #[schema(example = 1048576)]
+    #[serde(alias = "responseBodySizeGte")]
+    pub response_body_size_gte: Option<usize>,
+
+    /// Filter events whose delivery attempt failed with one of the specified classes of errors.
+    #[serde(alias = "errorCategory")]
+    pub error_category: Option<HashSet<WebhookDeliveryErrorCategory>>,
+
+    /// Filter events by whether an error message was recorded for their delivery attempt. Unlike
+    /// the status code filters, this includes failures that never received a response, such as
+    /// timeouts. Events that have not been attempted yet are excluded.
+    #[serde(alias = "hasError")]
+    pub has_error: Option<bool>,
+
+    /// Filter events whose delivery attempt recorded an error message containing the specified
+    /// substring, ignoring case, such as to find all deliveries that failed due to an expired
+    /// certificate. Must be at least 4 characters long.
+    #[schema(min_length = 4, example = "certificate expired")]
+    #[serde(alias = "errorMessageContains")]
+    pub error_message_contains: Option<String>,
+
+    /// Filter events by whether an HTTP response was received for their delivery attempt. This
+    /// separates transport failures, such as connection failures, from deliveries rejected by the
+    /// endpoint. Events that have not been attempted yet are excluded.
+    #[serde(alias = "responseReceived")]
+    pub response_received: Option<bool>,
+
+    /// Filter events whose delivery attempt received a response with the specified media type in
+    /// its `content-type` header, ignoring case and any parameters such as `charset`. This
+    /// surfaces endpoints returning error pages, such as `text/html`, instead of acknowledging
+    /// the webhook. Events without a recorded `content-type` are excluded.
+    #[schema(example = "text/html")]
+    #[serde(alias = "responseContentType")]
+    pub response_content_type: Option<String>,
+
+    /// Filter events by the schema version of their webhook payload, such as to find the events
+    /// still sent with an older version during a migration.
+    #[serde(alias = "payloadVersion")]
+    pub payload_version: Option<String>,
+
+    /// Include the number of events of each class, counted using the same filters as
+    /// `total_count`, in the response. This requires an additional count per event class, and
+    /// is therefore excluded by default.
+    #[serde(alias = "includeBreakdown")]
+    pub include_breakdown: Option<bool>,
+
+    /// The shape of each event included in the response. Defaults to `full`, while `compact`
+    /// includes only the fields required to identify each event and its delivery status.
+    #[serde(alias = "responseMode")]
+    pub response_mode: Option<EventResponseMode>,
+
+    /// How `total_count` is computed. Defaults to `exact`, while `estimated` extrapolates the
+    /// count of events within the most recent part of the time window, and `none` skips counting,
+    /// which trades the accuracy of `total_count` for speed when listing from large tables.
+    #[serde(alias = "countMode")]
+    pub count_mode: Option<CountMode>,
+
+    /// The order in which the events are sorted. Defaults to `created_desc`, listing the most
+    /// recent events first.
+    #[serde(alias = "sortBy")]
+    pub sort_by: Option<EventSortBy>,
+
+    /// A diagnostic filter listing only the events associated with a business profile which does
+    /// not belong to the merchant, which should normally return no events. This can only be
+    /// combined with `created_after`, `created_before`, `time_range`, `limit`, `starting_after`,
+    /// `sort_by`, `response_mode` and `count_mode`. Specifying `false` is the same as not
+    /// specifying it.
+    pub orphaned: Option<bool>,
+
+    /// Notes describing the filters dropped when the constraints were normalized, such as the
+    /// `event_types` that do not belong to any of the specified `event_classes`. This is recorded
+    /// when the constraints are deserialized, and cannot be specified in requests.
+    #[schema(example = json!(["event_types: refund_failed"]))]
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub dropped_filters: Option<Vec<String>>,
+}
+
+// The constraints are normalized as soon as they are deserialized, so that inconsistent filters
+// are handled the same way irrespective of whether they are obtained from a request body or a
+// query string.
+impl<'de> Deserialize<'de> for EventListConstraints {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize(deserializer).map(Self::normalize_event_types)
+    }
+}
+
+impl Serialize for EventListConstraints {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// The errors encountered when validating [`EventListConstraints`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventListConstraintsError {
+    /// `created_after` is later than `created_before`.
+    InvalidTimeRange {
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+    },
+    /// `ingested_after` is later than `ingested_before`.
+    InvalidIngestedTimeRange {
+        ingested_after: PrimitiveDateTime,
+        ingested_before: PrimitiveDateTime,
+    },
+    /// `response_status_code_gte` is greater than `response_status_code_lte`.
+    InvalidResponseStatusCodeRange {
+        response_status_code_gte: u16,
+        response_status_code_lte: u16,
+    },
+    /// `offset` exceeds [`MAX_EVENT_LIST_OFFSET`].
+    OffsetTooLarge { offset: u16, max_offset: u16 },
+    /// `offset` is specified along with `starting_after` or `ending_before`.
+    OffsetWithCursor,
+    /// More object identifiers than [`MAX_EVENT_LIST_OBJECT_IDS`] are specified in `object_ids`.
+    TooManyObjectIds { count: usize, max_count: usize },
+    /// More business profile IDs than [`MAX_EVENT_LIST_PROFILE_IDS`] are specified in
+    /// `profile_ids`.
+    TooManyProfileIds { count: usize, max_count: usize },
+    /// Both `profile_id` and `profile_ids` are specified.
+    ProfileIdWithProfileIds,
+    /// Fewer characters than [`MIN_OBJECT_ID_CONTAINS_LENGTH`] are specified in
+    /// `object_id_contains`.
+    ObjectIdContainsTooShort { length: usize, min_length: usize },
+    /// Fewer characters than [`MIN_ERROR_MESSAGE_CONTAINS_LENGTH`] are specified in
+    /// `error_message_contains`.
+    ErrorMessageContainsTooShort { length: usize, min_length: usize },
+    /// None of the event types specified in `event_types` belong to any of the event classes
+    /// specified in `event_classes`.
+    EventTypesOutsideEventClasses,
+    /// Both `time_range` and `created_after` are specified.
+    TimeRangeWithCreatedAfter,
+    /// An hour greater than 23 is specified in `created_hour_of_day`.
+    InvalidCreatedHourOfDay { hour_of_day: u8 },
+    /// A day greater than 6 is specified in `created_day_of_week`.
+    InvalidCreatedDayOfWeek { day_of_week: u8 },
+    /// `profile_id` differs from the profile the events are being listed for.
+    ProfileIdMismatch {
+        profile_id: common_utils::id_type::ProfileId,
+        expected_profile_id: common_utils::id_type::ProfileId,
+    },
+}
+
+impl std::fmt::Display for EventListConstraintsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTimeRange { .. } => write!(
+                f,
+                "The `created_after` timestamp must be an earlier timestamp compared to the \
+                 `created_before` timestamp"
+            ),
+            Self::InvalidIngestedTimeRange { .. } => write!(
+                f,
+                "The `ingested_after` timestamp must be an earlier timestamp compared to the \
+                 `ingested_before` timestamp"
+            ),
+            Self::InvalidResponseStatusCodeRange { .. } => write!(
+                f,
+                "`response_status_code_gte` must be less than or equal to \
+                 `response_status_code_lte`"
+            ),
+            Self::TooManyObjectIds { max_count, .. } => {
+                write!(
+                    f,
+                    "At most {max_count} object identifiers can be specified in `object_ids`"
+                )
+            }
+            Self::TooManyProfileIds { max_count, .. } => {
+                write!(
+                    f,
+                    "At most {max_count} business profile IDs can be specified in `profile_ids`"
+                )
+            }
+            Self::ProfileIdWithProfileIds => write!(
+                f,
+                "Only one of `profile_id` and `profile_ids` can be specified"
+            ),
+            Self::ObjectIdContainsTooShort { min_length, .. } => {
+                write!(
+                    f,
+                    "`object_id_contains` must be at least {min_length} characters long"
+                )
+            }
+            Self::ErrorMessageContainsTooShort { min_length, .. } => {
+                write!(
+                    f,
+                    "`error_message_contains` must be at least {min_length} characters long"
+                )
+            }
+            Self::OffsetTooLarge { max_offset, .. } => {
+                write!(
+                    f,
+                    "`offset` must be a number less than or equal to {max_offset}"
+                )
+            }
+            Self::OffsetWithCursor => write!(
+                f,
+                "`offset` cannot be specified along with `starting_after` or `ending_before`"
+            ),
+            Self::EventTypesOutsideEventClasses => write!(
+                f,
+                "At least one of the specified `event_types` must belong to one of the specified \
+                 `event_classes`"
+            ),
+            Self::TimeRangeWithCreatedAfter => write!(
+                f,
+                "Only one of `time_range` and `created_after` can be specified"
+            ),
+            Self::InvalidCreatedHourOfDay { hour_of_day } => write!(
+                f,
+                "`created_hour_of_day` must only contain hours from 0 to 23, found {hour_of_day}"
+            ),
+            Self::InvalidCreatedDayOfWeek { day_of_week } => write!(
+                f,
+                "`created_day_of_week` must only contain days from 0 to 6, found {day_of_week}"
+            ),
+            Self::ProfileIdMismatch { .. } => write!(
+                f,
+                "`profile_id` must match the profile the events are being listed for"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EventListConstraintsError {}
+
+/// The errors encountered when encoding [`EventListConstraints`] into, or decoding them from, a
+/// URL query string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventListQueryStringError {
+    /// The constraints could not be encoded into a query string.
+    Encode(String),
+    /// The query string could not be decoded into constraints.
+    Decode(String),
+}
+
+impl EventListQueryStringError {
+    fn encode(error: impl std::fmt::Display) -> Self {
+        Self::Encode(error.to_string())
+    }
+
+    fn decode(error: impl std::fmt::Display) -> Self {
+        Self::Decode(error.to_string())
+    }
+}
+
+impl std::fmt::Display for EventListQueryStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Encode(message) => write!(f, "Failed to encode the constraints: {message}"),
+            Self::Decode(message) => write!(f, "Failed to decode the constraints: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for EventListQueryStringError {}
+
+impl EventListConstraints {
+    /// Validates the constraints before they are used to construct the query. The constraints
+    /// are rejected unless:
+    ///
+    /// - `time_range` is not combined with `created_after`.
+    /// - The `created`, `ingested` and response status code ranges are ordered, with the lower
+    ///   bound not exceeding the upper bound.
+    /// - `offset` is at most [`MAX_EVENT_LIST_OFFSET`] and is not combined with `starting_after`
+    ///   or `ending_before`.
+    /// - `object_ids` contains at most [`MAX_EVENT_LIST_OBJECT_IDS`] object identifiers.
+    /// - `profile_ids` is not combined with `profile_id` and contains at most
+    ///   [`MAX_EVENT_LIST_PROFILE_IDS`] business profile IDs.
+    /// - `object_id_contains` is at least [`MIN_OBJECT_ID_CONTAINS_LENGTH`] characters long.
+    /// - `error_message_contains` is at least [`MIN_ERROR_MESSAGE_CONTAINS_LENGTH`] characters
+    ///   long.
+    /// - When both `event_types` and `event_classes` are specified, at least one of the event
+    ///   types belongs to one of the event classes.
+    /// - `created_hour_of_day` only contains hours from 0 to 23, and `created_day_of_week` only
+    ///   contains days from 0 to 6.
+    pub fn validate(&self) -> Result<(), EventListConstraintsError> {
+        if self.time_range.is_some() && self.created_after.is_some() {
+            return Err(EventListConstraintsError::TimeRangeWithCreatedAfter);
+        }
+
+        if let Some((created_after, created_before)) = self.created_after.zip(self.created_before) {
+            if created_after > created_before {
+                return Err(EventListConstraintsError::InvalidTimeRange {
+                    created_after,
+                    created_before,
+                });
+            }
+        }
+
+        if let Some((ingested_after, ingested_before)) =
+            self.ingested_after.zip(self.ingested_before)
+        {
+            if ingested_after > ingested_before {
+                return Err(EventListConstraintsError::InvalidIngestedTimeRange {
+                    ingested_after,
+                    ingested_before,
+                });
+            }
+        }
+
+        if let Some((response_status_code_gte, response_status_code_lte)) = self
+            .response_status_code_gte
+            .zip(self.response_status_code_lte)
+        {
+            if response_status_code_gte > response_status_code_lte {
+                return Err(EventListConstraintsError::InvalidResponseStatusCodeRange {
+                    response_status_code_gte,
+                    response_status_code_lte,
+                });
+            }
+        }
+
+        if let Some(offset) = self.offset.filter(|offset| *offset > MAX_EVENT_LIST_OFFSET) {
+            return Err(EventListConstraintsError::OffsetTooLarge {
+                offset,
+                max_offset: MAX_EVENT_LIST_OFFSET,
+            });
+        }
+
+        if self.offset.is_some() && (self.starting_after.is_some() || self.ending_before.is_some())
+        {
+            return Err(EventListConstraintsError::OffsetWithCursor);
+        }
+
+        if let Some(count) = self
+            .object_ids
+            .as_ref()
+            .map(Vec::len)
+            .filter(|count| *count > MAX_EVENT_LIST_OBJECT_IDS)
+        {
+            return Err(EventListConstraintsError::TooManyObjectIds {
+                count,
+                max_count: MAX_EVENT_LIST_OBJECT_IDS,
+            });
+        }
+
+        if self.profile_id.is_some() && self.profile_ids.is_some() {
+            return Err(EventListConstraintsError::ProfileIdWithProfileIds);
+        }
+
+        if let Some(count) = self
+            .profile_ids
+            .as_ref()
+            .map(HashSet::len)
+            .filter(|count| *count > MAX_EVENT_LIST_PROFILE_IDS)
+        {
+            return Err(EventListConstraintsError::TooManyProfileIds {
+                count,
+                max_count: MAX_EVENT_LIST_PROFILE_IDS,
+            });
+        }
+
+        if let Some(length) = self
+            .object_id_contains
+            .as_ref()
+            .map(|object_id_contains| object_id_contains.chars().count())
+            .filter(|length| *length < MIN_OBJECT_ID_CONTAINS_LENGTH)
+        {
+            return Err(EventListConstraintsError::ObjectIdContainsTooShort {
+                length,
+                min_length: MIN_OBJECT_ID_CONTAINS_LENGTH,
+            });
+        }
+
+        if let Some(length) = self
+            .error_message_contains
+            .as_ref()
+            .map(|error_message_contains| error_message_contains.chars().count())
+            .filter(|length| *length < MIN_ERROR_MESSAGE_CONTAINS_LENGTH)
+        {
+            return Err(EventListConstraintsError::ErrorMessageContainsTooShort {
+                length,
+                min_length: MIN_ERROR_MESSAGE_CONTAINS_LENGTH,
+            });
+        }
+
+        if let Some((event_classes, event_types)) = self
+            .event_classes
+            .as_ref()
+            .zip(self.event_types.as_ref())
+            .filter(|(event_classes, event_types)| {
+                !event_classes.is_empty() && !event_types.is_empty()
+            })
+        {
+            if !event_types
+                .iter()
+                .any(|event_type| event_classes.contains(&event_type.event_class()))
+            {
+                return Err(EventListConstraintsError::EventTypesOutsideEventClasses);
+            }
+        }
+
+        if let Some(hour_of_day) = self
+            .created_hour_of_day
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|hour_of_day| *hour_of_day > 23)
+            .min()
+        {
+            return Err(EventListConstraintsError::InvalidCreatedHourOfDay { hour_of_day });
+        }
+
+        if let Some(day_of_week) = self
+            .created_day_of_week
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|day_of_week| *day_of_week > 6)
+            .min()
+        {
+            return Err(EventListConstraintsError::InvalidCreatedDayOfWeek { day_of_week });
+        }
+
+        Ok(())
+    }
+
+    /// Drops the `event_types` that do not belong to any of the specified `event_classes`,
+    /// recording a note for each dropped event type in `dropped_filters`. The event types are left
+    /// unchanged if none of them belong to the event classes, since dropping all of them would
+    /// widen the filter to all event types; [`Self::validate`] rejects such constraints instead.
+    pub fn normalize_event_types(mut self) -> Self {
+        let (Some(event_classes), Some(event_types)) = (&self.event_classes, &self.event_types)
+        else {
+            return self;
+        };
+        if event_classes.is_empty() {
+            return self;
+        }
+
+        let (retained_event_types, dropped_event_types): (HashSet<_>, HashSet<_>) = event_types
+            .iter()
+            .copied()
+            .partition(|event_type| event_classes.contains(&event_type.event_class()));
+        if retained_event_types.is_empty() || dropped_event_types.is_empty() {
+            return self;
+        }
+
+        let mut dropped_filters = dropped_event_types
+            .into_iter()
+            .map(|event_type| format!("event_types: {event_type}"))
+            .collect::<Vec<_>>();
+        dropped_filters.sort();
+        self.event_types = Some(retained_event_types);
+        self.dropped_filters
+            .get_or_insert_with(Vec::new)
+            .extend(dropped_filters);
+
+        self
+    }
+
+    /// Returns the constraints for the next window of events when scanning events in the order
+    /// they were created, with `created_after` advanced to `last_created`, the creation time of
+    /// the last event listed. All other filters are preserved, while `time_range` and the
+    /// pagination constraints (`offset`, `starting_after` and `ending_before`) are cleared, since
+    /// they apply to the previous window. `created_after` is inclusive, so the events created at
+    /// `last_created` are listed again in the next window. This is meant to be used with
+    /// constraints sorted by `created_asc`.
+    pub fn next_window(&self, last_created: PrimitiveDateTime) -> Self {
+        Self {
+            created_after: Some(last_created),
+            time_range: None,
+            offset: None,
+            starting_after: None,
+            ending_before: None,
+            ..self.clone()
+        }
+    }
+
+    /// Resolves `time_range` against `now` into concrete `created_after` and `created_before`
+    /// constraints, where `created_before` defaults to `now` unless specified. Fails if
+    /// `created_after` is specified along with `time_range`.
+    pub fn resolve_time_range(
+        self,
+        now: PrimitiveDateTime,
+    ) -> Result<Self, EventListConstraintsError> {
+        let Some(time_range) = self.time_range else {
+            return Ok(self);
+        };
+        if self.created_after.is_some() {
+            return Err(EventListConstraintsError::TimeRangeWithCreatedAfter);
+        }
+
+        let duration = match time_range {
+            EventTimeRangePreset::Last1Hour => time::Duration::hours(1),
+            EventTimeRangePreset::Last24Hours => time::Duration::hours(24),
+            EventTimeRangePreset::Last7Days => time::Duration::days(7),
+            EventTimeRangePreset::Last30Days => time::Duration::days(30),
+        };
+
+        Ok(Self {
+            created_after: Some(now - duration),
+            created_before: Some(self.created_before.unwrap_or(now)),
+            time_range: None,
+            ..self
+        })
+    }
+
+    /// Returns the default `created_after` constraint, [`DEFAULT_EVENT_LIST_TIME_WINDOW`] before
+    /// `now`, if none of `created_after`, `created_before` and `time_range` are specified.
+    pub fn get_default_created_after(&self, now: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        (self.created_after.is_none() && self.created_before.is_none() && self.time_range.is_none())
+            .then(|| now - DEFAULT_EVENT_LIST_TIME_WINDOW)
+    }
+
+    /// Returns the number of events to be listed, applying [`DEFAULT_EVENT_LIST_LIMIT`] when
+    /// `limit` is not specified and clamping it to [`MAX_EVENT_LIST_LIMIT`].
+    pub fn get_limit(&self) -> u16 {
+        self.limit
+            .unwrap_or(DEFAULT_EVENT_LIST_LIMIT)
+            .min(MAX_EVENT_LIST_LIMIT)
+    }
+
+    /// Combines the `delivery_state` and `is_delivered` filters into the set of delivery states
+    /// events must be in. `is_delivered: true` maps to `succeeded`, while `is_delivered: false`
+    /// maps to `pending` and `failed`. Returns `None` if events are not to be filtered by their
+    /// delivery state.
+    pub fn get_delivery_states(&self) -> Option<HashSet<WebhookDeliveryState>> {
+        let delivery_states_from_is_delivered = self.is_delivered.map(|is_delivered| {
+            if is_delivered {
+                HashSet::from([WebhookDeliveryState::Succeeded])
+            } else {
+                HashSet::from([WebhookDeliveryState::Pending, WebhookDeliveryState::Failed])
+            }
+        });
+        let delivery_states = self
+            .delivery_state
+            .clone()
+            .filter(|delivery_states| !delivery_states.is_empty());
+
+        match (delivery_states, delivery_states_from_is_delivered) {
+            (Some(delivery_states), Some(delivery_states_from_is_delivered)) => Some(
+                delivery_states
+                    .intersection(&delivery_states_from_is_delivered)
+                    .copied()
+                    .collect(),
+            ),
+            (Some(delivery_states), None) | (None, Some(delivery_states)) => Some(delivery_states),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns whether only initial delivery attempts are to be listed, falling back to listing
+    /// only initial attempts when neither a `delivery_attempt` nor a `triggered_by` filter is
+    /// specified.
+    pub fn get_only_initial_attempts(&self) -> bool {
+        self.only_initial_attempts
+            .unwrap_or(self.delivery_attempt.is_none() && self.triggered_by.is_none())
+    }
+
+    /// Returns whether only events that were never delivered are to be listed.
+    pub fn get_never_delivered(&self) -> bool {
+        self.never_delivered.unwrap_or(false)
+    }
+
+    /// Returns whether only orphaned events are to be listed.
+    pub fn get_orphaned(&self) -> bool {
+        self.orphaned.unwrap_or(false)
+    }
+
+    /// Returns the names of the specified constraints that cannot be combined with `orphaned`,
+    /// sorted by name.
+    pub fn get_constraints_conflicting_with_orphaned(
+        &self,
+    ) -> Result<Vec<String>, serde_json::Error> {
+        let serde_json::Value::Object(constraints) = serde_json::to_value(self)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut conflicting_constraints = constraints
+            .into_iter()
+            .filter(|(key, value)| {
+                !value.is_null() && !ORPHANED_COMBINABLE_CONSTRAINTS.contains(&key.as_str())
+            })
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        conflicting_constraints.sort();
+
+        Ok(conflicting_constraints)
+    }
+
+    /// Encodes the constraints into a URL query string, so that a set of filters can be shared as
+    /// part of a URL. Multi-valued constraints are encoded as repeated keys, such as
+    /// `event_classes=payments&event_classes=refunds`, and unspecified constraints are omitted.
+    pub fn to_query_string(&self) -> Result<String, EventListQueryStringError> {
+        let serde_json::Value::Object(mut constraints) =
+            serde_json::to_value(self).map_err(EventListQueryStringError::encode)?
+        else {
+            return Ok(String::new());
+        };
+        // The dropped filters are already applied, and are not a filter themselves
+        constraints.remove("dropped_filters");
+
+        let mut pairs = Vec::new();
+        for (key, value) in constraints {
+            let mut values = match value {
+                serde_json::Value::Null => continue,
+                serde_json::Value::Array(values) => {
+                    values.into_iter().map(get_query_value).collect::<Vec<_>>()
+                }
+                value => vec![get_query_value(value)],
+            };
+            // Sets are unordered, so the values are sorted to encode the same constraints into the
+            // same query string.
+            values.sort();
+            pairs.extend(values.into_iter().map(|value| (key.clone(), value)));
+        }
+
+        serde_urlencoded::to_string(pairs).map_err(EventListQueryStringError::encode)
+    }
+
+    /// Decodes the constraints from a URL query string produced by [`Self::to_query_string`].
+    pub fn from_query_string(query_string: &str) -> Result<Self, EventListQueryStringError> {
+        let pairs = serde_urlencoded::from_str::<Vec<(String, String)>>(query_string)
+            .map_err(EventListQueryStringError::decode)?;
+
+        let mut repeated_values = HashMap::<String, Vec<String>>::new();
+        let mut single_values = Vec::new();
+        for (key, value) in pairs {
+            if EVENT_LIST_REPEATED_QUERY_KEYS.contains(&key.as_str()) {
+                repeated_values.entry(key).or_default().push(value);
+            } else {
+                single_values.push((key, value));
+            }
+        }
+
+        // The single valued constraints are deserialized by `serde_urlencoded` itself, which
+        // requires them to be encoded back into a query string.
+        let single_values = serde_urlencoded::to_string(single_values)
+            .map_err(EventListQueryStringError::decode)?;
+        let mut constraints = serde_urlencoded::from_str::<Self>(&single_values)
+            .map_err(EventListQueryStringError::decode)?;
+        constraints.object_ids = take_repeated_values(&mut repeated_values, "object_ids")?;
+        constraints.profile_ids = take_repeated_values(&mut repeated_values, "profile_ids")?;
+        constraints.delivery_attempt =
+            take_repeated_values(&mut repeated_values, "delivery_attempt")?;
+        constraints.event_classes = take_repeated_values(&mut repeated_values, "event_classes")?;
+        constraints.event_types = take_repeated_values(&mut repeated_values, "event_types")?;
+        constraints.delivery_state = take_repeated_values(&mut repeated_values, "delivery_state")?;
+        constraints.error_category = take_repeated_values(&mut repeated_values, "error_category")?;
+        constraints.created_hour_of_day =
+            take_repeated_numeric_values(&mut repeated_values, "created_hour_of_day")?;
+        constraints.created_day_of_week =
+            take_repeated_numeric_values(&mut repeated_values, "created_day_of_week")?;
+        constraints.tags = take_repeated_values(&mut repeated_values, "tags")?;
+        constraints.triggered_by = take_repeated_values(&mut repeated_values, "triggered_by")?;
+
+        Ok(constraints.normalize_event_types())
+    }
+
+    /// Returns a builder for constructing the constraints.
+    pub fn builder() -> EventListConstraintsBuilder {
+        EventListConstraintsBuilder::default()
+    }
+}
+
+fn get_query_value(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+/// Deserializes the values specified for a repeated query string key, if any.
+fn take_repeated_values<T: serde::de::DeserializeOwned>(
+    repeated_values: &mut HashMap<String, Vec<String>>,
+    key: &str,
+) -> Result<Option<T>, EventListQueryStringError> {
+    repeated_values
+        .remove(key)
+        .map(|values| {
+            serde_json::from_value(serde_json::Value::from(values))
+                .map_err(EventListQueryStringError::decode)
+        })
+        .transpose()
+}
+
+/// Parses the numeric values specified for a repeated query string key, if any.
+fn take_repeated_numeric_values<T: std::str::FromStr + Eq + std::hash::Hash>(
+    repeated_values: &mut HashMap<String, Vec<String>>,
+    key: &str,
+) -> Result<Option<HashSet<T>>, EventListQueryStringError> {
+    repeated_values
+        .remove(key)
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| {
+                    value.parse().map_err(|_| {
+                        EventListQueryStringError::Decode(format!(
+                            "invalid value `{value}` specified for `{key}`"
+                        ))
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// A builder for [`EventListConstraints`], for constructing the constraints without having to
+/// specify every field. Constraints which are not set are left unspecified.
+#[derive(Clone, Debug, Default)]
+pub struct EventListConstraintsBuilder {
+    constraints: EventListConstraints,
+}
+
+impl EventListConstraintsBuilder {
+    /// Filter events created after the specified time.
+    pub fn created_after(mut self, created_after: PrimitiveDateTime) -> Self {
+        self.constraints.created_after = Some(created_after);
+        self
+    }
+
+    /// Filter events created before the specified time.
+    pub fn created_before(mut self, created_before: PrimitiveDateTime) -> Self {
+        self.constraints.created_before = Some(created_before);
+        self
+    }
+
+    /// Filter events that expire before the specified time.
+    pub fn expiring_before(mut self, expiring_before: PrimitiveDateTime) -> Self {
+        self.constraints.expiring_before = Some(expiring_before);
+        self
+    }
+
+    /// Filter events persisted after the specified time.
+    pub fn ingested_after(mut self, ingested_after: PrimitiveDateTime) -> Self {
+        self.constraints.ingested_after = Some(ingested_after);
+        self
+    }
+
+    /// Filter events persisted before the specified time.
+    pub fn ingested_before(mut self, ingested_before: PrimitiveDateTime) -> Self {
+        self.constraints.ingested_before = Some(ingested_before);
+        self
+    }
+
+    /// Filter events created at least the specified number of milliseconds after their object
+    /// was last changed.
+    pub fn event_delay_ms_gte(mut self, event_delay_ms_gte: u64) -> Self {
+        self.constraints.event_delay_ms_gte = Some(event_delay_ms_gte);
+        self
+    }
+
+    /// Filter events created within the specified time range.
+    pub fn time_range(mut self, time_range: EventTimeRangePreset) -> Self {
+        self.constraints.time_range = Some(time_range);
+        self
+    }
+
+    /// Filter events created during any of the specified hours of the day (UTC).
+    pub fn created_hour_of_day(
+        mut self,
+        created_hour_of_day: impl IntoIterator<Item = u8>,
+    ) -> Self {
+        self.constraints.created_hour_of_day = Some(created_hour_of_day.into_iter().collect());
+        self
+    }
+
+    /// Filter events created on any of the specified days of the week (UTC), starting from 0 for
+    /// Sunday.
+    pub fn created_day_of_week(
+        mut self,
+        created_day_of_week: impl IntoIterator<Item = u8>,
+    ) -> Self {
+        self.constraints.created_day_of_week = Some(created_day_of_week.into_iter().collect());
+        self
+    }
+
+    /// Filter events tagged with any of the specified tags.
+    pub fn tags(mut self, tags: impl IntoIterator<Item = String>) -> Self {
+        self.constraints.tags = Some(tags.into_iter().collect());
+        self
+    }
+
+    /// Include at most the specified number of events.
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.constraints.limit = Some(limit);
+        self
+    }
+
+    /// Include events after the specified offset.
+    pub fn offset(mut self, offset: u16) -> Self {
+        self.constraints.offset = Some(offset);
+        self
+    }
+
+    /// List the events following the event with the specified identifier.
+    pub fn starting_after(mut self, starting_after: impl Into<String>) -> Self {
+        self.constraints.starting_after = Some(starting_after.into());
+        self
+    }
+
+    /// List the events preceding the event with the specified identifier.
+    pub fn ending_before(mut self, ending_before: impl Into<String>) -> Self {
+        self.constraints.ending_before = Some(ending_before.into());
+        self
+    }
+
+    /// Filter all events associated with the specified object identifier.
+    pub fn object_id(mut self, object_id: impl Into<String>) -> Self {
+        self.constraints.object_id = Some(object_id.into());
+        self
+    }
+
+    /// Filter all events associated with any of the specified object identifiers.
+    pub fn object_ids(mut self, object_ids: impl IntoIterator<Item = String>) -> Self {
+        self.constraints.object_ids = Some(object_ids.into_iter().collect());
+        self
+    }
+
+    /// Filter all events whose object identifier contains the specified substring.
+    pub fn object_id_contains(mut self, object_id_contains: impl Into<String>) -> Self {
+        self.constraints.object_id_contains = Some(object_id_contains.into());
+        self
+    }
+
+    /// Filter all delivery attempts sharing the specified initial delivery attempt.
+    pub fn initial_attempt_id(mut self, initial_attempt_id: impl Into<String>) -> Self {
+        self.constraints.initial_attempt_id = Some(initial_attempt_id.into());
+        self
+    }
+
+    /// Look up the event with the specified identifier.
+    pub fn event_id(mut self, event_id: impl Into<String>) -> Self {
+        self.constraints.event_id = Some(event_id.into());
+        self
+    }
+
+    /// Filter all events associated with the specified business profile ID.
+    pub fn profile_id(mut self, profile_id: common_utils::id_type::ProfileId) -> Self {
+        self.constraints.profile_id = Some(profile_id);
+        self
+    }
+
+    /// Filter all events associated with any of the specified business profile IDs.
+    pub fn profile_ids(
+        mut self,
+        profile_ids: impl IntoIterator<Item = common_utils::id_type::ProfileId>,
+    ) -> Self {
+        self.constraints.profile_ids = Some(profile_ids.into_iter().collect());
+        self
+    }
+
+    /// Filter all events associated with the specified merchant connector account ID.
+    pub fn merchant_connector_id(
+        mut self,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    ) -> Self {
+        self.constraints.merchant_connector_id = Some(merchant_connector_id);
+        self
+    }
+
+    /// Filter all events associated with the specified connector.
+    pub fn connector(mut self, connector: impl Into<String>) -> Self {
+        self.constraints.connector = Some(connector.into());
+        self
+    }
+
+    /// Filter delivery attempts by their type.
+    pub fn delivery_attempt(
+        mut self,
+        delivery_attempt: impl IntoIterator<Item = WebhookDeliveryAttempt>,
+    ) -> Self {
+        self.constraints.delivery_attempt = Some(delivery_attempt.into_iter().collect());
+        self
+    }
+
+    /// Filter retries and replays by the source that triggered them.
+    pub fn triggered_by(
+        mut self,
+        triggered_by: impl IntoIterator<Item = WebhookRetryTrigger>,
+    ) -> Self {
+        self.constraints.triggered_by = Some(triggered_by.into_iter().collect());
+        self
+    }
+
+    /// List only initial delivery attempts, excluding retries.
+    pub fn only_initial_attempts(mut self, only_initial_attempts: bool) -> Self {
+        self.constraints.only_initial_attempts = Some(only_initial_attempts);
+        self
+    }
+
+    /// Filter events by their class.
+    pub fn event_classes(mut self, event_classes: impl IntoIterator<Item = EventClass>) -> Self {
+        self.constraints.event_classes = Some(event_classes.into_iter().collect());
+        self
+    }
+
+    /// Filter events by their type.
+    pub fn event_types(mut self, event_types: impl IntoIterator<Item = EventType>) -> Self {
+        self.constraints.event_types = Some(event_types.into_iter().collect());
+        self
+    }
+
+    /// Filter events by whether they were ultimately delivered.
+    pub fn is_delivered(mut self, is_delivered: bool) -> Self {
+        self.constraints.is_delivered = Some(is_delivered);
+        self
+    }
+
+    /// Filter events whose delivery chain has no successful delivery attempt.
+    pub fn never_delivered(mut self, never_delivered: bool) -> Self {
+        self.constraints.never_delivered = Some(never_delivered);
+        self
+    }
+
+    /// Filter events whose delivery chain has at least the specified number of delivery attempts.
+    pub fn min_attempts(mut self, min_attempts: u16) -> Self {
+        self.constraints.min_attempts = Some(min_attempts);
+        self
+    }
+
+    /// Filter events by the state of their delivery.
+    pub fn delivery_state(
+        mut self,
+        delivery_state: impl IntoIterator<Item = WebhookDeliveryState>,
+    ) -> Self {
+        self.constraints.delivery_state = Some(delivery_state.into_iter().collect());
+        self
+    }
+
+    /// Filter events whose response status code is at least the specified value.
+    pub fn response_status_code_gte(mut self, response_status_code_gte: u16) -> Self {
+        self.constraints.response_status_code_gte = Some(response_status_code_gte);
+        self
+    }
+
+    /// Filter events whose response status code is at most the specified value.
+    pub fn response_status_code_lte(mut self, response_status_code_lte: u16) -> Self {
+        self.constraints.response_status_code_lte = Some(response_status_code_lte);
+        self
+    }
+
+    /// Filter events whose response took at least the specified number of milliseconds.
+    pub fn latency_ms_gte(mut self, latency_ms_gte: u32) -> Self {
+        self.constraints.latency_ms_gte = Some(latency_ms_gte);
+        self
+    }
+
+    /// Filter events by whether their response took longer than the delivery SLA.
+    pub fn sla_breached(mut self, sla_breached: bool) -> Self {
+        self.constraints.sla_breached = Some(sla_breached);
+        self
+    }
+
+    /// Filter events whose response body is at least the specified number of bytes.
+    pub fn response_body_size_gte(mut self, response_body_size_gte: usize) -> Self {
+        self.constraints.response_body_size_gte = Some(response_body_size_gte);
+        self
+    }
+
+    /// Filter events whose delivery failed with one of the specified classes of errors.
+    pub fn error_category(
+        mut self,
+        error_category: impl IntoIterator<Item = WebhookDeliveryErrorCategory>,
+    ) -> Self {
+        self.constraints.error_category = Some(error_category.into_iter().collect());
+        self
+    }
+
+    /// Filter events by whether an error message was recorded for their delivery attempt.
+    pub fn has_error(mut self, has_error: bool) -> Self {
+        self.constraints.has_error = Some(has_error);
+        self
+    }
+
+    /// Filter events whose delivery attempt recorded an error message containing the specified
+    /// substring, ignoring case.
+    pub fn error_message_contains(mut self, error_message_contains: impl Into<String>) -> Self {
+        self.constraints.error_message_contains = Some(error_message_contains.into());
+        self
+    }
+
+    /// Filter events by whether an HTTP response was received for their delivery attempt.
+    pub fn response_received(mut self, response_received: bool) -> Self {
+        self.constraints.response_received = Some(response_received);
+        self
+    }
+
+    /// Filter events whose delivery attempt received a response with the specified media type.
+    pub fn response_content_type(mut self, response_content_type: impl Into<String>) -> Self {
+        self.constraints.response_content_type = Some(response_content_type.into());
+        self
+    }
+
+    /// Filter events by the schema version of their webhook payload.
+    pub fn payload_version(mut self, payload_version: impl Into<String>) -> Self {
+        self.constraints.payload_version = Some(payload_version.into());
+        self
+    }
+
+    /// Include the number of events of each class in the response.
+    pub fn include_breakdown(mut self, include_breakdown: bool) -> Self {
+        self.constraints.include_breakdown = Some(include_breakdown);
+        self
+    }
+
+    /// Include the events in the response in the specified shape.
+    pub fn response_mode(mut self, response_mode: EventResponseMode) -> Self {
+        self.constraints.response_mode = Some(response_mode);
+        self
+    }
+
+    /// Compute the total count of events in the response in the specified mode.
+    pub fn count_mode(mut self, count_mode: CountMode) -> Self {
+        self.constraints.count_mode = Some(count_mode);
+        self
+    }
+
+    /// Sort the events in the specified order.
+    pub fn sort_by(mut self, sort_by: EventSortBy) -> Self {
+        self.constraints.sort_by = Some(sort_by);
+        self
+    }
+
+    /// Filter events associated with a business profile which does not belong to the merchant.
+    pub fn orphaned(mut self, orphaned: bool) -> Self {
+        self.constraints.orphaned = Some(orphaned);
+        self
+    }
+
+    /// Validates the constraints and returns them, see [`EventListConstraints::validate`].
+    pub fn build(self) -> Result<EventListConstraints, EventListConstraintsError> {
+        self.constraints.validate()?;
+        Ok(self.constraints)
+    }
+}
+
+#[derive(Debug)]
+pub enum EventListConstraintsInternal {
+    GenericFilter {
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        starting_after: Option<String>,
+        ending_before: Option<String>,
+        event_classes: Option<HashSet<EventClass>>,
+        event_types: Option<HashSet<EventType>>,
+        delivery_states: Option<HashSet<WebhookDeliveryState>>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        connector: Option<String>,
+        delivery_attempts: Option<HashSet<WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        response_status_code_gte: Option<u16>,
+        response_status_code_lte: Option<u16>,
+        latency_ms_gte: Option<u32>,
+        sla_breached: Option<bool>,
+        response_body_size_gte: Option<usize>,
+        error_category: Option<HashSet<WebhookDeliveryErrorCategory>>,
+        has_error: Option<bool>,
+        error_message_contains: Option<String>,
+        response_received: Option<bool>,
+        response_content_type: Option<String>,
+        payload_version: Option<String>,
+        expiring_before: Option<PrimitiveDateTime>,
+        ingested_after: Option<PrimitiveDateTime>,
+        ingested_before: Option<PrimitiveDateTime>,
+        event_delay_ms_gte: Option<u64>,
+        created_hour_of_day: Option<HashSet<u8>>,
+        created_day_of_week: Option<HashSet<u8>>,
+        tags: Option<HashSet<String>>,
+        triggered_by: Option<HashSet<WebhookRetryTrigger>>,
+        sort_by: EventSortBy,
+        /// Indicates whether `created_after` was defaulted to [`DEFAULT_EVENT_LIST_TIME_WINDOW`]
+        /// before the time of listing, since no time bounds were specified.
+        default_window_applied: bool,
+    },
+    ObjectIdFilter {
+        object_id: String,
+        event_classes: Option<HashSet<EventClass>>,
+        sort_by: EventSortBy,
+    },
+    ObjectIdsFilter {
+        object_ids: Vec<String>,
+        sort_by: EventSortBy,
+    },
+    ObjectIdContainsFilter {
+        object_id_contains: String,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: EventSortBy,
+    },
+    InitialAttemptIdFilter {
+        initial_attempt_id: String,
+        sort_by: EventSortBy,
+    },
+    EventIdFilter {
+        event_id: String,
+    },
+    OrphanedFilter {
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        starting_after: Option<String>,
+        sort_by: EventSortBy,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::test_utils::date_time;
+
+    #[test]
+    fn test_event_sort_by_serde_round_trip() {
+        for (sort_by, serialized) in [
+            (EventSortBy::CreatedAsc, r#""created_asc""#),
+            (EventSortBy::CreatedDesc, r#""created_desc""#),
+        ] {
+            assert_eq!(serde_json::to_string(&sort_by).unwrap(), serialized);
+            assert_eq!(
+                serde_json::from_str::<EventSortBy>(serialized).unwrap(),
+                sort_by
+            );
+        }
+    }
+
+    #[test]
+    fn test_absent_sort_by_defaults_to_created_desc() {
+        let constraints = serde_json::from_str::<EventListConstraints>("{}").unwrap();
+
+        assert_eq!(constraints.sort_by, None);
+        assert_eq!(
+            constraints.sort_by.unwrap_or_default(),
+            EventSortBy::CreatedDesc
+        );
+    }
+
+    fn object_ids(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("pay_{index}")).collect()
+    }
+
+    fn profile_ids(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("pro_{index}")).collect()
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_constraints() {
+        for constraints in [
+            serde_json::json!({}),
+            serde_json::json!({
+                "created_after": "2024-01-01T00:00:00Z",
+                "created_before": "2024-01-01T00:00:00Z",
+                "limit": MAX_EVENT_LIST_LIMIT,
+                "offset": MAX_EVENT_LIST_OFFSET,
+            }),
+            serde_json::json!({ "object_ids": object_ids(MAX_EVENT_LIST_OBJECT_IDS) }),
+            serde_json::json!({ "profile_ids": profile_ids(MAX_EVENT_LIST_PROFILE_IDS) }),
+            serde_json::json!({
+                "created_hour_of_day": (0..=23).collect::<Vec<_>>(),
+                "created_day_of_week": (0..=6).collect::<Vec<_>>(),
+            }),
+            serde_json::json!({
+                "event_classes": ["refunds"],
+                "event_types": ["payment_succeeded", "refund_failed"],
+            }),
+            serde_json::json!({ "event_classes": ["refunds"], "event_types": [] }),
+            serde_json::json!({ "event_types": ["payment_succeeded"] }),
+            serde_json::json!({ "object_id_contains": "pay_" }),
+            serde_json::json!({ "error_message_contains": "certificate expired" }),
+        ] {
+            assert_eq!(
+                serde_json::from_value::<EventListConstraints>(constraints.clone())
+                    .unwrap()
+                    .validate(),
+                Ok(()),
+                "{constraints}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_constraints() {
+        for (constraints, expected_error) in [
+            (
+                serde_json::json!({
+                    "created_after": "2024-01-02T00:00:00Z",
+                    "created_before": "2024-01-01T00:00:00Z",
+                }),
+                EventListConstraintsError::InvalidTimeRange {
+                    created_after: date_time(2),
+                    created_before: date_time(1),
+                },
+            ),
+            (
+                serde_json::json!({
+                    "ingested_after": "2024-01-02T00:00:00Z",
+                    "ingested_before": "2024-01-01T00:00:00Z",
+                }),
+                EventListConstraintsError::InvalidIngestedTimeRange {
+                    ingested_after: date_time(2),
+                    ingested_before: date_time(1),
+                },
+            ),
+            (
+                serde_json::json!({
+                    "time_range": "last_7_days",
+                    "created_after": "2024-01-01T00:00:00Z",
+                }),
+                EventListConstraintsError::TimeRangeWithCreatedAfter,
+            ),
+            (
+                serde_json::json!({
+                    "response_status_code_gte": 500,
+                    "response_status_code_lte": 499,
+                }),
+                EventListConstraintsError::InvalidResponseStatusCodeRange {
+                    response_status_code_gte: 500,
+                    response_status_code_lte: 499,
+                },
+            ),
+            (
+                serde_json::json!({ "offset": MAX_EVENT_LIST_OFFSET + 1 }),
+                EventListConstraintsError::OffsetTooLarge {
+                    offset: MAX_EVENT_LIST_OFFSET + 1,
+                    max_offset: MAX_EVENT_LIST_OFFSET,
+                },
+            ),
+            (
+                serde_json::json!({ "offset": 10, "starting_after": "evt_1" }),
+                EventListConstraintsError::OffsetWithCursor,
+            ),
+            (
+                serde_json::json!({ "offset": 10, "ending_before": "evt_1" }),
+                EventListConstraintsError::OffsetWithCursor,
+            ),
+            (
+                serde_json::json!({ "object_ids": object_ids(MAX_EVENT_LIST_OBJECT_IDS + 1) }),
+                EventListConstraintsError::TooManyObjectIds {
+                    count: MAX_EVENT_LIST_OBJECT_IDS + 1,
+                    max_count: MAX_EVENT_LIST_OBJECT_IDS,
+                },
+            ),
+            (
+                serde_json::json!({ "profile_ids": profile_ids(MAX_EVENT_LIST_PROFILE_IDS + 1) }),
+                EventListConstraintsError::TooManyProfileIds {
+                    count: MAX_EVENT_LIST_PROFILE_IDS + 1,
+                    max_count: MAX_EVENT_LIST_PROFILE_IDS,
+                },
+            ),
+            (
+                serde_json::json!({ "profile_id": "pro_1", "profile_ids": ["pro_1"] }),
+                EventListConstraintsError::ProfileIdWithProfileIds,
+            ),
+            (
+                serde_json::json!({ "object_id_contains": "pay" }),
+                EventListConstraintsError::ObjectIdContainsTooShort {
+                    length: 3,
+                    min_length: MIN_OBJECT_ID_CONTAINS_LENGTH,
+                },
+            ),
+            (
+                serde_json::json!({ "error_message_contains": "tls" }),
+                EventListConstraintsError::ErrorMessageContainsTooShort {
+                    length: 3,
+                    min_length: MIN_ERROR_MESSAGE_CONTAINS_LENGTH,
+                },
+            ),
+            (
+                serde_json::json!({
+                    "event_classes": ["refunds"],
+                    "event_types": ["payment_succeeded", "dispute_won"],
+                }),
+                EventListConstraintsError::EventTypesOutsideEventClasses,
+            ),
+            (
+                serde_json::json!({ "created_hour_of_day": [0, 24, 30] }),
+                EventListConstraintsError::InvalidCreatedHourOfDay { hour_of_day: 24 },
+            ),
+            (
+                serde_json::json!({ "created_day_of_week": [6, 7] }),
+                EventListConstraintsError::InvalidCreatedDayOfWeek { day_of_week: 7 },
+            ),
+            (
+                serde_json::json!({ "created_day_of_week": [255] }),
+                EventListConstraintsError::InvalidCreatedDayOfWeek { day_of_week: 255 },
+            ),
+        ] {
+            assert_eq!(
+                serde_json::from_value::<EventListConstraints>(constraints.clone())
+                    .unwrap()
+                    .validate(),
+                Err(expected_error),
+                "{constraints}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validation_error_messages() {
+        for (error, expected_message) in [
+            (
+                EventListConstraintsError::ProfileIdWithProfileIds,
+                "Only one of `profile_id` and `profile_ids` can be specified",
+            ),
+            (
+                EventListConstraintsError::InvalidCreatedHourOfDay { hour_of_day: 24 },
+                "`created_hour_of_day` must only contain hours from 0 to 23, found 24",
+            ),
+        ] {
+            assert_eq!(error.to_string(), expected_message);
+        }
+    }
+
+    #[test]
+    fn test_constraints_conflicting_with_orphaned() {
+        let constraints = EventListConstraints::builder()
+            .orphaned(true)
+            .created_after(date_time(1))
+            .limit(10)
+            .starting_after("evt_1")
+            .sort_by(EventSortBy::CreatedAsc)
+            .build()
+            .unwrap();
+        assert!(constraints.get_orphaned());
+        assert_eq!(
+            constraints
+                .get_constraints_conflicting_with_orphaned()
+                .unwrap(),
+            Vec::<String>::new()
+        );
+
+        let constraints = EventListConstraints::builder()
+            .orphaned(true)
+            .object_id("pay_1")
+            .profile_id(
+                common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile_1"))
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            constraints
+                .get_constraints_conflicting_with_orphaned()
+                .unwrap(),
+            vec!["object_id".to_string(), "profile_id".to_string()]
+        );
+
+        assert!(!serde_json::from_str::<EventListConstraints>("{}")
+            .unwrap()
+            .get_orphaned());
+    }
+
+    #[test]
+    fn test_constraints_query_string_round_trip() {
+        let created_after = date_time(1);
+        let constraints = EventListConstraints::builder()
+            .created_after(created_after)
+            .limit(50)
+            .object_ids(["pay_1".to_string(), "pay 2".to_string()])
+            .event_classes([EventClass::Payments, EventClass::Refunds])
+            .delivery_state([WebhookDeliveryState::Failed])
+            .has_error(true)
+            .created_hour_of_day([9, 17])
+            .sort_by(EventSortBy::CreatedAsc)
+            .build()
+            .unwrap();
+
+        let query_string = constraints.to_query_string().unwrap();
+        assert!(query_string.contains("event_classes=payments&event_classes=refunds"));
+        assert!(query_string.contains("object_ids=pay+2&object_ids=pay_1"));
+        assert!(!query_string.contains("created_before"));
+
+        let decoded = EventListConstraints::from_query_string(&query_string).unwrap();
+        assert_eq!(decoded.created_after, Some(created_after));
+        assert_eq!(decoded.created_before, None);
+        assert_eq!(decoded.limit, Some(50));
+        assert_eq!(
+            decoded.object_ids.map(HashSet::<String>::from_iter),
+            Some(HashSet::from(["pay_1".to_string(), "pay 2".to_string()]))
+        );
+        assert_eq!(
+            decoded.event_classes,
+            Some(HashSet::from([EventClass::Payments, EventClass::Refunds]))
+        );
+        assert_eq!(
+            decoded.delivery_state,
+            Some(HashSet::from([WebhookDeliveryState::Failed]))
+        );
+        assert_eq!(decoded.event_types, None);
+        assert_eq!(decoded.has_error, Some(true));
+        assert_eq!(decoded.created_hour_of_day, Some(HashSet::from([9, 17])));
+        assert_eq!(decoded.created_day_of_week, None);
+        assert_eq!(decoded.sort_by, Some(EventSortBy::CreatedAsc));
+        assert_eq!(decoded.to_query_string().unwrap(), query_string);
+    }
+
+    #[test]
+    fn test_next_window_advances_created_after_and_preserves_filters() {
+        let constraints = EventListConstraints::builder()
+            .time_range(EventTimeRangePreset::Last7Days)
+            .created_before(date_time(20))
+            .limit(50)
+            .offset(100)
+            .event_classes([EventClass::Payments])
+            .has_error(true)
+            .sort_by(EventSortBy::CreatedAsc)
+            .build()
+            .unwrap();
+
+        let next_window = constraints.next_window(date_time(15));
+
+        assert_eq!(next_window.created_after, Some(date_time(15)));
+        assert_eq!(next_window.created_before, Some(date_time(20)));
+        assert_eq!(next_window.time_range, None);
+        assert_eq!(next_window.offset, None);
+        assert_eq!(next_window.limit, Some(50));
+        assert_eq!(
+            next_window.event_classes,
+            Some(HashSet::from([EventClass::Payments]))
+        );
+        assert_eq!(next_window.has_error, Some(true));
+        assert_eq!(next_window.sort_by, Some(EventSortBy::CreatedAsc));
+        assert_eq!(next_window.validate(), Ok(()));
+
+        assert_eq!(
+            next_window.next_window(date_time(18)).created_after,
+            Some(date_time(18))
+        );
+    }
+
+    #[test]
+    fn test_empty_constraints_query_string_round_trip() {
+        let constraints = EventListConstraints::default();
+        assert_eq!(constraints.to_query_string().unwrap(), "");
+
+        let decoded = EventListConstraints::from_query_string("").unwrap();
+        assert_eq!(decoded.created_after, None);
+        assert_eq!(decoded.event_classes, None);
+
+        assert!(EventListConstraints::from_query_string("event_classes=unknown").is_err());
+        assert!(EventListConstraints::from_query_string("limit=many").is_err());
+        assert_eq!(
+            EventListConstraints::from_query_string("created_hour_of_day=noon").unwrap_err(),
+            EventListQueryStringError::Decode(
+                "invalid value `noon` specified for `created_hour_of_day`".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_constraints_deserialize_from_snake_case_and_camel_case() {
+        let snake_case = serde_json::json!({
+            "created_after": "2024-01-01T00:00:00.000Z",
+            "created_before": "2024-01-02T00:00:00.000Z",
+            "expiring_before": "2024-07-01T00:00:00.000Z",
+            "ingested_after": "2024-01-01T00:05:00.000Z",
+            "ingested_before": "2024-01-02T00:05:00.000Z",
+            "event_delay_ms_gte": 5000,
+            "time_range": "last_7_days",
+            "created_hour_of_day": [9],
+            "created_day_of_week": [1],
+            "tags": ["under-investigation"],
+            "limit": 10,
+            "offset": 20,
+            "starting_after": "evt_starting_after",
+            "ending_before": "evt_ending_before",
+            "object_id": "pay_1",
+            "object_ids": ["pay_1", "pay_2"],
+            "object_id_contains": "pay_",
+            "initial_attempt_id": "evt_initial_attempt",
+            "event_id": "evt_1",
+            "profile_id": "pro_1",
+            "profile_ids": ["pro_2"],
+            "merchant_connector_id": "mca_1",
+            "connector": "stripe",
+            "delivery_attempt": ["manual_retry"],
+            "triggered_by": ["manual_dashboard"],
+            "only_initial_attempts": false,
+            "event_classes": ["payments"],
+            "event_types": ["payment_succeeded"],
+            "is_delivered": false,
+            "never_delivered": true,
+            "min_attempts": 2,
+            "delivery_state": ["failed"],
+            "response_status_code_gte": 500,
+            "response_status_code_lte": 599,
+            "latency_ms_gte": 1000,
+            "sla_breached": true,
+            "response_body_size_gte": 1024,
+            "error_category": ["timeout"],
+            "has_error": true,
+            "error_message_contains": "certificate",
+            "response_received": false,
+            "response_content_type": "text/html",
+            "payload_version": "2024-06-01",
+            "include_breakdown": true,
+            "response_mode": "compact",
+            "count_mode": "estimated",
+            "sort_by": "created_asc",
+            "orphaned": true,
+        });
+        let to_camel_case = |key: &str| {
+            let mut words = key.split('_');
+            words.next().unwrap_or_default().to_string()
+                + &words
+                    .map(|word| {
+                        let mut chars = word.chars();
+                        chars
+                            .next()
+                            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                            .unwrap_or_default()
+                    })
+                    .collect::<String>()
+        };
+        let camel_case = serde_json::Value::Object(
+            snake_case
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(key, value)| (to_camel_case(key), value.clone()))
+                .collect(),
+        );
+        assert!(camel_case.get("createdAfter").is_some());
+        assert!(camel_case.get("responseStatusCodeGte").is_some());
+
+        for value in [&snake_case, &camel_case] {
+            let constraints =
+                serde_json::from_value::<EventListConstraints>(value.clone()).unwrap();
+
+            // Serialization always uses snake case
+            assert_eq!(serde_json::to_value(&constraints).unwrap(), snake_case);
+        }
+    }
+
+    #[test]
+    fn test_limit_defaults_and_clamps() {
+        let mut constraints = serde_json::from_str::<EventListConstraints>("{}").unwrap();
+        assert_eq!(constraints.get_limit(), DEFAULT_EVENT_LIST_LIMIT);
+        assert_eq!(constraints.get_limit(), 20);
+
+        constraints.limit = Some(50);
+        assert_eq!(constraints.get_limit(), 50);
+
+        constraints.limit = Some(500);
+        assert_eq!(constraints.validate(), Ok(()));
+        assert_eq!(constraints.get_limit(), MAX_EVENT_LIST_LIMIT);
+        assert_eq!(constraints.get_limit(), 100);
+    }
+
+    #[test]
+    fn test_only_initial_attempts_defaults_by_delivery_attempt() {
+        let mut constraints = EventListConstraints::default();
+        assert!(constraints.get_only_initial_attempts());
+
+        constraints.delivery_attempt = Some(HashSet::from([WebhookDeliveryAttempt::ManualRetry]));
+        assert!(!constraints.get_only_initial_attempts());
+
+        constraints.only_initial_attempts = Some(true);
+        assert!(constraints.get_only_initial_attempts());
+
+        constraints.delivery_attempt = None;
+        constraints.only_initial_attempts = Some(false);
+        assert!(!constraints.get_only_initial_attempts());
+    }
+
+    #[test]
+    fn test_default_constraints_are_unspecified() {
+        let constraints = EventListConstraints::default();
+
+        assert_eq!(
+            serde_json::to_value(&constraints).unwrap(),
+            serde_json::to_value(serde_json::from_str::<EventListConstraints>("{}").unwrap())
+                .unwrap()
+        );
+        assert_eq!(constraints.get_limit(), DEFAULT_EVENT_LIST_LIMIT);
+        assert_eq!(constraints.get_delivery_states(), None);
+        assert_eq!(EventListConstraints::builder().build().unwrap().limit, None);
+    }
+
+    #[test]
+    fn test_builder_sets_constraints() {
+        let constraints = EventListConstraints::builder()
+            .created_after(date_time(1))
+            .created_before(date_time(2))
+            .limit(50)
+            .event_types([EventType::PaymentSucceeded, EventType::PaymentFailed])
+            .sort_by(EventSortBy::CreatedAsc)
+            .build()
+            .unwrap();
+
+        assert_eq!(constraints.created_after, Some(date_time(1)));
+        assert_eq!(constraints.created_before, Some(date_time(2)));
+        assert_eq!(constraints.get_limit(), 50);
+        assert_eq!(
+            constraints.event_types,
+            Some(HashSet::from([
+                EventType::PaymentSucceeded,
+                EventType::PaymentFailed
+            ]))
+        );
+        assert_eq!(constraints.sort_by, Some(EventSortBy::CreatedAsc));
+        assert_eq!(constraints.offset, None);
+
+        let constraints = EventListConstraints::builder()
+            .object_id("pay_1")
+            .event_classes([EventClass::Payments])
+            .build()
+            .unwrap();
+
+        assert_eq!(constraints.object_id.as_deref(), Some("pay_1"));
+        assert_eq!(
+            constraints.event_classes,
+            Some(HashSet::from([EventClass::Payments]))
+        );
+    }
+
+    #[test]
+    fn test_builder_validates_constraints() {
+        assert_eq!(
+            EventListConstraints::builder()
+                .created_after(date_time(2))
+                .created_before(date_time(1))
+                .build()
+                .unwrap_err(),
+            EventListConstraintsError::InvalidTimeRange {
+                created_after: date_time(2),
+                created_before: date_time(1),
+            }
+        );
+        assert!(matches!(
+            EventListConstraints::builder()
+                .offset(MAX_EVENT_LIST_OFFSET + 1)
+                .build(),
+            Err(EventListConstraintsError::OffsetTooLarge { .. })
+        ));
+        assert_eq!(
+            EventListConstraints::builder()
+                .event_classes([EventClass::Refunds])
+                .event_types([EventType::PaymentSucceeded])
+                .build()
+                .unwrap_err(),
+            EventListConstraintsError::EventTypesOutsideEventClasses
+        );
+    }
+
+    #[test]
+    fn test_time_range_presets_resolve_relative_to_now() {
+        let now = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap(),
+            time::Time::from_hms(12, 30, 0).unwrap(),
+        );
+        for (time_range, duration) in [
+            (EventTimeRangePreset::Last1Hour, time::Duration::hours(1)),
+            (EventTimeRangePreset::Last24Hours, time::Duration::hours(24)),
+            (EventTimeRangePreset::Last7Days, time::Duration::days(7)),
+            (EventTimeRangePreset::Last30Days, time::Duration::days(30)),
+        ] {
+            let constraints = EventListConstraints::builder()
+                .time_range(time_range)
+                .build()
+                .unwrap()
+                .resolve_time_range(now)
+                .unwrap();
+
+            assert_eq!(constraints.time_range, None);
+            assert_eq!(constraints.created_after, Some(now - duration));
+            assert_eq!(constraints.created_before, Some(now));
+        }
+
+        let constraints = EventListConstraints::builder()
+            .time_range(EventTimeRangePreset::Last24Hours)
+            .created_before(date_time(1))
+            .build()
+            .unwrap()
+            .resolve_time_range(now)
+            .unwrap();
+        assert_eq!(constraints.created_before, Some(date_time(1)));
+
+        let constraints = EventListConstraints::builder()
+            .created_after(date_time(1))
+            .build()
+            .unwrap()
+            .resolve_time_range(now)
+            .unwrap();
+        assert_eq!(constraints.created_after, Some(date_time(1)));
+        assert_eq!(constraints.created_before, None);
+    }
+
+    #[test]
+    fn test_time_range_rejected_with_created_after() {
+        let constraints = serde_json::from_str::<EventListConstraints>(
+            r#"{"time_range":"last_7_days","created_after":"2024-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            constraints.resolve_time_range(date_time(2)).unwrap_err(),
+            EventListConstraintsError::TimeRangeWithCreatedAfter
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_time_range_first() {
+        let mut constraints = serde_json::from_str::<EventListConstraints>("{}").unwrap();
+        constraints.created_after = Some(date_time(2));
+        constraints.created_before = Some(date_time(1));
+        constraints.limit = Some(u16::MAX);
+        constraints.offset = Some(u16::MAX);
+
+        assert!(matches!(
+            constraints.validate(),
+            Err(EventListConstraintsError::InvalidTimeRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_merchant_connector_id_constraint_deserialization() {
+        let constraints = serde_json::from_str::<EventListConstraints>(
+            r#"{"merchant_connector_id":"mca_sAD3OZLATetvjLOYhUSy"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            constraints
+                .merchant_connector_id
+                .as_ref()
+                .map(|merchant_connector_id| merchant_connector_id.get_string_repr()),
+            Some("mca_sAD3OZLATetvjLOYhUSy")
+        );
+        assert!(serde_json::from_str::<EventListConstraints>("{}")
+            .unwrap()
+            .merchant_connector_id
+            .is_none());
+    }
+
+    fn constraints_with_delivery_filters(
+        is_delivered: Option<bool>,
+        delivery_state: Option<HashSet<WebhookDeliveryState>>,
+    ) -> EventListConstraints {
+        let mut constraints = serde_json::from_str::<EventListConstraints>("{}").unwrap();
+        constraints.is_delivered = is_delivered;
+        constraints.delivery_state = delivery_state;
+        constraints
+    }
+
+    #[test]
+    fn test_delivery_states_from_is_delivered() {
+        assert_eq!(
+            constraints_with_delivery_filters(Some(true), None).get_delivery_states(),
+            Some(HashSet::from([WebhookDeliveryState::Succeeded]))
+        );
+        assert_eq!(
+            constraints_with_delivery_filters(Some(false), None).get_delivery_states(),
+            Some(HashSet::from([
+                WebhookDeliveryState::Pending,
+                WebhookDeliveryState::Failed
+            ]))
+        );
+        assert_eq!(
+            constraints_with_delivery_filters(None, Some(HashSet::new())).get_delivery_states(),
+            None
+        );
+        assert_eq!(
+            constraints_with_delivery_filters(None, None).get_delivery_states(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_delivery_states_with_both_filters_set() {
+        assert_eq!(
+            constraints_with_delivery_filters(
+                Some(false),
+                Some(HashSet::from([
+                    WebhookDeliveryState::Pending,
+                    WebhookDeliveryState::Succeeded
+                ]))
+            )
+            .get_delivery_states(),
+            Some(HashSet::from([WebhookDeliveryState::Pending]))
+        );
+        assert_eq!(
+            constraints_with_delivery_filters(
+                Some(true),
+                Some(HashSet::from([WebhookDeliveryState::Pending]))
+            )
+            .get_delivery_states(),
+            Some(HashSet::new())
+        );
+    }
+
+    #[test]
+    fn test_event_types_outside_event_classes_are_dropped() {
+        let constraints = serde_json::from_str::<EventListConstraints>(
+            r#"{"event_classes":["payments"],"event_types":["payment_succeeded","refund_failed","dispute_won"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            constraints.event_types,
+            Some(HashSet::from([EventType::PaymentSucceeded]))
+        );
+        assert_eq!(
+            constraints.dropped_filters,
+            Some(vec![
+                "event_types: dispute_won".to_string(),
+                "event_types: refund_failed".to_string(),
+            ])
+        );
+        assert_eq!(constraints.validate(), Ok(()));
+        assert!(serde_json::to_value(&constraints)
+            .unwrap()
+            .get("dropped_filters")
+            .is_some());
+
+        let decoded = EventListConstraints::from_query_string(
+            "event_classes=refunds&event_types=refund_failed&event_types=payment_failed",
+        )
+        .unwrap();
+        assert_eq!(
+            decoded.event_types,
+            Some(HashSet::from([EventType::RefundFailed]))
+        );
+        assert_eq!(
+            decoded.dropped_filters,
+            Some(vec!["event_types: payment_failed".to_string()])
+        );
+        assert!(!decoded
+            .to_query_string()
+            .unwrap()
+            .contains("dropped_filters"));
+    }
+
+    #[test]
+    fn test_consistent_or_disjoint_event_types_are_not_normalized() {
+        let consistent = serde_json::from_str::<EventListConstraints>(
+            r#"{"event_classes":["payments","refunds"],"event_types":["payment_succeeded","refund_failed"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            consistent.event_types.map(|event_types| event_types.len()),
+            Some(2)
+        );
+        assert_eq!(consistent.dropped_filters, None);
+
+        // Dropping all the event types would widen the filter, so these are left to be rejected
+        let disjoint = serde_json::from_str::<EventListConstraints>(
+            r#"{"event_classes":["refunds"],"event_types":["payment_succeeded"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            disjoint.event_types,
+            Some(HashSet::from([EventType::PaymentSucceeded]))
+        );
+        assert_eq!(disjoint.dropped_filters, None);
+
+        let without_classes = serde_json::from_str::<EventListConstraints>(
+            r#"{"event_types":["payment_succeeded"],"dropped_filters":["event_types: refund_failed"]}"#,
+        )
+        .unwrap();
+        assert_eq!(without_classes.dropped_filters, None);
+    }
+}
@@ -0,0 +1,2021 @@
+//! The request sent when delivering a webhook and the response received, as stored for each
+//! delivery attempt.
+
+use std::collections::HashSet;
+
+use common_enums::{EventClass, WebhookDeliveryErrorCategory, WebhookSignatureAlgorithm};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{
+    IDEMPOTENCY_KEY_HEADER, MASKED_VALUE_PLACEHOLDER, PAYLOAD_VERSION_HEADER,
+    WEBHOOK_DELIVERY_SLA_MS,
+};
+
+/// The pattern matching email addresses, masked by the default [`PiiRedactionRules`].
+const EMAIL_REDACTION_PATTERN: &str = r"(?i)[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)+";
+
+/// The pattern matching PAN-like sequences of 13 to 19 digits, optionally separated by spaces or
+/// hyphens, masked by the default [`PiiRedactionRules`].
+const PAN_REDACTION_PATTERN: &str = r"\b\d(?:[ -]?\d){12,18}\b";
+
+/// The request information (headers and body) sent in the webhook.
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+pub struct OutgoingWebhookRequestContent {
+    /// The request body sent in the webhook.
+    #[schema(value_type = String)]
+    #[serde(alias = "payload")]
+    pub body: Secret<String>,
+
+    /// The request headers sent in the webhook.
+    #[schema(
+        value_type = Vec<(String, String)>,
+        example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
+    ]
+    pub headers: Vec<(String, Secret<String>)>,
+
+    /// The URL of the endpoint the webhook was delivered to.
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks")]
+    pub delivery_url: Option<Secret<String>>,
+
+    /// The HTTP method used to send the webhook. Webhooks sent before the method was recorded were
+    /// sent using `POST`.
+    #[schema(value_type = Option<String>, example = "POST")]
+    pub http_method: Option<common_utils::request::Method>,
+
+    /// Indicates whether the request body was truncated before being stored.
+    #[serde(default)]
+    pub is_truncated: bool,
+
+    /// The idempotency key sent in the [`IDEMPOTENCY_KEY_HEADER`] request header, if any.
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+
+    /// The `Content-Encoding` the request body was sent with, such as `gzip`. The stored `body` is
+    /// always the decoded text, so that it remains readable, while this records the encoding that
+    /// was actually applied to the bytes sent. Requests stored before the encoding was recorded
+    /// were sent without any encoding.
+    #[schema(example = "gzip")]
+    #[serde(default)]
+    pub content_encoding: Option<String>,
+
+    /// The number of header values that were redacted before the request was stored. Request
+    /// headers are currently stored as sent, since they are reused when retrying the webhook.
+    #[schema(example = 0)]
+    #[serde(default)]
+    pub redacted_header_count: usize,
+
+    /// The schema version of the webhook payload, taken from the [`PAYLOAD_VERSION_HEADER`]
+    /// request header or the `version` field of the request body, if any.
+    #[schema(example = "2024-06-01")]
+    #[serde(default)]
+    pub payload_version: Option<String>,
+
+    /// Indicates whether the request body was omitted when the request was stored, since request
+    /// bodies are not persisted for the event class of the webhook. The stored `body` is empty in
+    /// that case.
+    #[serde(default)]
+    pub body_omitted: bool,
+
+    /// Indicates whether personally identifiable information was masked in the request body when
+    /// the request was stored. The stored `body` no longer matches the body sent in that case.
+    #[serde(default)]
+    pub body_redacted: bool,
+
+    /// The algorithm used to sign the request, if it was signed. Requests stored before the
+    /// algorithm was recorded are verified using `hmac_sha256`.
+    #[schema(example = "hmac_sha512")]
+    #[serde(default)]
+    pub signature_algorithm: Option<WebhookSignatureAlgorithm>,
+}
+
+impl OutgoingWebhookRequestContent {
+    /// Returns the value of the specified request header, where the header name is matched
+    /// case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&Secret<String>> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the value of the `Content-Type` request header.
+    pub fn content_type(&self) -> Option<String> {
+        self.header("content-type")
+            .map(|content_type| content_type.peek().to_owned())
+    }
+
+    /// Indicates whether the request body was sent compressed, that is, with a `content_encoding`
+    /// other than `identity`.
+    pub fn is_compressed(&self) -> bool {
+        self.content_encoding
+            .as_deref()
+            .is_some_and(|content_encoding| {
+                let content_encoding = content_encoding.trim();
+                !content_encoding.is_empty() && !content_encoding.eq_ignore_ascii_case("identity")
+            })
+    }
+
+    /// Returns the idempotency key sent in the webhook, falling back to the
+    /// [`IDEMPOTENCY_KEY_HEADER`] request header for requests stored before the key was recorded.
+    pub fn get_idempotency_key(&self) -> Option<String> {
+        self.idempotency_key.clone().or_else(|| {
+            self.header(IDEMPOTENCY_KEY_HEADER)
+                .map(|idempotency_key| idempotency_key.peek().to_owned())
+        })
+    }
+
+    /// Returns the schema version of the webhook payload, falling back to the
+    /// [`PAYLOAD_VERSION_HEADER`] request header and then to the top-level `version` field of the
+    /// request body, for requests stored before the version was recorded.
+    pub fn get_payload_version(&self) -> Option<String> {
+        self.payload_version
+            .clone()
+            .or_else(|| {
+                self.header(PAYLOAD_VERSION_HEADER)
+                    .map(|payload_version| payload_version.peek().trim().to_owned())
+            })
+            .or_else(|| {
+                self.parsed_body().ok().and_then(|body| {
+                    body.get("version")
+                        .and_then(serde_json::Value::as_str)
+                        .map(|payload_version| payload_version.trim().to_owned())
+                })
+            })
+            .filter(|payload_version| !payload_version.is_empty())
+    }
+
+    /// Checks whether the schema version of the webhook payload matches the specified version.
+    pub fn is_payload_version_matching(&self, payload_version: Option<&str>) -> bool {
+        payload_version.map_or(true, |payload_version| {
+            self.get_payload_version().as_deref() == Some(payload_version)
+        })
+    }
+
+    /// Truncates the request body to at most `max_bytes`, appending a marker with the number of
+    /// bytes removed. The body is cut on a character boundary, so that the truncated body
+    /// remains valid UTF-8.
+    pub fn truncate(self, max_bytes: usize) -> Self {
+        let (body, is_truncated) = truncate_body(self.body, max_bytes);
+
+        Self {
+            body,
+            is_truncated: self.is_truncated || is_truncated,
+            ..self
+        }
+    }
+
+    /// Returns the hex-encoded SHA-256 digest of the request body, which can be compared across
+    /// delivery attempts to confirm that they were sent with identical payloads.
+    pub fn body_sha256(&self) -> String {
+        use common_utils::crypto::GenerateDigest;
+
+        // Computing a SHA-256 digest never fails, the `Result` is part of the trait signature
+        common_utils::crypto::Sha256
+            .generate_digest(self.body.peek().as_bytes())
+            .map(hex::encode)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of bytes stored for the request, summing the lengths of the body and of
+    /// the names and values of the headers.
+    pub fn stored_bytes(&self) -> usize {
+        self.body
+            .peek()
+            .len()
+            .saturating_add(headers_stored_bytes(&self.headers))
+    }
+
+    /// Exposes the first `max_chars` characters of the request body for display, with the
+    /// remainder replaced by `…`. This reveals part of the body unmasked, and must only be used
+    /// where a preview of the body is intentionally shown.
+    pub fn body_preview(&self, max_chars: usize) -> String {
+        body_preview(&self.body, max_chars)
+    }
+
+    /// Exposes the request body for display, indented when the `Content-Type` of the request is
+    /// JSON. Bodies of other content types, and JSON bodies which fail to parse, such as truncated
+    /// bodies, are returned unchanged. Returns `None` for empty bodies. This reveals the body
+    /// unmasked, and must only be used where the body is intentionally shown.
+    pub fn pretty_body(&self) -> Option<String> {
+        let body = self.body.peek();
+        if body.is_empty() {
+            return None;
+        }
+
+        let is_json = self
+            .content_type()
+            .is_some_and(|content_type| is_json_content_type(&content_type));
+        let pretty_body = is_json
+            .then(|| {
+                self.parsed_body()
+                    .ok()
+                    .and_then(|body| serde_json::to_string_pretty(&body).ok())
+            })
+            .flatten();
+
+        Some(pretty_body.unwrap_or_else(|| body.to_owned()))
+    }
+
+    /// Parses the request body as JSON. The parsed value is not masked, and must not be logged.
+    /// Truncated bodies are no longer valid JSON, and fail to parse.
+    pub fn parsed_body(&self) -> Result<serde_json::Value, serde_json::Error> {
+        self.parse_as()
+    }
+
+    /// Parses the request body as JSON into the specified type. The parsed value is not masked,
+    /// and must not be logged. Truncated bodies are no longer valid JSON, and fail to parse.
+    pub fn parse_as<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(self.body.peek())
+    }
+
+    /// Returns the amount and currency of the payment or refund in the request body. Both are
+    /// `None` for events of other classes, and for bodies which cannot be parsed.
+    pub fn payment_amount(
+        &self,
+        event_class: EventClass,
+    ) -> (
+        Option<common_utils::types::MinorUnit>,
+        Option<common_enums::Currency>,
+    ) {
+        if !matches!(event_class, EventClass::Payments | EventClass::Refunds) {
+            return (None, None);
+        }
+        let Ok(body) = self.parsed_body() else {
+            return (None, None);
+        };
+        let field = |name: &str| {
+            body.pointer(&format!("/content/object/{name}"))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        (
+            serde_json::from_value(field("amount")).ok().flatten(),
+            serde_json::from_value(field("currency")).ok().flatten(),
+        )
+    }
+
+    /// Returns the signature sent in the specified request header. The header name is a parameter
+    /// since profiles may be configured to send the signature under different header names.
+    pub fn signature(&self, header_name: &str) -> Option<Secret<String>> {
+        self.header(header_name).cloned()
+    }
+
+    /// Verifies the hex encoded signature sent in the specified request header against the request
+    /// body, using the algorithm in `signature_algorithm` (HMAC-SHA256 if not recorded). The
+    /// secret is the shared key for HMAC algorithms, and the public key of the signer for Ed25519.
+    /// HMAC comparisons are performed in constant time. Returns `false` when the header is missing
+    /// or is not valid hex.
+    ///
+    /// The signature is computed over the bytes that were sent over the wire. For compressed
+    /// requests (see [`Self::is_compressed`]), those are the encoded bytes rather than the decoded
+    /// `body` that is stored, so the body must be re-encoded with `content_encoding` before it can
+    /// be verified.
+    pub fn verify_signature(&self, header_name: &str, secret: &[u8]) -> bool {
+        self.signature(header_name)
+            .and_then(|signature| hex::decode(signature.peek()).ok())
+            .is_some_and(|signature| {
+                let algorithm: &dyn common_utils::crypto::VerifySignature =
+                    match self.signature_algorithm {
+                        None | Some(WebhookSignatureAlgorithm::HmacSha256) => {
+                            &common_utils::crypto::HmacSha256
+                        }
+                        Some(WebhookSignatureAlgorithm::HmacSha512) => {
+                            &common_utils::crypto::HmacSha512
+                        }
+                        Some(WebhookSignatureAlgorithm::Ed25519) => &common_utils::crypto::Ed25519,
+                    };
+
+                algorithm
+                    .verify_signature(secret, &signature, self.body.peek().as_bytes())
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Reports the request headers added, removed or changed in the `other` request compared to
+    /// this request, such as a signature regenerated for a retry. Header names are matched
+    /// case-insensitively, and only the first value of a repeated header is compared.
+    pub fn diff_request(&self, other: &Self) -> Vec<HeaderDiff> {
+        let mut seen_headers = HashSet::new();
+        let mut diffs = Vec::new();
+        for (name, value) in &self.headers {
+            if !seen_headers.insert(name.to_ascii_lowercase()) {
+                continue;
+            }
+            match other.header(name) {
+                None => diffs.push(HeaderDiff::Removed {
+                    name: name.clone(),
+                    value: value.clone(),
+                }),
+                Some(other_value) if other_value != value => diffs.push(HeaderDiff::Changed {
+                    name: name.clone(),
+                    old_value: value.clone(),
+                    new_value: other_value.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for (name, value) in &other.headers {
+            if seen_headers.insert(name.to_ascii_lowercase()) {
+                diffs.push(HeaderDiff::Added {
+                    name: name.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        diffs
+    }
+
+    /// Indicates whether the `other` request was sent with the same body as this request.
+    pub fn is_body_equal(&self, other: &Self) -> bool {
+        self.body == other.body
+    }
+
+    /// Returns the request headers sorted case-insensitively by name, and by value for headers
+    /// sent multiple times, so that requests sent with the same headers in a different order
+    /// compare equal.
+    pub fn normalized_headers(&self) -> Vec<(String, Secret<String>)> {
+        let mut headers = self.headers.clone();
+        headers.sort_by(|(name, value), (other_name, other_value)| {
+            name.to_ascii_lowercase()
+                .cmp(&other_name.to_ascii_lowercase())
+                .then_with(|| value.peek().cmp(other_value.peek()))
+        });
+        headers
+    }
+
+    /// Replaces the request headers with the [normalized headers][Self::normalized_headers], so
+    /// that stored requests are diff-stable.
+    pub fn normalize_headers(self) -> Self {
+        Self {
+            headers: self.normalized_headers(),
+            ..self
+        }
+    }
+
+    /// Masks the personally identifiable information in the request body as per the specified
+    /// rules, replacing it with [`MASKED_VALUE_PLACEHOLDER`]. This is meant to be applied to the
+    /// copy of the request being stored, since the masked body no longer matches the signature
+    /// sent with the webhook. Requests whose body was masked are marked as such, so that they are
+    /// not resent as stored.
+    pub fn redact_pii(self, rules: &PiiRedactionRules) -> Self {
+        let body = rules.redact(self.body.peek());
+        let body_redacted = self.body_redacted || body != *self.body.peek();
+
+        Self {
+            body: Secret::new(body),
+            body_redacted,
+            ..self
+        }
+    }
+
+    /// Drops the request body, keeping the rest of the request information. This is meant to be
+    /// applied to the copy of the request being stored, for event classes whose request bodies
+    /// are not persisted.
+    pub fn omit_body(self) -> Self {
+        Self {
+            body: Secret::new(String::new()),
+            is_truncated: false,
+            body_omitted: true,
+            ..self
+        }
+    }
+}
+
+/// The rules for masking personally identifiable information, such as email addresses and card
+/// numbers, in webhook bodies before they are stored.
+#[derive(Clone, Debug)]
+pub struct PiiRedactionRules {
+    /// The patterns whose matches are masked wherever they occur in the body.
+    pub patterns: Vec<regex::Regex>,
+
+    /// The JSON pointers, such as `/content/object/customer/email`, to the values masked in their
+    /// entirety in JSON bodies, irrespective of their contents.
+    pub json_pointers: Vec<String>,
+}
+
+impl Default for PiiRedactionRules {
+    /// Masks email addresses and PAN-like sequences of digits.
+    fn default() -> Self {
+        Self {
+            patterns: [EMAIL_REDACTION_PATTERN, PAN_REDACTION_PATTERN]
+                .into_iter()
+                .filter_map(|pattern| regex::Regex::new(pattern).ok())
+                .collect(),
+            json_pointers: Vec::new(),
+        }
+    }
+}
+
+impl PiiRedactionRules {
+    /// Masks the values at the JSON pointers, if the body is valid JSON, and then the matches of
+    /// the patterns. Bodies are only re-serialized when a value at one of the JSON pointers is
+    /// masked, so that the layout of the body is otherwise preserved.
+    fn redact(&self, body: &str) -> String {
+        let body = (!self.json_pointers.is_empty())
+            .then(|| serde_json::from_str::<serde_json::Value>(body).ok())
+            .flatten()
+            .and_then(|mut value| {
+                let mut is_masked = false;
+                for json_pointer in &self.json_pointers {
+                    if let Some(masked_value) = value.pointer_mut(json_pointer) {
+                        *masked_value = serde_json::Value::from(MASKED_VALUE_PLACEHOLDER);
+                        is_masked = true;
+                    }
+                }
+                is_masked
+                    .then(|| serde_json::to_string(&value).ok())
+                    .flatten()
+            })
+            .unwrap_or_else(|| body.to_owned());
+
+        self.patterns.iter().fold(body, |body, pattern| {
+            pattern
+                .replace_all(&body, MASKED_VALUE_PLACEHOLDER)
+                .into_owned()
+        })
+    }
+}
+
+/// The request information of a delivery attempt when returned on its own, such as when viewing
+/// the raw request sent, along with the merchant it was sent for.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OutgoingWebhookRequestContentResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The request information (headers and body) sent in the webhook.
+    #[serde(flatten)]
+    pub content: OutgoingWebhookRequestContent,
+}
+
+impl common_utils::events::ApiEventMetric for OutgoingWebhookRequestContentResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// A difference in the request headers sent in two delivery attempts of a webhook.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HeaderDiff {
+    /// The header was only sent in the other request.
+    Added { name: String, value: Secret<String> },
+    /// The header was not sent in the other request.
+    Removed { name: String, value: Secret<String> },
+    /// The header was sent with a different value in the other request.
+    Changed {
+        name: String,
+        old_value: Secret<String>,
+        new_value: Secret<String>,
+    },
+}
+
+/// The response information (headers, body and status code) received for the webhook sent.
+#[derive(serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct OutgoingWebhookResponseContent {
+    /// The response body received for the webhook sent.
+    #[schema(value_type = Option<String>)]
+    #[serde(alias = "payload")]
+    pub body: Option<Secret<String>>,
+
+    /// The size (in bytes) of the response body received for the webhook sent. This is recorded
+    /// even when the body itself is not stored, and may not be available for webhooks sent
+    /// before the size was recorded.
+    #[schema(example = 1024)]
+    pub body_size_bytes: Option<usize>,
+
+    /// The response headers received for the webhook sent.
+    #[schema(
+        value_type = Option<Vec<(String, String)>>,
+        example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
+    ]
+    pub headers: Option<Vec<(String, Secret<String>)>>,
+
+    /// The HTTP status code for the webhook sent.
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// Error message in case any error occurred when trying to deliver the webhook.
+    #[schema(example = 200)]
+    pub error_message: Option<String>,
+
+    /// The time taken (in milliseconds) to receive a response for the webhook sent. This may not
+    /// be available for webhooks sent before the latency was recorded.
+    #[schema(example = 350)]
+    pub response_latency_ms: Option<u32>,
+
+    /// The class of error encountered when trying to deliver the webhook, if any. The
+    /// `error_message` field carries the human-readable details.
+    pub error_category: Option<WebhookDeliveryErrorCategory>,
+
+    /// Indicates whether the response body was truncated before being stored.
+    #[serde(default)]
+    pub is_truncated: bool,
+
+    /// The number of response header values that were dropped before the response was stored,
+    /// such as `set-cookie` headers, which may carry sensitive data.
+    #[schema(example = 2)]
+    #[serde(default)]
+    pub redacted_header_count: usize,
+}
+
+impl std::fmt::Debug for OutgoingWebhookRequestContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutgoingWebhookRequestContent")
+            .field("body", &RedactedBody(&self.body))
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("delivery_url", &self.delivery_url)
+            .field("http_method", &self.http_method)
+            .field("is_truncated", &self.is_truncated)
+            .field("idempotency_key", &self.idempotency_key)
+            .field("content_encoding", &self.content_encoding)
+            .field("redacted_header_count", &self.redacted_header_count)
+            .field("payload_version", &self.payload_version)
+            .field("body_omitted", &self.body_omitted)
+            .field("body_redacted", &self.body_redacted)
+            .field("signature_algorithm", &self.signature_algorithm)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for OutgoingWebhookResponseContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutgoingWebhookResponseContent")
+            .field("body", &self.body.as_ref().map(RedactedBody))
+            .field("body_size_bytes", &self.body_size_bytes)
+            .field("headers", &self.headers.as_deref().map(RedactedHeaders))
+            .field("status_code", &self.status_code)
+            .field("error_message", &self.error_message)
+            .field("response_latency_ms", &self.response_latency_ms)
+            .field("error_category", &self.error_category)
+            .field("is_truncated", &self.is_truncated)
+            .field("redacted_header_count", &self.redacted_header_count)
+            .finish()
+    }
+}
+
+/// Cuts the body to at most `max_bytes` on a character boundary and appends a marker with the
+/// number of bytes removed, returning whether the body was truncated.
+fn truncate_body(body: Secret<String>, max_bytes: usize) -> (Secret<String>, bool) {
+    let mut body = body.expose();
+    if body.len() <= max_bytes {
+        return (Secret::new(body), false);
+    }
+
+    let cut_index = (0..=max_bytes)
+        .rev()
+        .find(|index| body.is_char_boundary(*index))
+        .unwrap_or_default();
+    let truncated_bytes = body.len() - cut_index;
+    body.truncate(cut_index);
+    body.push_str(&format!("…[truncated {truncated_bytes} bytes]"));
+
+    (Secret::new(body), true)
+}
+
+/// Returns the first `max_chars` characters of the body, appending `…` if any characters remain.
+fn body_preview(body: &Secret<String>, max_chars: usize) -> String {
+    let body = body.peek();
+    match body.char_indices().nth(max_chars) {
+        Some((cut_index, _)) => body
+            .get(..cut_index)
+            .map(|preview| format!("{preview}…"))
+            .unwrap_or_default(),
+        None => body.to_owned(),
+    }
+}
+
+/// Returns the number of bytes stored for the headers, summing the lengths of their names and
+/// values.
+fn headers_stored_bytes(headers: &[(String, Secret<String>)]) -> usize {
+    headers.iter().fold(0, |total, (name, value)| {
+        total
+            .saturating_add(name.len())
+            .saturating_add(value.peek().len())
+    })
+}
+
+/// Formats a webhook body as its length alone, so that the body never ends up in logs.
+struct RedactedBody<'a>(&'a Secret<String>);
+
+impl std::fmt::Debug for RedactedBody<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Secret({} bytes)", self.0.peek().len())
+    }
+}
+
+/// Formats webhook headers with their names alone, so that header values never end up in logs.
+struct RedactedHeaders<'a>(&'a [(String, Secret<String>)]);
+
+impl std::fmt::Debug for RedactedHeaders<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, _)| (name, format_args!("***"))))
+            .finish()
+    }
+}
+
+impl OutgoingWebhookResponseContent {
+    /// Truncates the response body to at most `max_bytes`, appending a marker with the number of
+    /// bytes removed. The body is cut on a character boundary, so that the truncated body
+    /// remains valid UTF-8. The size of the body received is retained in `body_size_bytes`.
+    pub fn truncate(self, max_bytes: usize) -> Self {
+        let body_size_bytes = self.get_body_size_bytes();
+        let (body, is_truncated) = match self.body {
+            Some(body) => {
+                let (body, is_truncated) = truncate_body(body, max_bytes);
+                (Some(body), is_truncated)
+            }
+            None => (None, false),
+        };
+
+        Self {
+            body,
+            body_size_bytes,
+            is_truncated: self.is_truncated || is_truncated,
+            ..self
+        }
+    }
+
+    /// Retains only the response headers whose names are present in `allowed`, dropping the rest.
+    /// Header names are compared case-insensitively. The number of headers dropped is added to
+    /// `redacted_header_count`.
+    pub fn retain_headers(self, allowed: &HashSet<String>) -> Self {
+        let received_header_count = self.headers.as_ref().map_or(0, Vec::len);
+        let headers = self.headers.map(|headers| {
+            headers
+                .into_iter()
+                .filter(|(name, _)| {
+                    allowed
+                        .iter()
+                        .any(|allowed_name| allowed_name.eq_ignore_ascii_case(name))
+                })
+                .collect::<Vec<_>>()
+        });
+        let dropped_header_count =
+            received_header_count.saturating_sub(headers.as_ref().map_or(0, Vec::len));
+
+        Self {
+            headers,
+            redacted_header_count: self
+                .redacted_header_count
+                .saturating_add(dropped_header_count),
+            ..self
+        }
+    }
+
+    /// Returns the number of bytes stored for the response, summing the lengths of the stored
+    /// body and of the names and values of the headers. Unlike [`Self::get_body_size_bytes`],
+    /// this counts the body as stored, after truncation.
+    pub fn stored_bytes(&self) -> usize {
+        let body_bytes = self.body.as_ref().map_or(0, |body| body.peek().len());
+        let headers_bytes = self.headers.as_deref().map_or(0, headers_stored_bytes);
+
+        body_bytes.saturating_add(headers_bytes)
+    }
+
+    /// Exposes the first `max_chars` characters of the response body for display, with the
+    /// remainder replaced by `…`. This reveals part of the body unmasked, and must only be used
+    /// where a preview of the body is intentionally shown. Returns an empty string when no
+    /// response body was received.
+    pub fn body_preview(&self, max_chars: usize) -> String {
+        self.body
+            .as_ref()
+            .map(|body| body_preview(body, max_chars))
+            .unwrap_or_default()
+    }
+
+    /// Checks whether the HTTP status code received lies within the specified inclusive bounds.
+    /// Responses without a status code never match when either bound is specified.
+    pub fn is_status_code_within(
+        &self,
+        status_code_gte: Option<u16>,
+        status_code_lte: Option<u16>,
+    ) -> bool {
+        if status_code_gte.is_none() && status_code_lte.is_none() {
+            return true;
+        }
+
+        self.status_code.is_some_and(|status_code| {
+            status_code_gte.map_or(true, |gte| status_code >= gte)
+                && status_code_lte.map_or(true, |lte| status_code <= lte)
+        })
+    }
+
+    /// Checks whether the delivery latency recorded is at least the specified number of
+    /// milliseconds. Responses without a recorded latency never match when a bound is specified.
+    pub fn is_latency_at_least(&self, latency_ms_gte: Option<u32>) -> bool {
+        latency_ms_gte.map_or(true, |latency_ms_gte| {
+            self.response_latency_ms
+                .is_some_and(|response_latency_ms| response_latency_ms >= latency_ms_gte)
+        })
+    }
+
+    /// Checks whether the delivery latency recorded exceeds [`WEBHOOK_DELIVERY_SLA_MS`]. Returns
+    /// `None` if no latency was recorded for the response.
+    pub fn is_sla_breached(&self) -> Option<bool> {
+        self.response_latency_ms
+            .map(|response_latency_ms| response_latency_ms > WEBHOOK_DELIVERY_SLA_MS)
+    }
+
+    /// Returns the size (in bytes) of the response body received. The size recorded at delivery
+    /// time is preferred, falling back to the length of the stored body for webhooks sent before
+    /// the size was recorded.
+    pub fn get_body_size_bytes(&self) -> Option<usize> {
+        self.body_size_bytes
+            .or_else(|| self.body.as_ref().map(|body| body.peek().len()))
+    }
+
+    /// Checks whether the response body received is at least the specified number of bytes.
+    /// Responses without a body size never match when a bound is specified.
+    pub fn is_body_size_at_least(&self, body_size_gte: Option<usize>) -> bool {
+        body_size_gte.map_or(true, |body_size_gte| {
+            self.get_body_size_bytes()
+                .is_some_and(|body_size_bytes| body_size_bytes >= body_size_gte)
+        })
+    }
+
+    /// Checks whether the error encountered belongs to one of the specified error categories.
+    /// Responses without an error category never match when a non-empty set is specified.
+    pub fn is_error_category_within(
+        &self,
+        error_categories: Option<&HashSet<WebhookDeliveryErrorCategory>>,
+    ) -> bool {
+        match error_categories {
+            Some(error_categories) if !error_categories.is_empty() => self
+                .error_category
+                .is_some_and(|error_category| error_categories.contains(&error_category)),
+            _ => true,
+        }
+    }
+
+    /// Checks whether the presence of an error message matches the specified value.
+    pub fn is_error_message_present(&self, has_error: Option<bool>) -> bool {
+        has_error.map_or(true, |has_error| self.error_message.is_some() == has_error)
+    }
+
+    /// Checks whether the error message contains the specified substring, ignoring case. Responses
+    /// without an error message never match a substring.
+    pub fn is_error_message_containing(&self, error_message_contains: Option<&str>) -> bool {
+        error_message_contains.map_or(true, |error_message_contains| {
+            self.error_message.as_ref().is_some_and(|error_message| {
+                error_message
+                    .to_lowercase()
+                    .contains(&error_message_contains.to_lowercase())
+            })
+        })
+    }
+
+    /// Checks whether the presence of an HTTP response, indicated by its status code, matches the
+    /// specified value.
+    pub fn is_response_received(&self, response_received: Option<bool>) -> bool {
+        response_received.map_or(true, |response_received| {
+            self.status_code.is_some() == response_received
+        })
+    }
+
+    /// Returns the media type of the response, taken from its `content-type` header without any
+    /// parameters such as `charset`, in lowercase.
+    pub fn get_content_type(&self) -> Option<String> {
+        self.headers.as_ref().and_then(|headers| {
+            headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .and_then(|(_, value)| value.peek().split(';').next())
+                .map(|content_type| content_type.trim().to_ascii_lowercase())
+                .filter(|content_type| !content_type.is_empty())
+        })
+    }
+
+    /// Checks whether the media type of the response matches the specified content type, ignoring
+    /// case and any parameters. Responses without a content type never match a content type.
+    pub fn is_content_type_matching(&self, content_type: Option<&str>) -> bool {
+        content_type.map_or(true, |content_type| {
+            self.get_content_type()
+                .is_some_and(|response_content_type| {
+                    content_type.split(';').next().is_some_and(|content_type| {
+                        content_type
+                            .trim()
+                            .eq_ignore_ascii_case(&response_content_type)
+                    })
+                })
+        })
+    }
+}
+
+/// Checks whether the media type of the specified `Content-Type` is JSON, either
+/// `application/json` or a type with the `+json` structured syntax suffix.
+fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    media_type == "application/json"
+        || (media_type.starts_with("application/") && media_type.ends_with("+json"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::EventType;
+
+    use super::*;
+    use crate::webhook_events::{
+        test_utils::{list_item, merchant_id, request_with_headers, response_with_status_code},
+        DEFAULT_RESPONSE_HEADER_ALLOWLIST,
+    };
+
+    #[test]
+    fn test_request_content_response_api_event_type() {
+        use common_utils::events::ApiEventMetric;
+
+        let response = OutgoingWebhookRequestContentResponse {
+            merchant_id: merchant_id(),
+            content: request_with_headers(vec![("Content-Type", "application/json")]),
+        };
+        assert!(matches!(
+            response.get_api_event_type(),
+            Some(common_utils::events::ApiEventsType::Events { merchant_id: response_merchant_id })
+                if response_merchant_id == merchant_id()
+        ));
+
+        let value = serde_json::to_value(&response).unwrap();
+        assert!(value.get("merchant_id").is_none());
+        assert!(value.get("headers").is_some());
+    }
+
+    #[test]
+    fn test_request_content_without_delivery_url_deserializes() {
+        let request_content = serde_json::from_str::<OutgoingWebhookRequestContent>(
+            r#"{"body":"{}","headers":[["content-type","application/json"]]}"#,
+        )
+        .unwrap();
+
+        assert!(request_content.delivery_url.is_none());
+        assert!(request_content.http_method.is_none());
+    }
+
+    #[test]
+    fn test_response_content_without_latency_deserializes() {
+        let response_content = serde_json::from_str::<OutgoingWebhookResponseContent>(
+            r#"{"body":"ok","headers":[],"status_code":200,"error_message":null}"#,
+        )
+        .unwrap();
+
+        assert!(response_content.response_latency_ms.is_none());
+        assert!(response_content.is_latency_at_least(None));
+        assert!(!response_content.is_latency_at_least(Some(0)));
+    }
+
+    #[test]
+    fn test_response_content_latency_round_trip() {
+        let response_content = OutgoingWebhookResponseContent {
+            response_latency_ms: Some(1500),
+            ..response_with_status_code(Some(200))
+        };
+        let serialized = serde_json::to_string(&response_content).unwrap();
+        let deserialized =
+            serde_json::from_str::<OutgoingWebhookResponseContent>(&serialized).unwrap();
+
+        assert_eq!(deserialized.response_latency_ms, Some(1500));
+        assert!(deserialized.is_latency_at_least(Some(1500)));
+        assert!(!deserialized.is_latency_at_least(Some(1501)));
+    }
+
+    #[test]
+    fn test_sla_breached_by_response_latency() {
+        for (response_latency_ms, expected_sla_breached) in [
+            (Some(350), Some(false)),
+            (Some(WEBHOOK_DELIVERY_SLA_MS), Some(false)),
+            (Some(WEBHOOK_DELIVERY_SLA_MS + 1), Some(true)),
+            (None, None),
+        ] {
+            let response_content = OutgoingWebhookResponseContent {
+                response_latency_ms,
+                ..response_with_status_code(Some(200))
+            };
+            assert_eq!(response_content.is_sla_breached(), expected_sla_breached);
+        }
+    }
+
+    #[test]
+    fn test_body_size_of_stored_body() {
+        let response_content = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("<html>Not Found</html>".to_string())),
+            body_size_bytes: Some(22),
+            ..response_with_status_code(Some(404))
+        };
+        let legacy_response_content = serde_json::from_str::<OutgoingWebhookResponseContent>(
+            r#"{"body":"<html>Not Found</html>","headers":[],"status_code":404}"#,
+        )
+        .unwrap();
+
+        assert!(legacy_response_content.body_size_bytes.is_none());
+        for response_content in [response_content, legacy_response_content] {
+            assert_eq!(response_content.get_body_size_bytes(), Some(22));
+            assert!(response_content.is_body_size_at_least(Some(22)));
+            assert!(!response_content.is_body_size_at_least(Some(23)));
+        }
+    }
+
+    #[test]
+    fn test_body_size_without_stored_body() {
+        let response_content = OutgoingWebhookResponseContent {
+            body_size_bytes: Some(4 * 1024 * 1024),
+            ..response_with_status_code(Some(502))
+        };
+        let serialized = serde_json::to_string(&response_content).unwrap();
+        let deserialized =
+            serde_json::from_str::<OutgoingWebhookResponseContent>(&serialized).unwrap();
+
+        assert!(deserialized.body.is_none());
+        assert_eq!(deserialized.get_body_size_bytes(), Some(4 * 1024 * 1024));
+        assert!(deserialized.is_body_size_at_least(Some(1024 * 1024)));
+
+        let no_response = response_with_status_code(None);
+        assert!(no_response.get_body_size_bytes().is_none());
+        assert!(no_response.is_body_size_at_least(None));
+        assert!(!no_response.is_body_size_at_least(Some(0)));
+    }
+
+    #[test]
+    fn test_diff_request_reports_added_removed_and_changed_headers() {
+        let original = request_with_headers(vec![
+            ("Content-Type", "application/json"),
+            ("X-Webhook-Signature-512", "signature_1"),
+            ("X-Request-Id", "req_1"),
+        ]);
+        let retry = request_with_headers(vec![
+            ("content-type", "application/json"),
+            ("X-Webhook-Signature-512", "signature_2"),
+            ("X-Retry-Attempt", "1"),
+        ]);
+
+        assert_eq!(
+            original.diff_request(&retry),
+            vec![
+                HeaderDiff::Changed {
+                    name: "X-Webhook-Signature-512".to_string(),
+                    old_value: Secret::new("signature_1".to_string()),
+                    new_value: Secret::new("signature_2".to_string()),
+                },
+                HeaderDiff::Removed {
+                    name: "X-Request-Id".to_string(),
+                    value: Secret::new("req_1".to_string()),
+                },
+                HeaderDiff::Added {
+                    name: "X-Retry-Attempt".to_string(),
+                    value: Secret::new("1".to_string()),
+                },
+            ]
+        );
+        assert!(original.diff_request(&original).is_empty());
+        assert!(original.is_body_equal(&retry));
+        assert!(!original.is_body_equal(&OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"retried":true}"#.to_string()),
+            ..request_with_headers(Vec::new())
+        }));
+    }
+
+    #[test]
+    fn test_request_header_lookup_is_case_insensitive() {
+        let request_content = request_with_headers(vec![
+            ("Content-Type", "application/json"),
+            ("X-Webhook-Signature-512", "signature"),
+        ]);
+
+        assert_eq!(
+            request_content.content_type(),
+            Some("application/json".to_string())
+        );
+        assert_eq!(
+            request_content
+                .header("x-webhook-signature-512")
+                .map(|value| value.peek().as_str()),
+            Some("signature")
+        );
+        assert_eq!(
+            request_content
+                .header("CONTENT-TYPE")
+                .map(|value| value.peek().as_str()),
+            Some("application/json")
+        );
+    }
+
+    #[test]
+    fn test_request_header_lookup_for_absent_headers() {
+        let request_content = request_with_headers(vec![("user-agent", "Hyperswitch")]);
+
+        assert!(request_content.content_type().is_none());
+        assert!(request_content.header("x-webhook-signature-512").is_none());
+        assert!(request_with_headers(Vec::new())
+            .header("user-agent")
+            .is_none());
+    }
+
+    #[test]
+    fn test_idempotency_key_from_request_header() {
+        let request = request_with_headers(vec![
+            ("content-type", "application/json"),
+            ("X-Idempotency-Key", "evt_018e31720d1b7a2b82677d3032cab959"),
+        ]);
+        assert_eq!(
+            request.get_idempotency_key().as_deref(),
+            Some("evt_018e31720d1b7a2b82677d3032cab959")
+        );
+
+        let request = OutgoingWebhookRequestContent {
+            idempotency_key: Some("evt_recorded".to_string()),
+            ..request
+        };
+        assert_eq!(
+            request.get_idempotency_key().as_deref(),
+            Some("evt_recorded")
+        );
+    }
+
+    #[test]
+    fn test_payload_version_from_header_or_body() {
+        let request = request_with_headers(vec![
+            ("content-type", "application/json"),
+            ("X-Webhook-Version", "2024-06-01"),
+        ]);
+        assert_eq!(request.get_payload_version().as_deref(), Some("2024-06-01"));
+
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"version":"2023-10-16","content":{}}"#.to_string()),
+            ..request_with_headers(Vec::new())
+        };
+        assert_eq!(request.get_payload_version().as_deref(), Some("2023-10-16"));
+
+        let request = OutgoingWebhookRequestContent {
+            payload_version: Some("2025-01-01".to_string()),
+            ..request
+        };
+        assert_eq!(request.get_payload_version().as_deref(), Some("2025-01-01"));
+
+        let request = serde_json::from_str::<OutgoingWebhookRequestContent>(
+            r#"{"body":"{}","headers":[["x-webhook-version",""]]}"#,
+        )
+        .unwrap();
+        assert_eq!(request.payload_version, None);
+        assert_eq!(request.get_payload_version(), None);
+    }
+
+    #[test]
+    fn test_payload_version_filter() {
+        let request = request_with_headers(vec![("x-webhook-version", "2024-06-01")]);
+
+        assert!(request.is_payload_version_matching(Some("2024-06-01")));
+        assert!(!request.is_payload_version_matching(Some("2023-10-16")));
+        assert!(request.is_payload_version_matching(None));
+        assert!(!request_with_headers(Vec::new()).is_payload_version_matching(Some("2024-06-01")));
+    }
+
+    #[test]
+    fn test_redact_pii_preserves_json_structure() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(
+                r#"{"content":{"object":{"email":"jane.doe@example.com","card":{"number":"4242 4242 4242 4242","last4":"4242"},"amount":6540}}}"#
+                    .to_string(),
+            ),
+            ..request_with_headers(vec![("content-type", "application/json")])
+        }
+        .redact_pii(&PiiRedactionRules::default());
+
+        assert_eq!(
+            request.body.peek(),
+            r#"{"content":{"object":{"email":"***","card":{"number":"***","last4":"4242"},"amount":6540}}}"#
+        );
+        assert!(request.body_redacted);
+        let body = request.parsed_body().unwrap();
+        assert_eq!(body.pointer("/content/object/amount"), Some(&6540.into()));
+        assert_eq!(request.headers.len(), 1);
+    }
+
+    #[test]
+    fn test_redact_pii_by_json_pointer() {
+        let rules = PiiRedactionRules {
+            patterns: Vec::new(),
+            json_pointers: vec![
+                "/content/object/customer/name".to_string(),
+                "/content/object/missing".to_string(),
+            ],
+        };
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(
+                r#"{"content":{"object":{"customer":{"name":"Jane Doe","id":"cus_1"}}}}"#
+                    .to_string(),
+            ),
+            ..request_with_headers(Vec::new())
+        }
+        .redact_pii(&rules);
+        let body = request.parsed_body().unwrap();
+        assert_eq!(
+            body.pointer("/content/object/customer/name"),
+            Some(&"***".into())
+        );
+        assert_eq!(
+            body.pointer("/content/object/customer/id"),
+            Some(&"cus_1".into())
+        );
+        assert_eq!(body.pointer("/content/object/missing"), None);
+
+        // Patterns still apply to bodies which are not JSON
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new("contact jane.doe@example.com".to_string()),
+            ..request_with_headers(Vec::new())
+        }
+        .redact_pii(&PiiRedactionRules::default());
+        assert_eq!(request.body.peek(), "contact ***");
+
+        // Bodies without personally identifiable information are not marked as redacted
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"content":{"object":{"amount":6540}}}"#.to_string()),
+            ..request_with_headers(Vec::new())
+        }
+        .redact_pii(&PiiRedactionRules::default());
+        assert!(!request.body_redacted);
+    }
+
+    #[test]
+    fn test_is_compressed_by_content_encoding() {
+        let request = request_with_headers(Vec::new());
+        assert_eq!(request.content_encoding, None);
+        assert!(!request.is_compressed());
+
+        for (content_encoding, is_compressed) in [
+            ("gzip", true),
+            ("GZIP", true),
+            ("identity", false),
+            ("", false),
+        ] {
+            let request = OutgoingWebhookRequestContent {
+                content_encoding: Some(content_encoding.to_string()),
+                ..request_with_headers(Vec::new())
+            };
+            assert_eq!(request.is_compressed(), is_compressed, "{content_encoding}");
+        }
+
+        let request = serde_json::from_str::<OutgoingWebhookRequestContent>(
+            r#"{"body":"{}","headers":[],"content_encoding":"gzip"}"#,
+        )
+        .unwrap();
+        assert_eq!(request.content_encoding.as_deref(), Some("gzip"));
+        assert!(request.is_compressed());
+    }
+
+    #[test]
+    fn test_idempotency_key_without_request_header() {
+        let request = request_with_headers(vec![("content-type", "application/json")]);
+        assert_eq!(request.get_idempotency_key(), None);
+
+        let request = serde_json::from_str::<OutgoingWebhookRequestContent>(
+            r#"{"body":"{}","headers":[["content-type","application/json"]]}"#,
+        )
+        .unwrap();
+        assert_eq!(request.idempotency_key, None);
+        assert_eq!(request.get_idempotency_key(), None);
+    }
+
+    #[test]
+    fn test_body_preview_is_limited_to_max_chars() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new("héllo wörld 🦀".to_string()),
+            ..request_with_headers(Vec::new())
+        };
+        for max_chars in 0..13 {
+            let preview = request.body_preview(max_chars);
+            let visible = preview.strip_suffix('…').unwrap();
+
+            assert_eq!(visible.chars().count(), max_chars);
+            assert!(request.body.peek().starts_with(visible));
+        }
+        assert_eq!(request.body_preview(2), "hé…");
+
+        let response = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("ok🦀🦀".to_string())),
+            ..response_with_status_code(Some(200))
+        };
+        assert_eq!(response.body_preview(3), "ok🦀…");
+    }
+
+    #[test]
+    fn test_body_preview_of_short_bodies() {
+        let request = request_with_headers(Vec::new());
+        assert_eq!(request.body_preview(2), "{}");
+        assert_eq!(request.body_preview(100), "{}");
+
+        let response = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("ok🦀🦀".to_string())),
+            ..response_with_status_code(Some(200))
+        };
+        assert_eq!(response.body_preview(4), "ok🦀🦀");
+        assert_eq!(response_with_status_code(Some(200)).body_preview(4), "");
+    }
+
+    #[test]
+    fn test_pretty_body_indents_json_bodies() {
+        let body = r#"{"content":{"object":{"amount":6540}}}"#;
+        for content_type in [
+            "application/json",
+            "Application/JSON; charset=utf-8",
+            "application/merge-patch+json",
+        ] {
+            let request = OutgoingWebhookRequestContent {
+                body: Secret::new(body.to_string()),
+                ..request_with_headers(vec![("Content-Type", content_type)])
+            };
+            assert_eq!(
+                request.pretty_body().as_deref(),
+                Some(
+                    "{\n  \"content\": {\n    \"object\": {\n      \"amount\": 6540\n    }\n  }\n}"
+                ),
+                "content type: {content_type}"
+            );
+        }
+
+        // JSON bodies which fail to parse are returned unchanged
+        let truncated = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"content":{"obj"#.to_string()),
+            ..request_with_headers(vec![("content-type", "application/json")])
+        };
+        assert_eq!(
+            truncated.pretty_body().as_deref(),
+            Some(r#"{"content":{"obj"#)
+        );
+    }
+
+    #[test]
+    fn test_pretty_body_leaves_non_json_bodies_unchanged() {
+        let body = r#"{"content":{"object":{"amount":6540}}}"#;
+        for headers in [
+            vec![("Content-Type", "text/plain")],
+            vec![("Content-Type", "application/x-www-form-urlencoded")],
+            vec![("Content-Type", "text/json-like")],
+            Vec::new(),
+        ] {
+            let request = OutgoingWebhookRequestContent {
+                body: Secret::new(body.to_string()),
+                ..request_with_headers(headers.clone())
+            };
+            assert_eq!(
+                request.pretty_body().as_deref(),
+                Some(body),
+                "headers: {headers:?}"
+            );
+        }
+
+        let empty = OutgoingWebhookRequestContent {
+            body: Secret::new(String::new()),
+            ..request_with_headers(vec![("Content-Type", "application/json")])
+        };
+        assert_eq!(empty.pretty_body(), None);
+    }
+
+    #[test]
+    fn test_body_sha256_of_known_bodies() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new("abc".to_string()),
+            ..request_with_headers(Vec::new())
+        };
+        assert_eq!(
+            request.body_sha256(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let empty = OutgoingWebhookRequestContent {
+            body: Secret::new(String::new()),
+            ..request_with_headers(Vec::new())
+        };
+        assert_eq!(
+            empty.body_sha256(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_normalized_headers_ignore_header_order() {
+        let request = request_with_headers(vec![
+            ("X-Webhook-Signature-512", "signature"),
+            ("content-type", "application/json"),
+            ("Accept", "text/plain"),
+            ("Accept", "application/json"),
+        ]);
+        let reordered_request = request_with_headers(vec![
+            ("Accept", "application/json"),
+            ("Content-Type", "application/json"),
+            ("Accept", "text/plain"),
+            ("x-webhook-signature-512", "signature"),
+        ]);
+
+        let normalized = |request: &OutgoingWebhookRequestContent| {
+            request
+                .normalized_headers()
+                .into_iter()
+                .map(|(name, value)| (name.to_ascii_lowercase(), value.peek().to_owned()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(normalized(&request), normalized(&reordered_request));
+        assert_eq!(
+            normalized(&request),
+            vec![
+                ("accept".to_string(), "application/json".to_string()),
+                ("accept".to_string(), "text/plain".to_string()),
+                ("content-type".to_string(), "application/json".to_string()),
+                (
+                    "x-webhook-signature-512".to_string(),
+                    "signature".to_string()
+                ),
+            ]
+        );
+        assert!(request
+            .normalize_headers()
+            .diff_request(&reordered_request.normalize_headers())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_omit_body_keeps_request_metadata() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"dispute_id":"dp_1"}"#.to_string()),
+            is_truncated: true,
+            ..request_with_headers(vec![("content-type", "application/json")])
+        };
+
+        let stored_request = request.clone().omit_body();
+
+        assert!(stored_request.body_omitted);
+        assert!(!stored_request.is_truncated);
+        assert!(stored_request.body.peek().is_empty());
+        assert!(stored_request.diff_request(&request).is_empty());
+
+        let deserialized = serde_json::from_str::<OutgoingWebhookRequestContent>(
+            r#"{"body":"{}","headers":[],"delivery_url":null,"http_method":null}"#,
+        )
+        .unwrap();
+        assert!(!deserialized.body_omitted);
+        assert!(!deserialized.body_redacted);
+    }
+
+    #[test]
+    fn test_webhook_content_debug_redacts_secrets() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"card_number":"4242424242424242"}"#.to_string()),
+            headers: vec![
+                (
+                    "content-type".to_string(),
+                    Secret::new("application/json".to_string()),
+                ),
+                (
+                    "x-webhook-signature-512".to_string(),
+                    Secret::new("sig_super_secret_value".to_string()),
+                ),
+            ],
+            delivery_url: Some(Secret::new(
+                "https://merchant.example.com/webhooks?token=url_secret".to_string(),
+            )),
+            http_method: None,
+            is_truncated: false,
+            idempotency_key: None,
+            content_encoding: None,
+            redacted_header_count: 0,
+            payload_version: None,
+            body_omitted: false,
+            body_redacted: false,
+            signature_algorithm: None,
+        };
+        let response = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("response_secret_body".to_string())),
+            body_size_bytes: Some(20),
+            headers: Some(vec![(
+                "set-cookie".to_string(),
+                Secret::new("session=cookie_secret".to_string()),
+            )]),
+            status_code: Some(200),
+            error_message: None,
+            response_latency_ms: Some(350),
+            error_category: None,
+            is_truncated: false,
+            redacted_header_count: 0,
+        };
+
+        let request_debug = format!("{request:?}");
+        let response_debug = format!("{response:?}");
+
+        for secret in [
+            "4242424242424242",
+            "application/json",
+            "sig_super_secret_value",
+            "url_secret",
+        ] {
+            assert!(!request_debug.contains(secret), "{request_debug}");
+        }
+        for secret in ["response_secret_body", "cookie_secret"] {
+            assert!(!response_debug.contains(secret), "{response_debug}");
+        }
+
+        assert!(request_debug.contains("body: Secret(34 bytes)"));
+        assert!(request_debug.contains(r#""x-webhook-signature-512": ***"#));
+        assert!(response_debug.contains("body: Some(Secret(20 bytes))"));
+        assert!(response_debug.contains(r#"Some({"set-cookie": ***})"#));
+    }
+
+    fn signed_request_content(signature: &str) -> OutgoingWebhookRequestContent {
+        OutgoingWebhookRequestContent {
+            body: Secret::new(
+                r#"{"event_type":"payment_succeeded","content":{"payment_id":"pay_1"}}"#
+                    .to_string(),
+            ),
+            headers: vec![
+                (
+                    "content-type".to_string(),
+                    Secret::new("application/json".to_string()),
+                ),
+                (
+                    "X-Webhook-Signature".to_string(),
+                    Secret::new(signature.to_string()),
+                ),
+            ],
+            delivery_url: None,
+            http_method: None,
+            is_truncated: false,
+            idempotency_key: None,
+            content_encoding: None,
+            redacted_header_count: 0,
+            payload_version: None,
+            body_omitted: false,
+            body_redacted: false,
+            signature_algorithm: None,
+        }
+    }
+
+    #[test]
+    fn test_webhook_signature_extraction() {
+        let request = signed_request_content("signature_1");
+
+        assert_eq!(
+            request
+                .signature("x-webhook-signature")
+                .map(|signature| signature.peek().to_owned()),
+            Some("signature_1".to_string())
+        );
+        assert!(request.signature("x-webhook-signature-512").is_none());
+    }
+
+    #[test]
+    fn test_webhook_signature_verification() {
+        let secret = b"whsec_test_key";
+        let request = signed_request_content(
+            "86c19449653a916f71bcca0906830a31b99a4949513d86c87c4a382fa0590988",
+        );
+
+        assert!(request.verify_signature("X-Webhook-Signature", secret));
+        assert!(!request.verify_signature("X-Webhook-Signature", b"whsec_wrong_key"));
+        assert!(!request.verify_signature("X-Webhook-Signature-512", secret));
+
+        let tampered_request = signed_request_content(
+            "86c19449653a916f71bcca0906830a31b99a4949513d86c87c4a382fa0590989",
+        );
+        assert!(!tampered_request.verify_signature("X-Webhook-Signature", secret));
+
+        let malformed_request = signed_request_content("not_a_hex_signature");
+        assert!(!malformed_request.verify_signature("X-Webhook-Signature", secret));
+    }
+
+    fn request_signed_with(
+        algorithm: Option<WebhookSignatureAlgorithm>,
+        body: &str,
+        signature: &str,
+    ) -> OutgoingWebhookRequestContent {
+        OutgoingWebhookRequestContent {
+            body: Secret::new(body.to_string()),
+            signature_algorithm: algorithm,
+            ..signed_request_content(signature)
+        }
+    }
+
+    #[test]
+    fn test_webhook_signature_verification_by_algorithm() {
+        // Test case 2 of RFC 4231
+        let hmac_key = b"Jefe";
+        let hmac_body = "what do ya want for nothing?";
+        let hmac_sha256_signature =
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+        let hmac_sha512_signature = "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737";
+
+        for algorithm in [None, Some(WebhookSignatureAlgorithm::HmacSha256)] {
+            assert!(
+                request_signed_with(algorithm, hmac_body, hmac_sha256_signature)
+                    .verify_signature("X-Webhook-Signature", hmac_key)
+            );
+        }
+        assert!(request_signed_with(
+            Some(WebhookSignatureAlgorithm::HmacSha512),
+            hmac_body,
+            hmac_sha512_signature
+        )
+        .verify_signature("X-Webhook-Signature", hmac_key));
+        assert!(!request_signed_with(
+            Some(WebhookSignatureAlgorithm::HmacSha512),
+            hmac_body,
+            hmac_sha256_signature
+        )
+        .verify_signature("X-Webhook-Signature", hmac_key));
+
+        // Test vector 2 of RFC 8032, section 7.1
+        let public_key =
+            hex::decode("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c")
+                .unwrap();
+        let ed25519_signature = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+        let ed25519_request = request_signed_with(
+            Some(WebhookSignatureAlgorithm::Ed25519),
+            "r",
+            ed25519_signature,
+        );
+        assert!(ed25519_request.verify_signature("X-Webhook-Signature", &public_key));
+        assert!(!request_signed_with(
+            Some(WebhookSignatureAlgorithm::Ed25519),
+            "s",
+            ed25519_signature
+        )
+        .verify_signature("X-Webhook-Signature", &public_key));
+
+        let value = serde_json::to_value(&ed25519_request).unwrap();
+        assert_eq!(
+            value.get("signature_algorithm"),
+            Some(&serde_json::json!("ed25519"))
+        );
+        let deserialized: OutgoingWebhookRequestContent = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            deserialized.signature_algorithm,
+            Some(WebhookSignatureAlgorithm::Ed25519)
+        );
+    }
+
+    #[test]
+    fn test_truncate_request_body_within_limit() {
+        let request = request_with_headers(Vec::new()).truncate(2);
+
+        assert_eq!(request.body.peek(), "{}");
+        assert!(!request.is_truncated);
+    }
+
+    #[test]
+    fn test_truncate_request_body_at_multibyte_boundary() {
+        let request = OutgoingWebhookRequestContent {
+            body: Secret::new("héllo".to_string()),
+            ..request_with_headers(Vec::new())
+        };
+
+        // The cut falls in the middle of the two byte `é`, which is dropped entirely
+        let truncated = request.truncate(2);
+        assert_eq!(truncated.body.peek(), "h…[truncated 5 bytes]");
+        assert!(truncated.is_truncated);
+
+        let truncated = truncated.truncate(1024);
+        assert_eq!(truncated.body.peek(), "h…[truncated 5 bytes]");
+        assert!(truncated.is_truncated);
+    }
+
+    #[test]
+    fn test_truncate_response_body_at_multibyte_boundary() {
+        let response = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("ok🦀🦀".to_string())),
+            ..response_with_status_code(Some(500))
+        };
+
+        // Every cut within the four byte crab falls back to the end of the preceding character
+        for max_bytes in 2..6 {
+            let truncated = OutgoingWebhookResponseContent {
+                body: response.body.clone(),
+                ..response_with_status_code(Some(500))
+            }
+            .truncate(max_bytes);
+
+            assert_eq!(
+                truncated.body.as_ref().map(|body| body.peek().as_str()),
+                Some("ok…[truncated 8 bytes]")
+            );
+            assert!(truncated.is_truncated);
+            assert_eq!(truncated.get_body_size_bytes(), Some(10));
+        }
+
+        let truncated = response.truncate(6);
+        assert_eq!(
+            truncated.body.as_ref().map(|body| body.peek().as_str()),
+            Some("ok🦀…[truncated 4 bytes]")
+        );
+    }
+
+    #[test]
+    fn test_truncate_response_without_body() {
+        let truncated = response_with_status_code(None).truncate(0);
+
+        assert!(truncated.body.is_none());
+        assert!(truncated.get_body_size_bytes().is_none());
+        assert!(!truncated.is_truncated);
+    }
+
+    #[test]
+    fn test_truncated_flag_defaults_for_stored_content() {
+        let request =
+            serde_json::from_str::<OutgoingWebhookRequestContent>(r#"{"body":"{}","headers":[]}"#)
+                .unwrap();
+        let response = serde_json::from_str::<OutgoingWebhookResponseContent>(
+            r#"{"body":"ok","headers":[],"status_code":200,"error_message":null}"#,
+        )
+        .unwrap();
+
+        assert!(!request.is_truncated);
+        assert!(!response.is_truncated);
+    }
+
+    #[test]
+    fn test_retain_headers_drops_headers_outside_allowlist() {
+        let response = OutgoingWebhookResponseContent {
+            headers: Some(vec![
+                (
+                    "Content-Type".to_string(),
+                    "application/json".to_string().into(),
+                ),
+                (
+                    "set-cookie".to_string(),
+                    "session=secret".to_string().into(),
+                ),
+            ]),
+            ..response_with_status_code(Some(200))
+        };
+        let allowed = DEFAULT_RESPONSE_HEADER_ALLOWLIST
+            .iter()
+            .map(|header| header.to_string())
+            .collect::<HashSet<_>>();
+
+        let headers = response.retain_headers(&allowed).headers.unwrap();
+
+        assert_eq!(
+            headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.peek().as_str()))
+                .collect::<Vec<_>>(),
+            vec![("Content-Type", "application/json")]
+        );
+    }
+
+    #[test]
+    fn test_retain_headers_without_headers() {
+        let allowed = HashSet::from(["content-type".to_string()]);
+
+        let response = response_with_status_code(Some(200)).retain_headers(&allowed);
+
+        assert!(response.headers.is_none());
+        assert_eq!(response.redacted_header_count, 0);
+    }
+
+    #[test]
+    fn test_retain_headers_counts_redacted_headers() {
+        let response = OutgoingWebhookResponseContent {
+            headers: Some(vec![
+                (
+                    "content-type".to_string(),
+                    "application/json".to_string().into(),
+                ),
+                (
+                    "Authorization".to_string(),
+                    "Bearer secret".to_string().into(),
+                ),
+                (
+                    "set-cookie".to_string(),
+                    "session=secret".to_string().into(),
+                ),
+            ]),
+            ..response_with_status_code(Some(200))
+        };
+        let allowed = DEFAULT_RESPONSE_HEADER_ALLOWLIST
+            .iter()
+            .map(|header| header.to_string())
+            .collect::<HashSet<_>>();
+
+        let response = response.retain_headers(&allowed);
+        assert_eq!(response.redacted_header_count, 2);
+        assert_eq!(response.headers.as_ref().map(Vec::len), Some(1));
+
+        // Counts accumulate if the headers are retained again, and are persisted with the response
+        let response = response.retain_headers(&HashSet::new());
+        assert_eq!(response.redacted_header_count, 3);
+        let deserialized = serde_json::from_str::<OutgoingWebhookResponseContent>(
+            &serde_json::to_string(&response).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(deserialized.redacted_header_count, 3);
+
+        let legacy = serde_json::from_str::<OutgoingWebhookResponseContent>(
+            r#"{"body":null,"headers":null,"status_code":200,"error_message":null}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.redacted_header_count, 0);
+    }
+
+    fn request_with_payment_body() -> OutgoingWebhookRequestContent {
+        OutgoingWebhookRequestContent {
+            body: Secret::new(
+                serde_json::json!({
+                    "merchant_id": "merchant_1",
+                    "event_id": "evt_018e31720d1b7a2b82677d3032cab959",
+                    "event_type": "payment_succeeded",
+                    "content": {
+                        "type": "payment_details",
+                        "object": {
+                            "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+                            "status": "succeeded",
+                            "amount": 6540,
+                            "currency": "USD"
+                        }
+                    }
+                })
+                .to_string(),
+            ),
+            ..request_with_headers(Vec::new())
+        }
+    }
+
+    #[test]
+    fn test_parsed_body_of_payment_webhook() {
+        let body = request_with_payment_body().parsed_body().unwrap();
+
+        assert_eq!(
+            body.get("event_type"),
+            Some(&serde_json::json!("payment_succeeded"))
+        );
+        assert_eq!(
+            body.pointer("/content/object/payment_id"),
+            Some(&serde_json::json!("pay_mbabizu24mvu3mela5njyhpit4"))
+        );
+    }
+
+    #[test]
+    fn test_parse_payment_webhook_body_as_type() {
+        #[derive(Deserialize)]
+        struct PaymentWebhookBody {
+            event_type: EventType,
+            content: serde_json::Value,
+        }
+
+        let body = request_with_payment_body()
+            .parse_as::<PaymentWebhookBody>()
+            .unwrap();
+        assert_eq!(body.event_type, EventType::PaymentSucceeded);
+        assert_eq!(
+            body.content.pointer("/object/amount"),
+            Some(&serde_json::json!(6540))
+        );
+
+        let truncated = request_with_payment_body().truncate(32);
+        assert!(truncated.parsed_body().is_err());
+    }
+
+    #[test]
+    fn test_payment_amount_of_payment_and_refund_events() {
+        let request_content = request_with_payment_body();
+        for event_class in [EventClass::Payments, EventClass::Refunds] {
+            assert_eq!(
+                request_content.payment_amount(event_class),
+                (
+                    Some(common_utils::types::MinorUnit::new(6540)),
+                    Some(common_enums::Currency::USD)
+                )
+            );
+        }
+
+        let truncated = request_with_payment_body().truncate(32);
+        assert_eq!(truncated.payment_amount(EventClass::Payments), (None, None));
+        assert_eq!(
+            request_with_headers(Vec::new()).payment_amount(EventClass::Payments),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_payment_amount_of_non_payment_events_is_none() {
+        let request_content = request_with_payment_body();
+        for event_class in [EventClass::Disputes, EventClass::Mandates] {
+            assert_eq!(request_content.payment_amount(event_class), (None, None));
+        }
+
+        let value = serde_json::to_value(list_item()).unwrap();
+        assert_eq!(value.get("amount"), Some(&serde_json::Value::Null));
+        assert_eq!(value.get("currency"), Some(&serde_json::Value::Null));
+    }
+
+    #[test]
+    fn test_response_content_filters() {
+        let with_content_type = |content_type: &str| OutgoingWebhookResponseContent {
+            headers: Some(vec![(
+                "Content-Type".to_string(),
+                Secret::new(content_type.to_string()),
+            )]),
+            ..response_with_status_code(Some(200))
+        };
+        let with_error = |error_message: &str, error_category| OutgoingWebhookResponseContent {
+            error_message: Some(error_message.to_string()),
+            error_category,
+            ..response_with_status_code(None)
+        };
+        let responses = [
+            ("server_error", response_with_status_code(Some(503))),
+            ("too_many_requests", response_with_status_code(Some(429))),
+            ("json", with_content_type("application/json")),
+            ("html", with_content_type("text/html; charset=UTF-8")),
+            (
+                "json_utf8",
+                with_content_type("application/json; charset=utf-8"),
+            ),
+            ("upper_html", with_content_type("TEXT/HTML")),
+            (
+                "timed_out",
+                with_error(
+                    "Request timed out",
+                    Some(WebhookDeliveryErrorCategory::Timeout),
+                ),
+            ),
+            (
+                "tls_failure",
+                with_error("TLS handshake failed: Certificate Expired", None),
+            ),
+            (
+                "connection_refused",
+                with_error(
+                    "Connection refused",
+                    Some(WebhookDeliveryErrorCategory::ConnectionRefused),
+                ),
+            ),
+            ("no_response", response_with_status_code(None)),
+        ];
+        let all = responses.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let status_codes = [
+            "server_error",
+            "too_many_requests",
+            "json",
+            "html",
+            "json_utf8",
+            "upper_html",
+        ];
+        let errors = ["timed_out", "tls_failure", "connection_refused"];
+
+        let cases: &[(fn(&OutgoingWebhookResponseContent) -> bool, &[&str])] = &[
+            (
+                |response| response.is_status_code_within(Some(500), Some(599)),
+                &["server_error"],
+            ),
+            (
+                |response| response.is_status_code_within(Some(429), Some(429)),
+                &["too_many_requests"],
+            ),
+            (
+                |response| response.is_status_code_within(Some(500), None),
+                &["server_error"],
+            ),
+            (
+                |response| response.is_status_code_within(None, Some(499)),
+                &[
+                    "too_many_requests",
+                    "json",
+                    "html",
+                    "json_utf8",
+                    "upper_html",
+                ],
+            ),
+            // Responses without a status code only match when the range is unbounded
+            (
+                |response| response.is_status_code_within(None, None),
+                all.as_slice(),
+            ),
+            (
+                |response| {
+                    response.is_error_category_within(Some(&HashSet::from([
+                        WebhookDeliveryErrorCategory::Timeout,
+                        WebhookDeliveryErrorCategory::DnsResolution,
+                    ])))
+                },
+                &["timed_out"],
+            ),
+            (
+                |response| response.is_error_category_within(Some(&HashSet::new())),
+                all.as_slice(),
+            ),
+            (
+                |response| response.is_error_category_within(None),
+                all.as_slice(),
+            ),
+            (
+                |response| response.is_error_message_present(Some(true)),
+                &errors,
+            ),
+            (
+                |response| response.is_error_message_present(Some(false)),
+                &[
+                    "server_error",
+                    "too_many_requests",
+                    "json",
+                    "html",
+                    "json_utf8",
+                    "upper_html",
+                    "no_response",
+                ],
+            ),
+            (
+                |response| response.is_error_message_present(None),
+                all.as_slice(),
+            ),
+            // Error messages are matched case-insensitively
+            (
+                |response| response.is_error_message_containing(Some("certificate expired")),
+                &["tls_failure"],
+            ),
+            (
+                |response| response.is_error_message_containing(Some("HANDSHAKE")),
+                &["tls_failure"],
+            ),
+            (
+                |response| response.is_error_message_containing(Some("timed out")),
+                &["timed_out"],
+            ),
+            (
+                |response| response.is_error_message_containing(None),
+                all.as_slice(),
+            ),
+            (
+                |response| response.is_response_received(Some(true)),
+                &status_codes,
+            ),
+            (
+                |response| response.is_response_received(Some(false)),
+                &[
+                    "timed_out",
+                    "tls_failure",
+                    "connection_refused",
+                    "no_response",
+                ],
+            ),
+            (
+                |response| response.is_response_received(None),
+                all.as_slice(),
+            ),
+            // Content types are matched case-insensitively, ignoring their parameters
+            (
+                |response| response.is_content_type_matching(Some("text/html")),
+                &["html", "upper_html"],
+            ),
+            (
+                |response| response.is_content_type_matching(Some("Application/JSON")),
+                &["json", "json_utf8"],
+            ),
+            (
+                |response| response.is_content_type_matching(Some("text/html; charset=utf-8")),
+                &["html", "upper_html"],
+            ),
+            (
+                |response| response.is_content_type_matching(Some("text/plain")),
+                &[],
+            ),
+            (
+                |response| response.is_content_type_matching(None),
+                all.as_slice(),
+            ),
+        ];
+
+        for (index, (is_matching, expected_responses)) in cases.iter().enumerate() {
+            assert_eq!(
+                responses
+                    .iter()
+                    .filter(|(_, response)| is_matching(response))
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>(),
+                *expected_responses,
+                "case {index}"
+            );
+        }
+
+        assert_eq!(
+            with_content_type("text/html; charset=UTF-8")
+                .get_content_type()
+                .as_deref(),
+            Some("text/html")
+        );
+        assert_eq!(response_with_status_code(None).get_content_type(), None);
+        assert_eq!(
+            serde_json::to_string(&WebhookDeliveryErrorCategory::Non2xxStatus).unwrap(),
+            r#""non_2xx_status""#
+        );
+    }
+}
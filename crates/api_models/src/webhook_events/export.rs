@@ -0,0 +1,400 @@
+//! Serialization of event lists into formats suitable for exporting.
+
+use common_enums::EventField;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use super::{EventListConstraints, EventListItemResponse};
+
+/// The columns of the CSV export, in the order they are serialized.
+const EVENT_LIST_CSV_COLUMNS: [EventField; 9] = [
+    EventField::EventId,
+    EventField::MerchantId,
+    EventField::ProfileId,
+    EventField::ObjectId,
+    EventField::EventType,
+    EventField::EventClass,
+    EventField::IsDeliverySuccessful,
+    EventField::InitialAttemptId,
+    EventField::Created,
+];
+
+/// Serializes the events into CSV, with a header row followed by one row per event. Timestamps
+/// use the same ISO 8601 representation as the JSON responses, and an unknown delivery status is
+/// serialized as an empty field.
+pub fn events_to_csv(events: &[EventListItemResponse]) -> Result<String, serde_json::Error> {
+    events_to_csv_with_fields(events, &EVENT_LIST_CSV_COLUMNS)
+}
+
+/// Serializes only the specified fields of the events into CSV, with a header row followed by one
+/// row per event, and the columns in the specified order. Missing values are serialized as empty
+/// fields, lists and objects are serialized as JSON, and secret fields are masked.
+pub fn events_to_csv_with_fields(
+    events: &[EventListItemResponse],
+    fields: &[EventField],
+) -> Result<String, serde_json::Error> {
+    let mut csv = fields
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+
+    for event in events {
+        let row = event
+            .select_fields(fields)?
+            .into_iter()
+            .map(|(_, value)| match value {
+                serde_json::Value::Null => String::new(),
+                serde_json::Value::String(value) => value,
+                value => value.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        csv.push_str(
+            &row.iter()
+                .map(String::as_str)
+                .map(escape_csv_field)
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    Ok(csv)
+}
+
+/// The formats events can be exported in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// Newline-delimited JSON, with one event per line.
+    Ndjson,
+    /// Comma-separated values, with a header row followed by one row per event.
+    Csv,
+}
+
+/// Describes an export of events, providing downstream systems with the provenance of the export,
+/// and allowing them to verify its completeness against the `total_count` of the events listed
+/// with the same constraints.
+#[derive(Debug, Serialize)]
+pub struct EventExportManifest {
+    /// The identifier for the Merchant Account the events were exported for.
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The constraints the exported events were selected with.
+    pub constraints: EventListConstraints,
+
+    /// The number of events exported.
+    pub total_exported: usize,
+
+    /// Time at which the export was generated.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub generated_at: PrimitiveDateTime,
+
+    /// The format the events were exported in.
+    pub format: ExportFormat,
+}
+
+impl EventExportManifest {
+    /// Serializes the manifest into a single line of newline-delimited JSON, without the trailing
+    /// newline. The manifest is nested under a `manifest` key, so that the line can be told apart
+    /// from the lines of the exported events.
+    pub fn to_ndjson_line(&self) -> Result<String, serde_json::Error> {
+        Ok(format!("{{\"manifest\":{}}}", serde_json::to_string(self)?))
+    }
+}
+
+/// Serializes the events into newline-delimited JSON, with one line per event preceded by a line
+/// with the manifest of the export. Only the specified fields of the events are serialized, if
+/// any, and secret fields are masked. Returns the manifest along with the serialized export.
+pub fn events_to_ndjson_with_manifest(
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: EventListConstraints,
+    events: &[EventListItemResponse],
+    fields: Option<&[EventField]>,
+    generated_at: PrimitiveDateTime,
+) -> Result<(EventExportManifest, String), serde_json::Error> {
+    let manifest = EventExportManifest {
+        merchant_id,
+        constraints,
+        total_exported: events.len(),
+        generated_at,
+        format: ExportFormat::Ndjson,
+    };
+
+    let mut ndjson = manifest.to_ndjson_line()?;
+    ndjson.push('\n');
+    for event in events {
+        let line = match fields {
+            Some(fields) => event.to_ndjson_line_with_fields(fields),
+            None => event.to_ndjson_line(),
+        }?;
+        ndjson.push_str(&line);
+        ndjson.push('\n');
+    }
+
+    Ok((manifest, ndjson))
+}
+
+/// Quotes the field if it contains a delimiter, a quote or a line break, escaping any quotes
+/// within the field.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::{EventClass, EventType, WebhookOutcome};
+    use time::PrimitiveDateTime;
+
+    use super::*;
+    use crate::webhook_events::test_utils;
+
+    fn list_item(
+        event_id: &str,
+        object_id: &str,
+        event_type: EventType,
+        event_class: EventClass,
+        is_delivery_successful: Option<bool>,
+        created: PrimitiveDateTime,
+    ) -> EventListItemResponse {
+        EventListItemResponse {
+            event_id: test_utils::to_event_id(event_id),
+            object_id: object_id.to_string(),
+            event_type,
+            event_class,
+            is_delivery_successful,
+            outcome: match is_delivery_successful {
+                Some(true) => WebhookOutcome::Delivered,
+                Some(false) => WebhookOutcome::Failed,
+                None => WebhookOutcome::Pending,
+            },
+            connector: None,
+            initial_attempt_id: test_utils::to_event_id(event_id),
+            delivery_attempt: None,
+            created,
+            ..test_utils::list_item()
+        }
+    }
+
+    #[test]
+    fn test_manifest_total_exported_matches_exported_lines() {
+        let created = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+        let events = vec![
+            list_item(
+                "evt_1",
+                "pay_1",
+                EventType::PaymentSucceeded,
+                EventClass::Payments,
+                Some(true),
+                created,
+            ),
+            list_item(
+                "evt_2",
+                "ref_1",
+                EventType::RefundSucceeded,
+                EventClass::Refunds,
+                Some(false),
+                created,
+            ),
+            list_item(
+                "evt_3",
+                "pay_2",
+                EventType::PaymentFailed,
+                EventClass::Payments,
+                None,
+                created,
+            ),
+        ];
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let constraints = serde_json::from_str::<EventListConstraints>(
+            r#"{"event_classes":["payments","refunds"]}"#,
+        )
+        .unwrap();
+
+        let (manifest, ndjson) = events_to_ndjson_with_manifest(
+            merchant_id,
+            constraints,
+            &events,
+            Some(&[EventField::EventId, EventField::EventType]),
+            created,
+        )
+        .unwrap();
+        let mut lines = ndjson.lines();
+        let manifest_line =
+            serde_json::from_str::<serde_json::Value>(lines.next().unwrap()).unwrap();
+        let event_lines = lines.collect::<Vec<_>>();
+
+        assert_eq!(manifest.total_exported, 3);
+        assert_eq!(event_lines.len(), manifest.total_exported);
+        assert_eq!(
+            manifest_line,
+            serde_json::json!({
+                "manifest": {
+                    "merchant_id": "merchant_1",
+                    "constraints": serde_json::to_value(&manifest.constraints).unwrap(),
+                    "total_exported": 3,
+                    "generated_at": "2024-01-01T00:00:00.000Z",
+                    "format": "ndjson",
+                }
+            })
+        );
+        assert_eq!(
+            event_lines.first().copied(),
+            Some(r#"{"event_id":"evt_1","event_type":"payment_succeeded"}"#)
+        );
+
+        let (manifest, ndjson) = events_to_ndjson_with_manifest(
+            manifest.merchant_id,
+            manifest.constraints,
+            &[],
+            None,
+            created,
+        )
+        .unwrap();
+        assert_eq!(manifest.total_exported, 0);
+        assert_eq!(ndjson.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_events_to_csv_golden_output() {
+        let events = vec![
+            list_item(
+                "evt_1",
+                "pay_1",
+                EventType::PaymentSucceeded,
+                EventClass::Payments,
+                Some(true),
+                PrimitiveDateTime::new(
+                    time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                    time::Time::from_hms_milli(10, 11, 12, 345).unwrap(),
+                ),
+            ),
+            list_item(
+                "evt_2",
+                "ref_1",
+                EventType::RefundFailed,
+                EventClass::Refunds,
+                Some(false),
+                PrimitiveDateTime::new(
+                    time::Date::from_calendar_date(2024, time::Month::January, 2).unwrap(),
+                    time::Time::MIDNIGHT,
+                ),
+            ),
+            list_item(
+                "evt_3",
+                "pay,2",
+                EventType::PaymentProcessing,
+                EventClass::Payments,
+                None,
+                PrimitiveDateTime::new(
+                    time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap(),
+                    time::Time::from_hms(23, 59, 59).unwrap(),
+                ),
+            ),
+        ];
+
+        let expected = "\
+event_id,merchant_id,profile_id,object_id,event_type,event_class,is_delivery_successful,initial_attempt_id,created
+evt_1,merchant_1,pro_1,pay_1,payment_succeeded,payments,true,evt_1,2024-01-01T10:11:12.345Z
+evt_2,merchant_1,pro_1,ref_1,refund_failed,refunds,false,evt_2,2024-01-02T00:00:00.000Z
+evt_3,merchant_1,pro_1,\"pay,2\",payment_processing,payments,,evt_3,2024-02-29T23:59:59.000Z
+";
+
+        assert_eq!(events_to_csv(&events).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_events_to_csv_without_events() {
+        assert_eq!(
+            events_to_csv(&[]).unwrap(),
+            "event_id,merchant_id,profile_id,object_id,event_type,event_class,\
+             is_delivery_successful,initial_attempt_id,created\n"
+        );
+    }
+
+    #[test]
+    fn test_events_to_csv_with_selected_fields() {
+        let mut event = list_item(
+            "evt_1",
+            "pay_1",
+            EventType::PaymentSucceeded,
+            EventClass::Payments,
+            Some(true),
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                time::Time::MIDNIGHT,
+            ),
+        );
+        event.tags = Some(vec!["incident-1".to_string()]);
+
+        let expected = "\
+created,event_type,tags,connector
+2024-01-01T00:00:00.000Z,payment_succeeded,\"[\"\"incident-1\"\"]\",
+";
+
+        assert_eq!(
+            events_to_csv_with_fields(
+                &[event],
+                &[
+                    EventField::Created,
+                    EventField::EventType,
+                    EventField::Tags,
+                    EventField::Connector,
+                ],
+            )
+            .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ndjson_line_with_selected_fields() {
+        let mut event = list_item(
+            "evt_1",
+            "pay_1",
+            EventType::PaymentSucceeded,
+            EventClass::Payments,
+            Some(true),
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                time::Time::MIDNIGHT,
+            ),
+        );
+        event.delivery_url = Some(masking::Secret::new(
+            "https://merchant.example.com/webhooks".to_string(),
+        ));
+
+        let line = event
+            .to_ndjson_line_with_fields(&[
+                EventField::ObjectId,
+                EventField::EventId,
+                EventField::DeliveryUrl,
+            ])
+            .unwrap();
+        assert!(line.starts_with(r#"{"object_id":"pay_1","event_id":"evt_1","delivery_url":"#));
+        assert!(!line.contains("merchant.example.com"));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("pay_1"), "pay_1");
+        assert_eq!(escape_csv_field("pay,1"), "\"pay,1\"");
+        assert_eq!(escape_csv_field("pay \"1\""), "\"pay \"\"1\"\"\"");
+        assert_eq!(escape_csv_field("pay\n1"), "\"pay\n1\"");
+    }
+}
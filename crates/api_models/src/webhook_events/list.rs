@@ -0,0 +1,759 @@
+//! The responses of listing and counting events.
+
+use std::collections::{HashMap, HashSet};
+
+use common_enums::{
+    CountMode, EventClass, EventField, EventResponseMode, EventType, WebhookDeliveryAttempt,
+    WebhookOutcome,
+};
+use masking::Secret;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use super::{EventId, EVENT_DEDUP_BUCKET_SECONDS, MAX_EVENT_LIST_OFFSET};
+
+/// The response body for each item when listing events.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventListItemResponse {
+    /// The identifier for the Event.
+    #[schema(value_type = String, max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: EventId,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
+    #[schema(max_length = 64, example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
+    pub object_id: String,
+
+    /// Specifies the type of event, which includes the object and its status.
+    pub event_type: EventType,
+
+    /// Specifies the class of event (the type of object: Payment, Refund, etc.)
+    pub event_class: EventClass,
+
+    /// Indicates whether webhooks are currently enabled for the class of the event for the
+    /// business profile. Events may have been generated before their class was disabled.
+    #[schema(example = true)]
+    pub class_enabled: bool,
+
+    /// Indicates whether the webhook was ultimately delivered or not.
+    pub is_delivery_successful: Option<bool>,
+
+    /// The overall outcome of the delivery of the webhook, accounting for its retries.
+    pub outcome: WebhookOutcome,
+
+    /// The identifier for the merchant connector account associated with the object, if any.
+    #[schema(max_length = 64, value_type = Option<String>, example = "mca_sAD3OZLATetvjLOYhUSy")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// The name of the connector associated with the object, if any.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The identifier for the initial delivery attempt. This will be the same as `event_id` for
+    /// the initial delivery attempt.
+    #[schema(value_type = String, max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub initial_attempt_id: EventId,
+
+    /// Indicates the type of delivery attempt.
+    pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+
+    /// Time at which the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the event was persisted, which may lag behind `created`. This is unavailable
+    /// for events persisted before the ingestion time was recorded.
+    #[schema(example = "2022-09-10T10:11:13Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub ingested_at: Option<PrimitiveDateTime>,
+
+    /// The time (in milliseconds) from the last change of the object of the event to the creation
+    /// of the event. This is unavailable for objects which do not record their change time, and
+    /// for events created before the change time was recorded.
+    #[schema(example = 120)]
+    pub event_delay_ms: Option<u64>,
+
+    /// The URL of the endpoint the webhook was delivered to. This may not be available for
+    /// events delivered before the URL was recorded.
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks")]
+    pub delivery_url: Option<Secret<String>>,
+
+    /// The number of bytes stored for the request and response bodies and headers of the
+    /// event. This is unavailable for events whose request has not been stored.
+    #[schema(example = 2048)]
+    pub stored_bytes: Option<usize>,
+
+    /// Time at which the event expires and is purged, as per the retention duration of its class.
+    #[schema(example = "2023-03-09T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// Indicates whether the delivery attempt took longer than
+    /// [`WEBHOOK_DELIVERY_SLA_MS`](super::WEBHOOK_DELIVERY_SLA_MS) to receive a response. This is
+    /// unavailable for events without a recorded latency.
+    #[schema(example = false)]
+    pub sla_breached: Option<bool>,
+
+    /// The amount of the payment or refund in the event payload, in the lowest denomination of
+    /// the currency. This is only available for payment and refund events.
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<common_utils::types::MinorUnit>,
+
+    /// The currency of the payment or refund in the event payload. This is only available for
+    /// payment and refund events.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<common_enums::Currency>,
+
+    /// The tags added to the event by operators, such as during incident triage.
+    #[schema(example = json!(["under-investigation"]))]
+    pub tags: Option<Vec<String>>,
+}
+
+impl EventListItemResponse {
+    /// Serializes the event into a single line of newline-delimited JSON, without the trailing
+    /// newline. Secret fields are masked in the serialized line.
+    pub fn to_ndjson_line(&self) -> Result<String, serde_json::Error> {
+        masking::masked_serialize(self).and_then(|value| serde_json::to_string(&value))
+    }
+
+    /// Returns the values of the specified fields of the event, in the specified order. Secret
+    /// fields are masked in the returned values.
+    pub fn select_fields(
+        &self,
+        fields: &[EventField],
+    ) -> Result<Vec<(EventField, serde_json::Value)>, serde_json::Error> {
+        let value = masking::masked_serialize(self)?;
+
+        Ok(fields
+            .iter()
+            .map(|field| {
+                let field_value = value
+                    .get(field.to_string())
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null);
+                (*field, field_value)
+            })
+            .collect())
+    }
+
+    /// Serializes only the specified fields of the event into a single line of newline-delimited
+    /// JSON, without the trailing newline. The fields are serialized in the specified order, and
+    /// secret fields are masked in the serialized line.
+    pub fn to_ndjson_line_with_fields(
+        &self,
+        fields: &[EventField],
+    ) -> Result<String, serde_json::Error> {
+        let members = self
+            .select_fields(fields)?
+            .into_iter()
+            .map(|(field, value)| {
+                Ok(format!(
+                    "{}:{}",
+                    serde_json::to_string(&field)?,
+                    serde_json::to_string(&value)?
+                ))
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        Ok(format!("{{{}}}", members.join(",")))
+    }
+
+    /// Returns the key identifying the logical event, combining the object identifier, the event
+    /// type and the [`EVENT_DEDUP_BUCKET_SECONDS`] wide bucket the event was created in. Events
+    /// recorded multiple times due to upstream retries share the same key.
+    pub fn dedup_key(&self) -> String {
+        let bucket = self
+            .created
+            .assume_utc()
+            .unix_timestamp()
+            .div_euclid(EVENT_DEDUP_BUCKET_SECONDS);
+
+        format!("{}_{}_{bucket}", self.object_id, self.event_type)
+    }
+
+    /// Collapses the events sharing the same [`Self::dedup_key`], keeping only the latest created
+    /// event for each key. The retained events are returned in their original order.
+    pub fn dedup(events: Vec<Self>) -> Vec<Self> {
+        let mut latest_by_key = HashMap::<String, (usize, PrimitiveDateTime)>::new();
+        for (index, event) in events.iter().enumerate() {
+            latest_by_key
+                .entry(event.dedup_key())
+                .and_modify(|(latest_index, latest_created)| {
+                    if event.created >= *latest_created {
+                        *latest_index = index;
+                        *latest_created = event.created;
+                    }
+                })
+                .or_insert((index, event.created));
+        }
+        let retained = latest_by_key
+            .into_values()
+            .map(|(index, _)| index)
+            .collect::<HashSet<_>>();
+
+        events
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, event)| retained.contains(&index).then_some(event))
+            .collect()
+    }
+}
+
+/// The compact response body for each item when listing events, including only the fields
+/// required to identify the event and its delivery status.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventListItemCompact {
+    /// The identifier for the Event.
+    #[schema(value_type = String, max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: EventId,
+
+    /// Specifies the type of event, which includes the object and its status.
+    pub event_type: EventType,
+
+    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
+    #[schema(max_length = 64, example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
+    pub object_id: String,
+
+    /// Time at which the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Indicates whether the webhook was ultimately delivered or not.
+    pub is_delivery_successful: Option<bool>,
+}
+
+impl From<EventListItemResponse> for EventListItemCompact {
+    fn from(item: EventListItemResponse) -> Self {
+        Self {
+            event_id: item.event_id,
+            event_type: item.event_type,
+            object_id: item.object_id,
+            created: item.created,
+            is_delivery_successful: item.is_delivery_successful,
+        }
+    }
+}
+
+/// The events listed, in the shape requested with `response_mode`.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum EventListItems {
+    /// The events listed with all their details.
+    Full(Vec<EventListItemResponse>),
+    /// The events listed in the compact shape.
+    Compact(Vec<EventListItemCompact>),
+}
+
+/// The response body of list initial delivery attempts api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotalEventsResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The list of events
+    pub events: EventListItems,
+    /// Count of total events. This is an approximation if `count_mode` is `estimated`, and is not
+    /// available if `count_mode` is `none`.
+    pub total_count: Option<i64>,
+    /// How `total_count` was computed.
+    pub count_mode: CountMode,
+    /// Indicates whether more events exist beyond the listed page of events. This is derived from
+    /// `total_count`, and is therefore approximate if `count_mode` is `estimated`. If `count_mode`
+    /// is `none`, this indicates whether the listed page of events is full.
+    pub has_more: bool,
+    /// The `offset` to use for fetching the next page of events. This is not available if there
+    /// are no more events, or if the next page lies beyond the maximum allowed `offset`, in which
+    /// case the `starting_after` cursor can be used instead.
+    #[schema(example = 20)]
+    pub next_offset: Option<u16>,
+    /// The number of events of each class, counted using the same filters as `total_count`. This
+    /// is only available if `include_breakdown` was requested.
+    #[schema(
+        value_type = Option<HashMap<String, i64>>,
+        example = json!({"payments": 12, "refunds": 3})
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_breakdown: Option<HashMap<EventClass, i64>>,
+    /// Notes describing the filters dropped from the constraints, such as the `event_types` that
+    /// do not belong to any of the specified `event_classes`. Omitted if no filters were dropped.
+    #[schema(example = json!(["event_types: refund_failed"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropped_filters: Option<Vec<String>>,
+}
+
+impl TotalEventsResponse {
+    /// Creates the response for the page of events listed at the specified offset, deriving the
+    /// pagination metadata from the total count of events.
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        total_count: i64,
+        events: Vec<EventListItemResponse>,
+        offset: Option<u16>,
+    ) -> Self {
+        let page_end = i64::from(offset.unwrap_or(0))
+            .saturating_add(i64::try_from(events.len()).unwrap_or(i64::MAX));
+        let has_more = page_end < total_count;
+        let next_offset = has_more
+            .then(|| u16::try_from(page_end).ok())
+            .flatten()
+            .filter(|next_offset| *next_offset <= MAX_EVENT_LIST_OFFSET);
+
+        Self {
+            merchant_id,
+            events: EventListItems::Full(events),
+            total_count: Some(total_count),
+            count_mode: CountMode::Exact,
+            has_more,
+            next_offset,
+            class_breakdown: None,
+            dropped_filters: None,
+        }
+    }
+
+    /// Creates the response for the page of events listed at the specified offset without
+    /// counting the total events, assuming more events exist if the page of events is full.
+    pub fn without_total_count(
+        merchant_id: common_utils::id_type::MerchantId,
+        events: Vec<EventListItemResponse>,
+        offset: Option<u16>,
+        limit: u16,
+    ) -> Self {
+        let has_more = events.len() >= usize::from(limit);
+        let next_offset = has_more
+            .then(|| {
+                u16::try_from(events.len())
+                    .ok()
+                    .and_then(|page_size| offset.unwrap_or(0).checked_add(page_size))
+            })
+            .flatten()
+            .filter(|next_offset| *next_offset <= MAX_EVENT_LIST_OFFSET);
+
+        Self {
+            merchant_id,
+            events: EventListItems::Full(events),
+            total_count: None,
+            count_mode: CountMode::None,
+            has_more,
+            next_offset,
+            class_breakdown: None,
+            dropped_filters: None,
+        }
+    }
+
+    /// Marks the total count of events included in the response as an estimate.
+    pub fn with_estimated_total_count(self) -> Self {
+        Self {
+            count_mode: CountMode::Estimated,
+            ..self
+        }
+    }
+
+    /// Converts the events included in the response into the specified shape.
+    pub fn with_response_mode(self, response_mode: EventResponseMode) -> Self {
+        let events = match (response_mode, self.events) {
+            (EventResponseMode::Compact, EventListItems::Full(events)) => {
+                EventListItems::Compact(events.into_iter().map(Into::into).collect())
+            }
+            (_, events) => events,
+        };
+
+        Self { events, ..self }
+    }
+
+    /// Sets the number of events of each class included in the response.
+    pub fn with_class_breakdown(self, class_breakdown: Option<HashMap<EventClass, i64>>) -> Self {
+        Self {
+            class_breakdown,
+            ..self
+        }
+    }
+
+    /// Sets the notes describing the filters dropped from the constraints.
+    pub fn with_dropped_filters(self, dropped_filters: Option<Vec<String>>) -> Self {
+        Self {
+            dropped_filters,
+            ..self
+        }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for TotalEventsResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// Extrapolates the number of events counted within the most recent part of a time window to the
+/// entire time window, assuming events are spread evenly across the time window. Returns the
+/// count as is if the sampled part covers the entire time window.
+pub fn estimate_total_count(
+    sample_count: i64,
+    sample_window: time::Duration,
+    time_window: time::Duration,
+) -> i64 {
+    if sample_window >= time_window || sample_window <= time::Duration::ZERO {
+        return sample_count;
+    }
+
+    let estimate = i128::from(sample_count)
+        .saturating_mul(time_window.whole_milliseconds())
+        .checked_div(sample_window.whole_milliseconds())
+        .unwrap_or(i128::from(sample_count));
+
+    i64::try_from(estimate).unwrap_or(i64::MAX)
+}
+
+/// The response body of the events count api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventsCountResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Count of total events matching the specified constraints
+    pub total_count: i64,
+}
+
+impl common_utils::events::ApiEventMetric for EventsCountResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventListItemResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::{
+        test_utils::{date_time, list_item, merchant_id, to_event_id},
+        EventListConstraints,
+    };
+
+    #[test]
+    fn test_empty_events_response_api_event_type() {
+        use common_utils::events::ApiEventMetric;
+
+        let response = TotalEventsResponse::new(merchant_id(), 0, Vec::new(), None);
+        assert!(matches!(
+            response.get_api_event_type(),
+            Some(common_utils::events::ApiEventsType::Events { merchant_id: response_merchant_id })
+                if response_merchant_id == merchant_id()
+        ));
+    }
+
+    #[test]
+    fn test_empty_events_response_serialization() {
+        assert_eq!(
+            serde_json::to_value(TotalEventsResponse::new(merchant_id(), 0, Vec::new(), None))
+                .unwrap(),
+            serde_json::json!({
+                "events": [],
+                "total_count": 0,
+                "count_mode": "exact",
+                "has_more": false,
+                "next_offset": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_events_response_serialization_by_response_mode() {
+        let full = serde_json::to_value(
+            TotalEventsResponse::new(merchant_id(), 1, list_items(1), None)
+                .with_response_mode(EventResponseMode::Full),
+        )
+        .unwrap();
+        let full_event = full.pointer("/events/0").unwrap();
+        assert_eq!(
+            full_event.get("event_id"),
+            Some(&serde_json::json!("evt_018e31720d1b7a2b82677d3032cab959"))
+        );
+        assert_eq!(
+            full_event.get("connector"),
+            Some(&serde_json::json!("stripe"))
+        );
+        assert!(full_event.get("merchant_id").is_some());
+
+        let compact = serde_json::to_value(
+            TotalEventsResponse::new(merchant_id(), 1, list_items(1), None)
+                .with_response_mode(EventResponseMode::Compact),
+        )
+        .unwrap();
+        assert_eq!(
+            compact.get("events"),
+            Some(&serde_json::json!([{
+                "event_id": "evt_018e31720d1b7a2b82677d3032cab959",
+                "event_type": "payment_succeeded",
+                "object_id": "pay_1",
+                "created": "2024-01-01T00:00:00.000Z",
+                "is_delivery_successful": true
+            }]))
+        );
+        assert_eq!(compact.get("total_count"), Some(&serde_json::json!(1)));
+
+        let constraints =
+            serde_json::from_str::<EventListConstraints>(r#"{"responseMode":"compact"}"#).unwrap();
+        assert_eq!(constraints.response_mode, Some(EventResponseMode::Compact));
+    }
+
+    #[test]
+    fn test_events_response_class_breakdown_serialization() {
+        let response = TotalEventsResponse::new(merchant_id(), 3, Vec::new(), None)
+            .with_class_breakdown(Some(HashMap::from([
+                (EventClass::Payments, 2),
+                (EventClass::Refunds, 1),
+            ])));
+
+        assert_eq!(
+            serde_json::to_value(response)
+                .unwrap()
+                .get("class_breakdown")
+                .cloned(),
+            Some(serde_json::json!({ "payments": 2, "refunds": 1 }))
+        );
+    }
+
+    fn list_items(count: usize) -> Vec<EventListItemResponse> {
+        (0..count).map(|_| list_item()).collect()
+    }
+
+    #[test]
+    fn test_events_response_pagination_middle_page() {
+        let response = TotalEventsResponse::new(merchant_id(), 5, list_items(2), Some(2));
+        assert!(response.has_more);
+        assert_eq!(response.next_offset, Some(4));
+
+        let response = TotalEventsResponse::new(merchant_id(), 5, list_items(2), None);
+        assert!(response.has_more);
+        assert_eq!(response.next_offset, Some(2));
+    }
+
+    #[test]
+    fn test_events_response_pagination_last_page() {
+        let response = TotalEventsResponse::new(merchant_id(), 5, list_items(2), Some(3));
+        assert!(!response.has_more);
+        assert_eq!(response.next_offset, None);
+
+        let response = TotalEventsResponse::new(merchant_id(), 2, list_items(2), None);
+        assert!(!response.has_more);
+        assert_eq!(response.next_offset, None);
+    }
+
+    #[test]
+    fn test_events_response_next_offset_beyond_max_offset() {
+        let response = TotalEventsResponse::new(
+            merchant_id(),
+            20_000,
+            list_items(2),
+            Some(MAX_EVENT_LIST_OFFSET),
+        );
+        assert!(response.has_more);
+        assert_eq!(response.next_offset, None);
+    }
+
+    #[test]
+    fn test_events_response_exact_count_mode() {
+        let value = serde_json::to_value(TotalEventsResponse::new(
+            merchant_id(),
+            5,
+            list_items(2),
+            None,
+        ))
+        .unwrap();
+        assert_eq!(value.get("total_count"), Some(&serde_json::json!(5)));
+        assert_eq!(value.get("count_mode"), Some(&serde_json::json!("exact")));
+        assert_eq!(value.get("has_more"), Some(&serde_json::json!(true)));
+    }
+
+    #[test]
+    fn test_events_response_estimated_count_mode() {
+        let response = TotalEventsResponse::new(merchant_id(), 300, list_items(2), None)
+            .with_estimated_total_count();
+        assert_eq!(response.total_count, Some(300));
+        assert_eq!(response.count_mode, CountMode::Estimated);
+        assert!(response.has_more);
+
+        // 10 events in the last day of a 30 day window are extrapolated to 300 events
+        assert_eq!(
+            estimate_total_count(10, time::Duration::days(1), time::Duration::days(30)),
+            300
+        );
+        // Windows covered entirely by the sample are counted exactly
+        assert_eq!(
+            estimate_total_count(10, time::Duration::days(1), time::Duration::hours(6)),
+            10
+        );
+        assert_eq!(
+            estimate_total_count(0, time::Duration::days(1), time::Duration::days(30)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_events_response_none_count_mode() {
+        let response =
+            TotalEventsResponse::without_total_count(merchant_id(), list_items(2), Some(4), 2);
+        assert_eq!(response.total_count, None);
+        assert_eq!(response.count_mode, CountMode::None);
+        assert!(response.has_more);
+        assert_eq!(response.next_offset, Some(6));
+
+        let value = serde_json::to_value(TotalEventsResponse::without_total_count(
+            merchant_id(),
+            list_items(1),
+            Some(4),
+            2,
+        ))
+        .unwrap();
+        assert_eq!(value.get("total_count"), Some(&serde_json::Value::Null));
+        assert_eq!(value.get("count_mode"), Some(&serde_json::json!("none")));
+        assert_eq!(value.get("has_more"), Some(&serde_json::json!(false)));
+        assert_eq!(value.get("next_offset"), Some(&serde_json::Value::Null));
+
+        let constraints =
+            serde_json::from_str::<EventListConstraints>(r#"{"countMode":"none"}"#).unwrap();
+        assert_eq!(constraints.count_mode, Some(CountMode::None));
+    }
+
+    #[test]
+    fn test_ndjson_line_masks_secret_fields() {
+        let line = EventListItemResponse {
+            delivery_url: Some(Secret::new(
+                "https://merchant.example.com/webhooks".to_string(),
+            )),
+            ..list_item()
+        }
+        .to_ndjson_line()
+        .unwrap();
+
+        assert!(!line.contains('\n'));
+        assert!(!line.contains("merchant.example.com"));
+
+        let value = serde_json::from_str::<serde_json::Value>(&line).unwrap();
+        assert_eq!(
+            value.get("event_id"),
+            Some(&serde_json::json!("evt_018e31720d1b7a2b82677d3032cab959"))
+        );
+        assert_eq!(
+            value.get("created"),
+            Some(&serde_json::json!("2024-01-01T00:00:00.000Z"))
+        );
+        assert!(value
+            .get("delivery_url")
+            .is_some_and(|delivery_url| delivery_url.is_string()));
+    }
+
+    #[test]
+    fn test_connector_serialization() {
+        let value = serde_json::to_value(list_item()).unwrap();
+        assert_eq!(value.get("connector"), Some(&serde_json::json!("stripe")));
+
+        let value = serde_json::to_value(EventListItemResponse {
+            connector: None,
+            ..list_item()
+        })
+        .unwrap();
+        assert_eq!(value.get("connector"), Some(&serde_json::Value::Null));
+
+        let constraints =
+            serde_json::from_str::<EventListConstraints>(r#"{"connector":"adyen"}"#).unwrap();
+        assert_eq!(constraints.connector.as_deref(), Some("adyen"));
+        assert_eq!(
+            serde_json::from_str::<EventListConstraints>("{}")
+                .unwrap()
+                .connector,
+            None
+        );
+    }
+
+    #[test]
+    fn test_ingested_at_serialization() {
+        let value = serde_json::to_value(EventListItemResponse {
+            ingested_at: Some(date_time(1)),
+            ..list_item()
+        })
+        .unwrap();
+        assert_eq!(
+            value.get("ingested_at"),
+            Some(&serde_json::json!("2024-01-01T00:00:00.000Z"))
+        );
+
+        let value = serde_json::to_value(list_item()).unwrap();
+        assert_eq!(value.get("ingested_at"), Some(&serde_json::Value::Null));
+
+        let constraints = serde_json::from_str::<EventListConstraints>(
+            r#"{"ingestedAfter":"2024-01-01T00:00:00Z","ingested_before":"2024-01-02T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(constraints.ingested_after, Some(date_time(1)));
+        assert_eq!(constraints.ingested_before, Some(date_time(2)));
+    }
+
+    #[test]
+    fn test_dedup_collapses_duplicate_events() {
+        let event = |event_id: &str, object_id: &str, event_type: EventType, seconds: i64| {
+            EventListItemResponse {
+                event_id: to_event_id(event_id),
+                object_id: object_id.to_string(),
+                event_type,
+                created: date_time(1) + time::Duration::seconds(seconds),
+                ..list_item()
+            }
+        };
+        let events = vec![
+            event("evt_1", "pay_1", EventType::PaymentSucceeded, 10),
+            event("evt_2", "pay_1", EventType::PaymentFailed, 20),
+            event("evt_3", "pay_1", EventType::PaymentSucceeded, 40),
+            event("evt_4", "pay_2", EventType::PaymentSucceeded, 50),
+            event(
+                "evt_5",
+                "pay_1",
+                EventType::PaymentSucceeded,
+                EVENT_DEDUP_BUCKET_SECONDS + 10,
+            ),
+        ];
+
+        assert_eq!(events[0].dedup_key(), events[2].dedup_key());
+        assert_ne!(events[0].dedup_key(), events[1].dedup_key());
+        assert_ne!(events[0].dedup_key(), events[3].dedup_key());
+        assert_ne!(events[0].dedup_key(), events[4].dedup_key());
+
+        assert_eq!(
+            EventListItemResponse::dedup(events)
+                .iter()
+                .map(|event| event.event_id.get_string_repr())
+                .collect::<Vec<_>>(),
+            vec!["evt_2", "evt_3", "evt_4", "evt_5"]
+        );
+    }
+
+    #[test]
+    fn test_ndjson_line_without_delivery_url() {
+        let line = list_item().to_ndjson_line().unwrap();
+        let value = serde_json::from_str::<serde_json::Value>(&line).unwrap();
+
+        assert_eq!(value.get("delivery_url"), Some(&serde_json::Value::Null));
+    }
+}
@@ -0,0 +1,710 @@
+//! The requests for listing, exporting, syncing, retrieving and tagging events.
+
+use std::collections::{HashMap, HashSet};
+
+use common_enums::EventField;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{
+    EventId, EventListConstraints, EventListConstraintsError, EventListItemResponse,
+    EventRetrieveResponse, DEFAULT_EVENT_LIST_LIMIT, MAX_EVENT_BATCH_RETRIEVE_IDS,
+    MAX_EVENT_LIST_LIMIT, MAX_EVENT_TAGS, MAX_EVENT_TAG_LENGTH,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for EventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// A request to list the events of a single business profile, where the events are always limited
+/// to `profile_id` irrespective of the `profile_id` specified in the constraints.
+#[derive(Debug, serde::Serialize)]
+pub struct ProfileEventListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub constraints: EventListConstraints,
+}
+
+impl ProfileEventListRequestInternal {
+    /// Converts the request into a request for listing the merchant's events, with the constraints
+    /// limited to the profile. Fails if the constraints specify a different profile.
+    pub fn into_event_list_request(
+        self,
+    ) -> Result<EventListRequestInternal, EventListConstraintsError> {
+        let Self {
+            merchant_id,
+            profile_id,
+            mut constraints,
+        } = self;
+
+        if let Some(constraints_profile_id) = constraints
+            .profile_id
+            .take()
+            .filter(|constraints_profile_id| *constraints_profile_id != profile_id)
+        {
+            return Err(EventListConstraintsError::ProfileIdMismatch {
+                profile_id: constraints_profile_id,
+                expected_profile_id: profile_id,
+            });
+        }
+        constraints.profile_id = Some(profile_id);
+
+        Ok(EventListRequestInternal {
+            merchant_id,
+            constraints,
+        })
+    }
+}
+
+impl common_utils::events::ApiEventMetric for ProfileEventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventsCountRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for EventsCountRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request body for exporting events.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct EventListExportRequest {
+    /// The constraints to apply when selecting the events to export.
+    #[serde(flatten)]
+    pub constraints: EventListConstraints,
+
+    /// The fields of the events to export, in the order they are serialized. All the fields are
+    /// exported when not specified.
+    #[schema(example = json!(["event_id", "event_type", "created"]))]
+    pub fields: Option<Vec<EventField>>,
+}
+
+/// The errors encountered when validating [`EventListExportRequestInternal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventListExportRequestError {
+    /// `fields` is specified, but does not contain any field.
+    EmptyFields,
+    /// A field is specified more than once in `fields`.
+    DuplicateField { field: EventField },
+}
+
+impl std::fmt::Display for EventListExportRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyFields => write!(f, "`fields` must contain at least one field"),
+            Self::DuplicateField { field } => {
+                write!(
+                    f,
+                    "The field `{field}` is specified more than once in `fields`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventListExportRequestError {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventListExportRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventListConstraints,
+    pub fields: Option<Vec<EventField>>,
+}
+
+impl EventListExportRequestInternal {
+    /// Validates that `fields`, when specified, contains at least one field and no field more
+    /// than once.
+    pub fn validate(&self) -> Result<(), EventListExportRequestError> {
+        let Some(fields) = self.fields.as_ref() else {
+            return Ok(());
+        };
+
+        if fields.is_empty() {
+            return Err(EventListExportRequestError::EmptyFields);
+        }
+
+        let mut seen_fields = HashSet::new();
+        if let Some(field) = fields.iter().find(|field| !seen_fields.insert(**field)) {
+            return Err(EventListExportRequestError::DuplicateField { field: *field });
+        }
+
+        Ok(())
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventListExportRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request body for incrementally syncing events, such as for mirroring events into a data
+/// warehouse.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct EventSyncRequest {
+    /// The `last_event_id` returned by the previous sync. When not specified, events are synced
+    /// from the oldest event that can be listed. The sync must be restarted without it once the
+    /// event falls outside the window of events that can be listed.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub since_event_id: Option<String>,
+
+    /// The maximum number of events to return. Defaults to [`DEFAULT_EVENT_LIST_LIMIT`] and is
+    /// clamped to [`MAX_EVENT_LIST_LIMIT`].
+    #[schema(example = 20)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventSyncRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub since_event_id: Option<String>,
+    pub limit: u16,
+}
+
+impl EventSyncRequestInternal {
+    /// Constructs the internal request, applying the default and maximum limits.
+    pub fn new(merchant_id: common_utils::id_type::MerchantId, request: EventSyncRequest) -> Self {
+        Self {
+            merchant_id,
+            since_event_id: request.since_event_id,
+            limit: request
+                .limit
+                .unwrap_or(DEFAULT_EVENT_LIST_LIMIT)
+                .min(MAX_EVENT_LIST_LIMIT),
+        }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventSyncRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body of the incremental events sync api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventSyncResponse {
+    /// The initial delivery attempts created after `since_event_id`, ordered by their creation
+    /// time and event ID.
+    pub events: Vec<EventListItemResponse>,
+
+    /// The `since_event_id` to pass in the next sync. This is the ID of the last event returned,
+    /// or the `since_event_id` of the request when no new events were returned.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub last_event_id: Option<String>,
+}
+
+impl EventSyncResponse {
+    /// Constructs the response for the events synced after `since_event_id`.
+    pub fn new(events: Vec<EventListItemResponse>, since_event_id: Option<String>) -> Self {
+        let last_event_id = events
+            .last()
+            .map(|event| String::from(event.event_id.clone()))
+            .or(since_event_id);
+
+        Self {
+            events,
+            last_event_id,
+        }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventSyncResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.events.first().map(|event| event.merchant_id.clone())?,
+        })
+    }
+}
+
+/// The request body for retrieving multiple events at once.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventBatchRetrieveRequest {
+    /// The identifiers of the events to retrieve. At most 20 events can be retrieved at once.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventBatchRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_ids: Vec<String>,
+}
+
+/// The errors encountered when validating [`EventBatchRetrieveRequestInternal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventBatchRetrieveRequestError {
+    /// No event identifiers are specified in `event_ids`.
+    NoEventIds,
+    /// More event identifiers than [`MAX_EVENT_BATCH_RETRIEVE_IDS`] are specified in `event_ids`.
+    TooManyEventIds { count: usize, max_count: usize },
+}
+
+impl std::fmt::Display for EventBatchRetrieveRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoEventIds => write!(f, "`event_ids` must contain at least one event ID"),
+            Self::TooManyEventIds { max_count, .. } => {
+                write!(f, "At most {max_count} events can be retrieved at once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventBatchRetrieveRequestError {}
+
+impl EventBatchRetrieveRequestInternal {
+    /// Validates that at least one and at most [`MAX_EVENT_BATCH_RETRIEVE_IDS`] event identifiers
+    /// are specified.
+    pub fn validate(&self) -> Result<(), EventBatchRetrieveRequestError> {
+        if self.event_ids.is_empty() {
+            return Err(EventBatchRetrieveRequestError::NoEventIds);
+        }
+
+        if self.event_ids.len() > MAX_EVENT_BATCH_RETRIEVE_IDS {
+            return Err(EventBatchRetrieveRequestError::TooManyEventIds {
+                count: self.event_ids.len(),
+                max_count: MAX_EVENT_BATCH_RETRIEVE_IDS,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventBatchRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request body for tagging an event.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventTagRequest {
+    /// The tags to set on the event, replacing any tags previously added to it. An empty list
+    /// removes all the tags of the event. At most 10 tags of at most 64 characters each can be
+    /// added to an event.
+    #[schema(example = json!(["under-investigation"]))]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventTagRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_id: EventId,
+    pub tags: Vec<String>,
+}
+
+/// The errors encountered when validating [`EventTagRequestInternal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventTagRequestError {
+    /// A tag in `tags` is empty, or consists only of whitespace.
+    EmptyTag,
+    /// A tag in `tags` is longer than [`MAX_EVENT_TAG_LENGTH`] characters.
+    TagTooLong { tag: String, max_length: usize },
+    /// More distinct tags than [`MAX_EVENT_TAGS`] are specified in `tags`.
+    TooManyTags { count: usize, max_count: usize },
+}
+
+impl std::fmt::Display for EventTagRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyTag => write!(f, "`tags` must not contain empty tags"),
+            Self::TagTooLong { tag, max_length } => {
+                write!(f, "The tag `{tag}` is longer than {max_length} characters")
+            }
+            Self::TooManyTags { max_count, .. } => {
+                write!(f, "At most {max_count} tags can be added to an event")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventTagRequestError {}
+
+impl EventTagRequestInternal {
+    /// Returns the tags to be stored for the event, trimmed of surrounding whitespace, sorted and
+    /// without duplicates.
+    pub fn normalized_tags(&self) -> Vec<String> {
+        let mut tags = self
+            .tags
+            .iter()
+            .map(|tag| tag.trim().to_owned())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Validates that the tags are non-empty, at most [`MAX_EVENT_TAG_LENGTH`] characters long,
+    /// and that at most [`MAX_EVENT_TAGS`] distinct tags are specified.
+    pub fn validate(&self) -> Result<(), EventTagRequestError> {
+        let tags = self.normalized_tags();
+        if let Some(tag) = tags
+            .iter()
+            .find(|tag| tag.chars().count() > MAX_EVENT_TAG_LENGTH)
+        {
+            return Err(EventTagRequestError::TagTooLong {
+                tag: tag.to_owned(),
+                max_length: MAX_EVENT_TAG_LENGTH,
+            });
+        }
+
+        if tags.iter().any(String::is_empty) {
+            return Err(EventTagRequestError::EmptyTag);
+        }
+
+        if tags.len() > MAX_EVENT_TAGS {
+            return Err(EventTagRequestError::TooManyTags {
+                count: tags.len(),
+                max_count: MAX_EVENT_TAGS,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventTagRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The result of retrieving an event in a batch retrieval, tagged by whether the event was found.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum EventBatchRetrieveResult {
+    /// The event was found.
+    Found(Box<EventRetrieveResponse>),
+    /// No event exists with the requested identifier.
+    NotFound {
+        /// The identifier of the event that was not found.
+        #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+        event_id: String,
+    },
+}
+
+/// The response body of the batch retrieve api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventBatchRetrieveResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The result of retrieving each of the requested events, in the order they were requested.
+    pub events: Vec<EventBatchRetrieveResult>,
+}
+
+impl EventBatchRetrieveResponse {
+    /// Arranges the retrieved events in the order of the requested event identifiers, marking the
+    /// identifiers for which no event was retrieved as not found. Identifiers requested multiple
+    /// times are only included once.
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        event_ids: Vec<String>,
+        events: Vec<EventRetrieveResponse>,
+    ) -> Self {
+        let mut events_by_id = events
+            .into_iter()
+            .map(|event| {
+                (
+                    event
+                        .event_information
+                        .event_id
+                        .get_string_repr()
+                        .to_owned(),
+                    event,
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let mut seen_event_ids = HashSet::new();
+        let events = event_ids
+            .into_iter()
+            .filter(|event_id| seen_event_ids.insert(event_id.clone()))
+            .map(|event_id| match events_by_id.remove(&event_id) {
+                Some(event) => EventBatchRetrieveResult::Found(Box::new(event)),
+                None => EventBatchRetrieveResult::NotFound { event_id },
+            })
+            .collect();
+
+        Self {
+            merchant_id,
+            events,
+        }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventBatchRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::test_utils::{delivery_attempt, list_item, to_event_id};
+
+    #[test]
+    fn test_sync_request_limit_defaults_and_clamps() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        for (limit, expected_limit) in [(None, 20), (Some(50), 50), (Some(500), 100)] {
+            let request = EventSyncRequestInternal::new(
+                merchant_id.clone(),
+                EventSyncRequest {
+                    since_event_id: None,
+                    limit,
+                },
+            );
+            assert_eq!(request.limit, expected_limit);
+        }
+    }
+
+    #[test]
+    fn test_sync_response_last_event_id() {
+        let events = vec![
+            EventListItemResponse {
+                event_id: to_event_id("evt_1"),
+                ..list_item()
+            },
+            EventListItemResponse {
+                event_id: to_event_id("evt_2"),
+                ..list_item()
+            },
+        ];
+        let response = EventSyncResponse::new(events, Some("evt_0".to_string()));
+        assert_eq!(response.last_event_id.as_deref(), Some("evt_2"));
+
+        // The cursor is retained when there are no new events, so that the next sync resumes from
+        // the same position.
+        let response = EventSyncResponse::new(Vec::new(), Some("evt_2".to_string()));
+        assert_eq!(response.last_event_id.as_deref(), Some("evt_2"));
+        assert_eq!(EventSyncResponse::new(Vec::new(), None).last_event_id, None);
+    }
+
+    fn profile_event_list_request(
+        constraints_profile_id: Option<&str>,
+    ) -> ProfileEventListRequestInternal {
+        ProfileEventListRequestInternal {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1"))
+                .unwrap(),
+            constraints: EventListConstraints {
+                profile_id: constraints_profile_id.map(|profile_id| {
+                    common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                        profile_id.to_string(),
+                    ))
+                    .unwrap()
+                }),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_profile_event_list_request_forces_profile_id() {
+        for constraints_profile_id in [None, Some("pro_1")] {
+            let request = profile_event_list_request(constraints_profile_id)
+                .into_event_list_request()
+                .unwrap();
+
+            assert_eq!(request.merchant_id.get_string_repr(), "merchant_1");
+            assert_eq!(
+                request
+                    .constraints
+                    .profile_id
+                    .as_ref()
+                    .map(|profile_id| profile_id.get_string_repr()),
+                Some("pro_1")
+            );
+        }
+    }
+
+    #[test]
+    fn test_profile_event_list_request_rejects_other_profile() {
+        let error = profile_event_list_request(Some("pro_2"))
+            .into_event_list_request()
+            .unwrap_err();
+
+        assert!(matches!(
+            &error,
+            EventListConstraintsError::ProfileIdMismatch {
+                profile_id,
+                expected_profile_id,
+            } if profile_id.get_string_repr() == "pro_2"
+                && expected_profile_id.get_string_repr() == "pro_1"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "`profile_id` must match the profile the events are being listed for"
+        );
+    }
+
+    #[test]
+    fn test_batch_retrieve_request_validation() {
+        let request = |count: usize| EventBatchRetrieveRequestInternal {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            event_ids: (0..count).map(|index| format!("evt_{index}")).collect(),
+        };
+
+        assert_eq!(request(1).validate(), Ok(()));
+        assert_eq!(request(MAX_EVENT_BATCH_RETRIEVE_IDS).validate(), Ok(()));
+        assert_eq!(
+            request(0).validate(),
+            Err(EventBatchRetrieveRequestError::NoEventIds)
+        );
+
+        let error = request(MAX_EVENT_BATCH_RETRIEVE_IDS + 1)
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            EventBatchRetrieveRequestError::TooManyEventIds {
+                count: MAX_EVENT_BATCH_RETRIEVE_IDS + 1,
+                max_count: MAX_EVENT_BATCH_RETRIEVE_IDS,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "At most 20 events can be retrieved at once"
+        );
+    }
+
+    #[test]
+    fn test_batch_retrieve_response_marks_missing_events() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let response = EventBatchRetrieveResponse::new(
+            merchant_id,
+            vec![
+                "evt_missing".to_string(),
+                "evt_found_2".to_string(),
+                "evt_found_1".to_string(),
+                "evt_found_2".to_string(),
+            ],
+            vec![
+                delivery_attempt("evt_found_1", 1),
+                delivery_attempt("evt_found_2", 2),
+            ],
+        );
+
+        assert_eq!(
+            response
+                .events
+                .iter()
+                .map(|result| match result {
+                    EventBatchRetrieveResult::Found(event) => {
+                        (event.event_information.event_id.get_string_repr(), true)
+                    }
+                    EventBatchRetrieveResult::NotFound { event_id } => (event_id.as_str(), false),
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                ("evt_missing", false),
+                ("evt_found_2", true),
+                ("evt_found_1", true),
+            ]
+        );
+
+        let value = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            value.pointer("/events/0"),
+            Some(&serde_json::json!({ "status": "not_found", "event_id": "evt_missing" }))
+        );
+        assert_eq!(
+            value.pointer("/events/1/status"),
+            Some(&serde_json::json!("found"))
+        );
+        assert_eq!(
+            value.pointer("/events/1/event_id"),
+            Some(&serde_json::json!("evt_found_2"))
+        );
+        assert!(value.pointer("/events/1/request").is_some());
+    }
+
+    #[test]
+    fn test_event_tag_request_validation() {
+        let request = |tags: Vec<&str>| EventTagRequestInternal {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            event_id: to_event_id("evt_1"),
+            tags: tags.into_iter().map(ToOwned::to_owned).collect(),
+        };
+
+        let tag_request = request(vec![" under-investigation ", "incident-42", "incident-42"]);
+        assert_eq!(tag_request.validate(), Ok(()));
+        assert_eq!(
+            tag_request.normalized_tags(),
+            vec!["incident-42".to_string(), "under-investigation".to_string()]
+        );
+        assert_eq!(request(Vec::new()).validate(), Ok(()));
+
+        assert_eq!(
+            request(vec!["  "]).validate(),
+            Err(EventTagRequestError::EmptyTag)
+        );
+        let long_tag = "t".repeat(MAX_EVENT_TAG_LENGTH + 1);
+        assert_eq!(
+            request(vec![long_tag.as_str()]).validate(),
+            Err(EventTagRequestError::TagTooLong {
+                tag: long_tag.clone(),
+                max_length: MAX_EVENT_TAG_LENGTH,
+            })
+        );
+        let tags = (0..=MAX_EVENT_TAGS)
+            .map(|index| format!("tag-{index}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            request(tags.iter().map(String::as_str).collect()).validate(),
+            Err(EventTagRequestError::TooManyTags {
+                count: MAX_EVENT_TAGS + 1,
+                max_count: MAX_EVENT_TAGS,
+            })
+        );
+    }
+}
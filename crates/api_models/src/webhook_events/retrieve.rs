@@ -0,0 +1,1065 @@
+//! The response of retrieving a delivery attempt of an event, along with the summaries of the
+//! other delivery attempts of the event.
+
+use common_enums::{
+    WebhookDeliveryAttempt, WebhookDeliveryErrorCategory, WebhookOutcome, WebhookRetryTrigger,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use super::{
+    check_retry_event_age, EventId, EventListItemResponse, OutgoingWebhookRequestContent,
+    OutgoingWebhookResponseContent, WebhookRetryEligibilityError, MASKED_VALUE_PLACEHOLDER,
+    MAX_EVENT_DELIVERY_ATTEMPTS,
+};
+
+/// The JSON pointers to the secret values of a serialized [`EventRetrieveResponse`], masked by
+/// [`EventRetrieveResponseView`] unless revealed.
+const EVENT_RETRIEVE_RESPONSE_SECRET_POINTERS: [&str; 4] = [
+    "/delivery_url",
+    "/request/body",
+    "/request/delivery_url",
+    "/response/body",
+];
+
+/// The JSON pointers to the headers of a serialized [`EventRetrieveResponse`], whose values are
+/// masked by [`EventRetrieveResponseView`] unless revealed.
+const EVENT_RETRIEVE_RESPONSE_HEADER_POINTERS: [&str; 2] =
+    ["/request/headers", "/response/headers"];
+
+/// The response body for retrieving an event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventRetrieveResponse {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+
+    /// The request information (headers and body) sent in the webhook.
+    pub request: OutgoingWebhookRequestContent,
+
+    /// The response information (headers, body and status code) received for the webhook sent.
+    pub response: OutgoingWebhookResponseContent,
+
+    /// The idempotency key sent in the webhook, which can be used to match a redelivered webhook
+    /// with the original delivery.
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub idempotency_key: Option<String>,
+
+    /// The schema version of the webhook payload, if known.
+    #[schema(example = "2024-06-01")]
+    pub payload_version: Option<String>,
+
+    /// The hex-encoded SHA-256 digest of the request body, which can be compared across delivery
+    /// attempts to confirm that they were sent with identical payloads. This is unavailable when
+    /// the stored request body was truncated.
+    #[schema(example = "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a")]
+    pub request_body_sha256: Option<String>,
+
+    /// The source that triggered the delivery attempt, for retries and replays. This is
+    /// unavailable for initial delivery attempts, and for manual retries recorded before their
+    /// source was stored.
+    #[schema(value_type = Option<WebhookRetryTrigger>, example = "manual_dashboard")]
+    pub triggered_by: Option<WebhookRetryTrigger>,
+
+    /// Indicates whether the request and response bodies were persisted for the delivery attempt.
+    /// Bodies are not persisted for event classes configured to omit them, in which case the
+    /// `request` and `response` bodies are empty.
+    #[schema(example = true)]
+    pub body_persisted: bool,
+
+    /// The ordinal number of the delivery attempt among the delivery attempts sharing the same
+    /// initial delivery attempt, starting at `1` for the initial attempt. Replays are delivered to
+    /// another URL, and are not numbered.
+    #[schema(example = 1)]
+    pub attempt_number: Option<u16>,
+
+    /// The time (in milliseconds) from the initial delivery attempt to the first delivery attempt
+    /// that succeeded, among the delivery attempts sharing the same initial delivery attempt,
+    /// excluding replays. This is unavailable if none of the delivery attempts have succeeded.
+    #[schema(example = 185000)]
+    pub time_to_first_success_ms: Option<u64>,
+
+    /// The timeline of all the delivery attempts sharing the same initial delivery attempt, in
+    /// the order they were attempted. Only included when requested with `include_attempts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<Vec<WebhookAttemptSummary>>,
+
+    #[serde(flatten)]
+    pub retry_schedule: EventRetrySchedule,
+}
+
+impl EventRetrieveResponse {
+    /// Numbers the delivery attempts sharing the same initial delivery attempt in the order they
+    /// were created. The initial attempt is always numbered `1`, irrespective of its creation time.
+    /// Replays are delivered to another URL, and are not numbered.
+    pub fn assign_attempt_numbers(attempts: &mut [Self]) {
+        let mut attempt_number = 0u16;
+        for index in Self::attempt_order(attempts) {
+            if let Some(attempt) = attempts.get_mut(index) {
+                attempt.attempt_number = if attempt.is_replay() {
+                    None
+                } else {
+                    attempt_number = attempt_number.saturating_add(1);
+                    Some(attempt_number)
+                };
+            }
+        }
+    }
+
+    /// Computes the time (in milliseconds) between the creation of the initial delivery attempt
+    /// and the creation of the first delivery attempt that received a successful (2xx) response,
+    /// among the delivery attempts sharing the same initial delivery attempt. Replays are delivered
+    /// to another URL, and are not considered. Returns `None` if none of the delivery attempts
+    /// succeeded, or if the initial delivery attempt is missing.
+    pub fn time_to_first_success_ms(attempts: &[Self]) -> Option<u64> {
+        let initial_attempt = attempts.iter().find(|attempt| {
+            attempt.event_information.event_id == attempt.event_information.initial_attempt_id
+        })?;
+        let first_successful_attempt = Self::attempt_order(attempts)
+            .into_iter()
+            .filter_map(|index| attempts.get(index))
+            .find(|attempt| {
+                !attempt.is_replay()
+                    && attempt
+                        .response
+                        .status_code
+                        .is_some_and(|status_code| (200..300).contains(&status_code))
+            })?;
+
+        u64::try_from(
+            (first_successful_attempt.event_information.created
+                - initial_attempt.event_information.created)
+                .whole_milliseconds(),
+        )
+        .ok()
+    }
+
+    /// Summarizes the delivery attempts sharing the same initial delivery attempt into a
+    /// timeline, ordered the same way as [`Self::assign_attempt_numbers`] numbers them.
+    pub fn attempt_timeline(attempts: &[Self]) -> Vec<WebhookAttemptSummary> {
+        Self::attempt_order(attempts)
+            .into_iter()
+            .filter_map(|index| attempts.get(index))
+            .map(|attempt| WebhookAttemptSummary {
+                event_id: attempt.event_information.event_id.clone(),
+                created: attempt.event_information.created,
+                status_code: attempt.response.status_code,
+                error_category: attempt.response.error_category,
+                delivery_attempt: attempt.event_information.delivery_attempt,
+                is_delivery_successful: attempt.event_information.is_delivery_successful,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the delivery attempt is a replay, delivered to a URL other than the
+    /// endpoint configured for the business profile.
+    fn is_replay(&self) -> bool {
+        self.event_information.delivery_attempt == Some(WebhookDeliveryAttempt::ManualReplay)
+    }
+
+    /// Returns the indices of the delivery attempts in the order they were attempted, with the
+    /// initial attempt first, followed by the retries in the order they were created.
+    fn attempt_order(attempts: &[Self]) -> Vec<usize> {
+        let mut attempt_order = attempts
+            .iter()
+            .enumerate()
+            .map(|(index, attempt)| {
+                let information = &attempt.event_information;
+                let is_retry = information.event_id != information.initial_attempt_id;
+                (
+                    (is_retry, information.created, information.event_id.clone()),
+                    index,
+                )
+            })
+            .collect::<Vec<_>>();
+        attempt_order.sort();
+
+        attempt_order.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// Returns the number of bytes stored for the event, summing the lengths of the request and
+    /// response bodies and of the names and values of their headers.
+    pub fn total_stored_bytes(&self) -> usize {
+        self.request
+            .stored_bytes()
+            .saturating_add(self.response.stored_bytes())
+    }
+
+    /// Returns `true` if the event can still be retried, as per [`Self::check_retryable`] with
+    /// [`DEFAULT_RETRY_MAX_EVENT_AGE`](super::DEFAULT_RETRY_MAX_EVENT_AGE).
+    pub fn is_retryable(&self) -> bool {
+        self.check_retryable(common_utils::date_time::now(), None)
+            .is_ok()
+    }
+
+    /// Checks that the event can still be retried at `now`: the webhook must not have been
+    /// delivered already, the event must be at most `max_event_age` old, it must have been
+    /// attempted fewer than [`MAX_EVENT_DELIVERY_ATTEMPTS`] times, webhooks must be enabled for its
+    /// class, and its request body must have been persisted. The delivery attempts are counted
+    /// from the timeline in `attempts` when included, and from `attempt_number` otherwise. Replays
+    /// are delivered to another URL, and neither deliver the webhook nor count as attempts.
+    pub fn check_retryable(
+        &self,
+        now: PrimitiveDateTime,
+        max_event_age: Option<time::Duration>,
+    ) -> Result<(), WebhookRetryEligibilityError> {
+        let information = &self.event_information;
+        let is_delivered = information.outcome == WebhookOutcome::Delivered
+            || self.attempts.as_ref().is_some_and(|attempts| {
+                attempts
+                    .iter()
+                    .any(|attempt| !attempt.is_replay() && !attempt.is_failure())
+            });
+        if is_delivered {
+            return Err(WebhookRetryEligibilityError::AlreadyDelivered);
+        }
+
+        check_retry_event_age(information.created, now, max_event_age)
+            .map_err(WebhookRetryEligibilityError::EventTooOld)?;
+
+        let attempts = self
+            .attempts
+            .as_ref()
+            .map(|attempts| {
+                attempts
+                    .iter()
+                    .filter(|attempt| !attempt.is_replay())
+                    .count()
+            })
+            .or_else(|| self.attempt_number.map(usize::from))
+            .unwrap_or(1);
+        if attempts >= MAX_EVENT_DELIVERY_ATTEMPTS {
+            return Err(WebhookRetryEligibilityError::TooManyAttempts {
+                attempts,
+                max_attempts: MAX_EVENT_DELIVERY_ATTEMPTS,
+            });
+        }
+
+        if !information.class_enabled {
+            return Err(WebhookRetryEligibilityError::EventClassDisabled {
+                event_class: information.event_class,
+            });
+        }
+
+        if !self.body_persisted {
+            return Err(WebhookRetryEligibilityError::BodyNotPersisted);
+        }
+
+        Ok(())
+    }
+
+    /// Computes the [RFC 6902](https://datatracker.ietf.org/doc/html/rfc6902) JSON Patch which
+    /// transforms the request body of the previous event into the request body of this event,
+    /// such as the changes to a payment between two consecutive events. Fails if either of the
+    /// request bodies cannot be parsed as JSON. The patch is not masked, and must not be logged.
+    pub fn json_patch_from(&self, prev: &Self) -> Result<serde_json::Value, serde_json::Error> {
+        let prev_body = prev.request.parsed_body()?;
+        let body = self.request.parsed_body()?;
+
+        let mut operations = Vec::new();
+        json_patch_operations(String::new(), &prev_body, &body, &mut operations);
+
+        Ok(serde_json::Value::Array(operations))
+    }
+}
+
+/// Appends the JSON Patch operations which transform the `prev` value at the JSON pointer `path`
+/// into the `current` value. Objects are compared key by key and arrays index by index, while any
+/// other differing values are replaced as a whole.
+fn json_patch_operations(
+    path: String,
+    prev: &serde_json::Value,
+    current: &serde_json::Value,
+    operations: &mut Vec<serde_json::Value>,
+) {
+    use serde_json::{json, Value};
+
+    match (prev, current) {
+        (Value::Object(prev), Value::Object(current)) => {
+            for (key, prev_value) in prev {
+                let key_path = format!("{path}/{}", escape_json_pointer_token(key));
+                match current.get(key) {
+                    Some(value) => json_patch_operations(key_path, prev_value, value, operations),
+                    None => operations.push(json!({ "op": "remove", "path": key_path })),
+                }
+            }
+            for (key, value) in current {
+                if !prev.contains_key(key) {
+                    let key_path = format!("{path}/{}", escape_json_pointer_token(key));
+                    operations.push(json!({ "op": "add", "path": key_path, "value": value }));
+                }
+            }
+        }
+        (Value::Array(prev), Value::Array(current)) => {
+            for (index, (prev_value, value)) in prev.iter().zip(current).enumerate() {
+                json_patch_operations(format!("{path}/{index}"), prev_value, value, operations);
+            }
+            // Trailing elements are removed from the end, so that the indices of the remaining
+            // elements are unaffected by the preceding removals
+            for index in (current.len()..prev.len()).rev() {
+                operations.push(json!({ "op": "remove", "path": format!("{path}/{index}") }));
+            }
+            for (index, value) in current.iter().enumerate().skip(prev.len()) {
+                operations.push(json!({
+                    "op": "add",
+                    "path": format!("{path}/{index}"),
+                    "value": value,
+                }));
+            }
+        }
+        (prev, current) if prev != current => {
+            operations.push(json!({ "op": "replace", "path": path, "value": current }));
+        }
+        _ => {}
+    }
+}
+
+/// Escapes a reference token of a JSON pointer, as specified in
+/// [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901#section-3).
+fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// A summary of a delivery attempt, included in the timeline of the delivery attempts of an
+/// event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookAttemptSummary {
+    /// The identifier for the delivery attempt.
+    #[schema(value_type = String, max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: EventId,
+
+    /// Time at which the delivery attempt was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The HTTP status code received for the delivery attempt, if any.
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// The class of error encountered during the delivery attempt, if any.
+    pub error_category: Option<WebhookDeliveryErrorCategory>,
+
+    /// Indicates the type of delivery attempt.
+    pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+
+    /// Indicates whether the webhook was ultimately delivered or not.
+    pub is_delivery_successful: Option<bool>,
+}
+
+impl WebhookAttemptSummary {
+    /// Returns `true` if the delivery attempt did not receive a successful (2xx) response.
+    pub fn is_failure(&self) -> bool {
+        !self
+            .status_code
+            .is_some_and(|status_code| (200..300).contains(&status_code))
+    }
+
+    /// Returns `true` if the delivery attempt is a replay, delivered to a URL other than the
+    /// endpoint configured for the business profile.
+    pub fn is_replay(&self) -> bool {
+        self.delivery_attempt == Some(WebhookDeliveryAttempt::ManualReplay)
+    }
+
+    /// Returns `true` if both delivery attempts failed with the same error category and status
+    /// code.
+    fn is_identical_failure(&self, other: &Self) -> bool {
+        self.is_failure()
+            && other.is_failure()
+            && self.error_category == other.error_category
+            && self.status_code == other.status_code
+    }
+}
+
+/// A group of consecutive delivery attempts in the timeline of an event that failed identically,
+/// represented by the first delivery attempt of the group. Delivery attempts that did not fail
+/// identically to their predecessor form a group of their own.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CollapsedAttempt {
+    /// The first delivery attempt of the group.
+    #[serde(flatten)]
+    pub attempt: WebhookAttemptSummary,
+
+    /// The number of delivery attempts in the group.
+    #[schema(example = 5)]
+    pub count: u32,
+
+    /// Time at which the last delivery attempt of the group was created. This is the same as the
+    /// `created` time of the group for groups with a single delivery attempt.
+    #[schema(example = "2022-09-10T10:41:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_created: PrimitiveDateTime,
+}
+
+/// Collapses consecutive delivery attempts that failed with the same error category and status
+/// code into a single entry, recording the number of delivery attempts and the time span they
+/// cover. The attempts are expected in the order they were attempted, as returned by
+/// [`EventRetrieveResponse::attempt_timeline`]. Successful delivery attempts are never collapsed.
+pub fn collapse_identical_failures(attempts: Vec<WebhookAttemptSummary>) -> Vec<CollapsedAttempt> {
+    let mut collapsed_attempts: Vec<CollapsedAttempt> = Vec::with_capacity(attempts.len());
+
+    for attempt in attempts {
+        match collapsed_attempts.last_mut() {
+            Some(collapsed_attempt) if collapsed_attempt.attempt.is_identical_failure(&attempt) => {
+                collapsed_attempt.count = collapsed_attempt.count.saturating_add(1);
+                collapsed_attempt.last_created = attempt.created;
+            }
+            _ => collapsed_attempts.push(CollapsedAttempt {
+                last_created: attempt.created,
+                count: 1,
+                attempt,
+            }),
+        }
+    }
+
+    collapsed_attempts
+}
+
+/// The schedule of the automatic retries of an event. This is unavailable for events that are no
+/// longer being retried automatically.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EventRetrySchedule {
+    /// The time at which the next automatic retry of the event is scheduled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+
+    /// The number of automatic retries of the event that have occurred.
+    #[schema(example = 2)]
+    pub retry_count: Option<u16>,
+}
+
+impl common_utils::events::ApiEventMetric for EventRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.event_information.merchant_id.clone(),
+        })
+    }
+}
+
+/// A view of an [`EventRetrieveResponse`] for serialization, which renders the request and
+/// response bodies, the header values and the delivery URLs either masked as
+/// [`MASKED_VALUE_PLACEHOLDER`] or revealed. Views are masked unless explicitly revealed, so that
+/// the decision to reveal secrets is made in one place, rather than by each caller.
+#[derive(Debug)]
+pub struct EventRetrieveResponseView<'a> {
+    response: &'a EventRetrieveResponse,
+    reveal: bool,
+}
+
+impl<'a> EventRetrieveResponseView<'a> {
+    /// Creates a masked view of the response.
+    pub fn new(response: &'a EventRetrieveResponse) -> Self {
+        Self {
+            response,
+            reveal: false,
+        }
+    }
+
+    /// Reveals the secret values of the response if `reveal` is `true`. This must only be used
+    /// for consumers authorized to see the unmasked bodies and headers.
+    pub fn reveal(self, reveal: bool) -> Self {
+        Self { reveal, ..self }
+    }
+}
+
+impl Serialize for EventRetrieveResponseView<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.reveal {
+            return self.response.serialize(serializer);
+        }
+
+        let mut value = serde_json::to_value(self.response).map_err(serde::ser::Error::custom)?;
+        for pointer in EVENT_RETRIEVE_RESPONSE_SECRET_POINTERS {
+            if let Some(secret) = value
+                .pointer_mut(pointer)
+                .filter(|secret| !secret.is_null())
+            {
+                *secret = serde_json::Value::from(MASKED_VALUE_PLACEHOLDER);
+            }
+        }
+        for pointer in EVENT_RETRIEVE_RESPONSE_HEADER_POINTERS {
+            if let Some(serde_json::Value::Array(headers)) = value.pointer_mut(pointer) {
+                for header_value in headers.iter_mut().filter_map(|header| header.get_mut(1)) {
+                    *header_value = serde_json::Value::from(MASKED_VALUE_PLACEHOLDER);
+                }
+            }
+        }
+
+        value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::EventClass;
+    use masking::Secret;
+
+    use super::*;
+    use crate::webhook_events::test_utils::{
+        date_time, delivery_attempt, list_item, request_with_headers, response_with_status_code,
+        to_event_id,
+    };
+
+    #[test]
+    fn test_event_retrieve_response_view_masks_unless_revealed() {
+        let mut response = delivery_attempt("evt_1", 1);
+        response.event_information.delivery_url = Some(Secret::new(
+            "https://merchant.example.com/webhooks?token=url_secret".to_string(),
+        ));
+        response.request = OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"card":"4242424242424242"}"#.to_string()),
+            ..request_with_headers(vec![("x-webhook-signature-512", "sig_secret")])
+        };
+        response.response = OutgoingWebhookResponseContent {
+            body: Some(Secret::new("response_secret_body".to_string())),
+            headers: Some(vec![(
+                "content-type".to_string(),
+                Secret::new("text/html".to_string()),
+            )]),
+            ..response_with_status_code(Some(500))
+        };
+
+        let masked = serde_json::to_value(EventRetrieveResponseView::new(&response)).unwrap();
+        let revealed =
+            serde_json::to_value(EventRetrieveResponseView::new(&response).reveal(true)).unwrap();
+
+        let masked_string = masked.to_string();
+        for secret in [
+            "4242424242424242",
+            "sig_secret",
+            "url_secret",
+            "response_secret_body",
+            "text/html",
+        ] {
+            assert!(!masked_string.contains(secret), "{masked_string}");
+        }
+        assert_eq!(
+            masked.pointer("/request/headers/0"),
+            Some(&serde_json::json!(["x-webhook-signature-512", "***"]))
+        );
+        assert_eq!(
+            masked.pointer("/response/body"),
+            Some(&serde_json::json!("***"))
+        );
+        assert_eq!(
+            masked.pointer("/request/delivery_url"),
+            Some(&serde_json::Value::Null)
+        );
+        assert_eq!(masked.get("event_id"), revealed.get("event_id"));
+        assert_eq!(
+            masked.pointer("/response/status_code"),
+            Some(&serde_json::json!(500))
+        );
+
+        assert_eq!(revealed, serde_json::to_value(&response).unwrap());
+        assert_eq!(
+            revealed.pointer("/request/body"),
+            Some(&serde_json::json!(r#"{"card":"4242424242424242"}"#))
+        );
+        assert_eq!(
+            EventRetrieveResponseView::new(&response)
+                .reveal(false)
+                .serialize(serde_json::value::Serializer)
+                .unwrap(),
+            masked
+        );
+    }
+
+    #[test]
+    fn test_retry_schedule_serde_round_trip() {
+        let retry_schedule = EventRetrySchedule {
+            next_retry_at: Some(PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(10, 11, 12).unwrap(),
+            )),
+            retry_count: Some(2),
+        };
+        let serialized = serde_json::to_value(&retry_schedule).unwrap();
+
+        assert_eq!(
+            serialized,
+            serde_json::json!({
+                "next_retry_at": "2024-01-01T10:11:12.000Z",
+                "retry_count": 2
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<EventRetrySchedule>(serialized).unwrap(),
+            retry_schedule
+        );
+    }
+
+    #[test]
+    fn test_retry_schedule_serde_round_trip_for_terminal_events() {
+        let serialized = serde_json::to_value(EventRetrySchedule::default()).unwrap();
+
+        assert_eq!(
+            serialized,
+            serde_json::json!({ "next_retry_at": null, "retry_count": null })
+        );
+        assert_eq!(
+            serde_json::from_value::<EventRetrySchedule>(serialized).unwrap(),
+            EventRetrySchedule::default()
+        );
+        assert_eq!(
+            serde_json::from_str::<EventRetrySchedule>("{}").unwrap(),
+            EventRetrySchedule::default()
+        );
+    }
+
+    fn replay_attempt(event_id: &str, day: u8) -> EventRetrieveResponse {
+        let attempt = delivery_attempt(event_id, day);
+        EventRetrieveResponse {
+            event_information: EventListItemResponse {
+                delivery_attempt: Some(WebhookDeliveryAttempt::ManualReplay),
+                ..attempt.event_information
+            },
+            ..attempt
+        }
+    }
+
+    #[test]
+    fn test_attempt_numbers_of_delivery_attempt_chain() {
+        let initial_attempt_id = list_item().initial_attempt_id;
+        let mut attempts = vec![
+            delivery_attempt("evt_manual_retry", 3),
+            delivery_attempt(initial_attempt_id.get_string_repr(), 1),
+            delivery_attempt("evt_automatic_retry", 2),
+        ];
+
+        EventRetrieveResponse::assign_attempt_numbers(&mut attempts);
+
+        assert_eq!(
+            attempts
+                .iter()
+                .map(|attempt| (
+                    attempt.event_information.event_id.get_string_repr(),
+                    attempt.attempt_number
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("evt_manual_retry", Some(3)),
+                (initial_attempt_id.get_string_repr(), Some(1)),
+                ("evt_automatic_retry", Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_initial_attempt_is_numbered_first() {
+        let initial_attempt_id = list_item().initial_attempt_id;
+        // The retry is recorded with an earlier creation time than the initial attempt
+        let mut attempts = vec![
+            delivery_attempt("evt_retry", 1),
+            delivery_attempt(initial_attempt_id.get_string_repr(), 2),
+        ];
+
+        EventRetrieveResponse::assign_attempt_numbers(&mut attempts);
+
+        assert_eq!(attempts.first().unwrap().attempt_number, Some(2));
+        assert_eq!(attempts.get(1).unwrap().attempt_number, Some(1));
+    }
+
+    #[test]
+    fn test_replays_are_not_numbered() {
+        let initial_attempt_id = list_item().initial_attempt_id;
+        let mut attempts = vec![
+            delivery_attempt(initial_attempt_id.get_string_repr(), 1),
+            replay_attempt("evt_replay", 2),
+            delivery_attempt("evt_retry", 3),
+        ];
+
+        EventRetrieveResponse::assign_attempt_numbers(&mut attempts);
+
+        assert_eq!(
+            attempts
+                .iter()
+                .map(|attempt| attempt.attempt_number)
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_attempt_timeline_of_delivery_attempt_chain() {
+        let initial_attempt_id = list_item().initial_attempt_id;
+        let mut manual_retry = delivery_attempt("evt_manual_retry", 3);
+        manual_retry.response = response_with_status_code(Some(200));
+        manual_retry.event_information.delivery_attempt = Some(WebhookDeliveryAttempt::ManualRetry);
+        let attempts = vec![
+            manual_retry,
+            delivery_attempt(initial_attempt_id.get_string_repr(), 1),
+            delivery_attempt("evt_automatic_retry", 2),
+        ];
+
+        let timeline = EventRetrieveResponse::attempt_timeline(&attempts);
+
+        assert_eq!(
+            timeline
+                .iter()
+                .map(|attempt| (attempt.event_id.get_string_repr(), attempt.status_code))
+                .collect::<Vec<_>>(),
+            vec![
+                (initial_attempt_id.get_string_repr(), Some(500)),
+                ("evt_automatic_retry", Some(500)),
+                ("evt_manual_retry", Some(200)),
+            ]
+        );
+        assert_eq!(timeline.first().unwrap().created, date_time(1));
+        assert_eq!(
+            timeline.last().unwrap().delivery_attempt,
+            Some(WebhookDeliveryAttempt::ManualRetry)
+        );
+    }
+
+    fn attempt_summary(
+        event_id: &str,
+        minute: u8,
+        status_code: Option<u16>,
+        error_category: Option<WebhookDeliveryErrorCategory>,
+    ) -> WebhookAttemptSummary {
+        WebhookAttemptSummary {
+            event_id: to_event_id(event_id),
+            created: date_time(1) + time::Duration::minutes(minute.into()),
+            status_code,
+            error_category,
+            delivery_attempt: Some(WebhookDeliveryAttempt::AutomaticRetry),
+            is_delivery_successful: Some(false),
+        }
+    }
+
+    #[test]
+    fn test_collapse_identical_failures() {
+        let timeouts = (0..5)
+            .map(|minute| {
+                attempt_summary(
+                    &format!("evt_timeout_{minute}"),
+                    minute,
+                    None,
+                    Some(WebhookDeliveryErrorCategory::Timeout),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let collapsed_attempts = collapse_identical_failures(timeouts);
+        assert_eq!(collapsed_attempts.len(), 1);
+        let collapsed_attempt = collapsed_attempts.first().unwrap();
+        assert_eq!(collapsed_attempt.count, 5);
+        assert_eq!(
+            collapsed_attempt.attempt.event_id.get_string_repr(),
+            "evt_timeout_0"
+        );
+        assert_eq!(collapsed_attempt.attempt.created, date_time(1));
+        assert_eq!(
+            collapsed_attempt.last_created,
+            date_time(1) + time::Duration::minutes(4)
+        );
+    }
+
+    #[test]
+    fn test_collapse_identical_failures_keeps_distinct_attempts_apart() {
+        let non_2xx_status = Some(WebhookDeliveryErrorCategory::Non2xxStatus);
+        let attempts = vec![
+            attempt_summary("evt_500_a", 0, Some(500), non_2xx_status),
+            attempt_summary("evt_500_b", 1, Some(500), non_2xx_status),
+            attempt_summary("evt_503", 2, Some(503), non_2xx_status),
+            attempt_summary("evt_200_a", 3, Some(200), None),
+            attempt_summary("evt_200_b", 4, Some(200), None),
+            attempt_summary("evt_500_c", 5, Some(500), non_2xx_status),
+        ];
+
+        assert_eq!(
+            collapse_identical_failures(attempts)
+                .iter()
+                .map(|collapsed_attempt| (
+                    collapsed_attempt.attempt.event_id.get_string_repr(),
+                    collapsed_attempt.count
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("evt_500_a", 2),
+                ("evt_503", 1),
+                ("evt_200_a", 1),
+                ("evt_200_b", 1),
+                ("evt_500_c", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_to_first_success_of_delivery_attempt_chain() {
+        let initial_attempt_id = list_item().initial_attempt_id;
+        let mut third_attempt = delivery_attempt("evt_third_attempt", 3);
+        third_attempt.response = response_with_status_code(Some(200));
+        let mut fourth_attempt = delivery_attempt("evt_fourth_attempt", 4);
+        fourth_attempt.response = response_with_status_code(Some(204));
+        let mut attempts = vec![
+            fourth_attempt,
+            delivery_attempt("evt_second_attempt", 2),
+            third_attempt,
+            delivery_attempt(initial_attempt_id.get_string_repr(), 1),
+        ];
+
+        assert_eq!(
+            EventRetrieveResponse::time_to_first_success_ms(&attempts),
+            Some(2 * 24 * 60 * 60 * 1000)
+        );
+
+        // Replays delivered to another URL do not count as the first success
+        let mut replay = replay_attempt("evt_replay", 2);
+        replay.response = response_with_status_code(Some(200));
+        attempts.push(replay);
+        assert_eq!(
+            EventRetrieveResponse::time_to_first_success_ms(&attempts),
+            Some(2 * 24 * 60 * 60 * 1000)
+        );
+
+        // Chains that never succeed have no time to first success
+        attempts.retain(|attempt| attempt.response.status_code == Some(500));
+        assert_eq!(
+            EventRetrieveResponse::time_to_first_success_ms(&attempts),
+            None
+        );
+    }
+
+    #[test]
+    fn test_attempts_are_omitted_unless_included() {
+        let mut response = delivery_attempt("evt_1", 1);
+        let value = serde_json::to_value(&response).unwrap();
+        assert!(value.get("attempts").is_none());
+
+        response.attempts = Some(EventRetrieveResponse::attempt_timeline(
+            std::slice::from_ref(&response),
+        ));
+        let value = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            value
+                .get("attempts")
+                .and_then(|attempts| attempts.as_array())
+                .map(Vec::len),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_total_stored_bytes() {
+        let response = EventRetrieveResponse {
+            request: OutgoingWebhookRequestContent {
+                body: Secret::new(r#"{"amount":6540}"#.to_string()),
+                ..request_with_headers(vec![("content-type", "application/json")])
+            },
+            response: OutgoingWebhookResponseContent {
+                body: Some(Secret::new("ok".to_string())),
+                headers: Some(vec![(
+                    "server".to_string(),
+                    Secret::new("nginx".to_string()),
+                )]),
+                ..response_with_status_code(Some(200))
+            },
+            ..delivery_attempt("evt_1", 1)
+        };
+
+        // 15 bytes of body and 12 + 16 bytes of headers
+        assert_eq!(response.request.stored_bytes(), 43);
+        // 2 bytes of body and 6 + 5 bytes of headers
+        assert_eq!(response.response.stored_bytes(), 13);
+        assert_eq!(response.total_stored_bytes(), 56);
+
+        assert_eq!(response_with_status_code(None).stored_bytes(), 0);
+    }
+
+    #[test]
+    fn test_json_patch_between_payment_events() {
+        let event_with_body = |event_id: &str, body: &str| EventRetrieveResponse {
+            request: OutgoingWebhookRequestContent {
+                body: Secret::new(body.to_string()),
+                ..request_with_headers(vec![("content-type", "application/json")])
+            },
+            ..delivery_attempt(event_id, 1)
+        };
+        let prev = event_with_body(
+            "evt_1",
+            r#"{"content":{"object":{"amount":6540,"payment_id":"pay_1","status":"processing"}}}"#,
+        );
+        let current = event_with_body(
+            "evt_2",
+            r#"{"content":{"object":{"amount":5000,"payment_id":"pay_1","status":"succeeded"}}}"#,
+        );
+
+        assert_eq!(
+            current.json_patch_from(&prev).unwrap(),
+            serde_json::json!([
+                { "op": "replace", "path": "/content/object/amount", "value": 5000 },
+                { "op": "replace", "path": "/content/object/status", "value": "succeeded" },
+            ])
+        );
+        assert_eq!(
+            current.json_patch_from(&current).unwrap(),
+            serde_json::json!([])
+        );
+
+        // Keys are escaped, and trailing array elements are removed from the end
+        assert_eq!(
+            event_with_body("evt_4", r#"{"tags":["a"]}"#)
+                .json_patch_from(&event_with_body(
+                    "evt_3",
+                    r#"{"order/id":1,"tags":["a","b","c"]}"#
+                ))
+                .unwrap(),
+            serde_json::json!([
+                { "op": "remove", "path": "/order~1id" },
+                { "op": "remove", "path": "/tags/2" },
+                { "op": "remove", "path": "/tags/1" },
+            ])
+        );
+
+        assert!(current
+            .json_patch_from(&event_with_body("evt_5", r#"{"content":{"obj"#))
+            .is_err());
+    }
+
+    #[test]
+    fn test_retryable_event() {
+        let failed_attempt = |event_id: &str| {
+            let attempt = delivery_attempt(event_id, 1);
+            EventRetrieveResponse {
+                event_information: EventListItemResponse {
+                    is_delivery_successful: Some(false),
+                    outcome: WebhookOutcome::Failed,
+                    ..attempt.event_information
+                },
+                ..attempt
+            }
+        };
+        let event = failed_attempt("evt_1");
+
+        assert_eq!(event.check_retryable(date_time(2), None), Ok(()));
+        assert_eq!(
+            EventRetrieveResponse {
+                attempts: Some(EventRetrieveResponse::attempt_timeline(&[
+                    failed_attempt("evt_1"),
+                    failed_attempt("evt_2"),
+                ])),
+                ..failed_attempt("evt_1")
+            }
+            .check_retryable(date_time(2), None),
+            Ok(())
+        );
+        // Older events can be retried with a larger maximum age
+        assert_eq!(
+            event.check_retryable(date_time(20), Some(time::Duration::days(30))),
+            Ok(())
+        );
+
+        // Replays delivered to another URL neither deliver the event nor count as attempts
+        let delivered_replay = EventRetrieveResponse {
+            response: response_with_status_code(Some(200)),
+            ..replay_attempt("evt_replay", 1)
+        };
+        let mut attempts = (1..MAX_EVENT_DELIVERY_ATTEMPTS)
+            .map(|_| failed_attempt("evt_1"))
+            .collect::<Vec<_>>();
+        attempts.push(delivered_replay);
+        assert_eq!(
+            EventRetrieveResponse {
+                attempts: Some(EventRetrieveResponse::attempt_timeline(&attempts)),
+                ..failed_attempt("evt_1")
+            }
+            .check_retryable(date_time(2), None),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_non_retryable_events() {
+        let failed_attempt = || {
+            let attempt = delivery_attempt("evt_1", 1);
+            EventRetrieveResponse {
+                event_information: EventListItemResponse {
+                    is_delivery_successful: Some(false),
+                    outcome: WebhookOutcome::Failed,
+                    ..attempt.event_information
+                },
+                ..attempt
+            }
+        };
+
+        // Delivered by the event itself, or by one of its other delivery attempts
+        assert_eq!(
+            delivery_attempt("evt_1", 1).check_retryable(date_time(2), None),
+            Err(WebhookRetryEligibilityError::AlreadyDelivered)
+        );
+        let delivered_attempt = EventRetrieveResponse {
+            response: response_with_status_code(Some(200)),
+            ..delivery_attempt("evt_2", 1)
+        };
+        assert_eq!(
+            EventRetrieveResponse {
+                attempts: Some(EventRetrieveResponse::attempt_timeline(&[
+                    failed_attempt(),
+                    delivered_attempt,
+                ])),
+                ..failed_attempt()
+            }
+            .check_retryable(date_time(2), None),
+            Err(WebhookRetryEligibilityError::AlreadyDelivered)
+        );
+
+        assert!(matches!(
+            failed_attempt().check_retryable(date_time(5), None),
+            Err(WebhookRetryEligibilityError::EventTooOld(_))
+        ));
+
+        let error = EventRetrieveResponse {
+            attempt_number: u16::try_from(MAX_EVENT_DELIVERY_ATTEMPTS).ok(),
+            ..failed_attempt()
+        }
+        .check_retryable(date_time(2), None)
+        .unwrap_err();
+        assert_eq!(
+            error,
+            WebhookRetryEligibilityError::TooManyAttempts {
+                attempts: MAX_EVENT_DELIVERY_ATTEMPTS,
+                max_attempts: MAX_EVENT_DELIVERY_ATTEMPTS,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "The event was already attempted 30 times, which reaches the maximum of 30 delivery \
+             attempts allowed for an event"
+        );
+
+        let attempt = failed_attempt();
+        let error = EventRetrieveResponse {
+            event_information: EventListItemResponse {
+                class_enabled: false,
+                ..attempt.event_information
+            },
+            ..attempt
+        }
+        .check_retryable(date_time(2), None)
+        .unwrap_err();
+        assert_eq!(
+            error,
+            WebhookRetryEligibilityError::EventClassDisabled {
+                event_class: EventClass::Payments
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Webhooks are disabled for the `payments` event class, and the event cannot be retried"
+        );
+
+        assert_eq!(
+            EventRetrieveResponse {
+                body_persisted: false,
+                ..failed_attempt()
+            }
+            .check_retryable(date_time(2), None),
+            Err(WebhookRetryEligibilityError::BodyNotPersisted)
+        );
+        assert!(!delivery_attempt("evt_1", 1).is_retryable());
+    }
+}
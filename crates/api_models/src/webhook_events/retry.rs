@@ -0,0 +1,590 @@
+//! The requests and responses of listing, retrying and replaying the delivery attempts of events.
+
+use common_enums::{EventClass, WebhookRetryTrigger};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use super::{EventId, EventListConstraints, EventRetrieveResponse, DEFAULT_RETRY_MAX_EVENT_AGE};
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryAttemptListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub initial_attempt_id: EventId,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryRetryRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_id: EventId,
+    pub dry_run: bool,
+    pub include_attempts: bool,
+    pub idempotency_key: Option<String>,
+    pub max_event_age: Option<time::Duration>,
+    pub triggered_by: WebhookRetryTrigger,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The query parameters of the retry api call.
+#[derive(Debug, Default, Deserialize)]
+pub struct WebhookDeliveryRetryQuery {
+    /// Only resolve the event to retry, without creating a new delivery attempt.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include the timeline of all the delivery attempts of the event in the response.
+    #[serde(default)]
+    pub include_attempts: bool,
+
+    /// A key identifying the retry. Retries of the same event with the same key are accepted only
+    /// once within [`RETRY_IDEMPOTENCY_WINDOW_SECONDS`](super::RETRY_IDEMPOTENCY_WINDOW_SECONDS),
+    /// and subsequent retries are deduplicated without creating a new delivery attempt.
+    pub idempotency_key: Option<String>,
+
+    /// The maximum age of the event to retry, in seconds. Events older than this are not
+    /// retried. Defaults to [`DEFAULT_RETRY_MAX_EVENT_AGE`].
+    pub max_event_age_seconds: Option<u32>,
+}
+
+/// The errors encountered when checking whether an event is recent enough to be retried.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookRetryEventAgeError {
+    /// The event was created longer ago than the maximum age allowed for retries.
+    EventTooOld {
+        event_age: time::Duration,
+        max_event_age: time::Duration,
+    },
+}
+
+impl std::fmt::Display for WebhookRetryEventAgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EventTooOld {
+                event_age,
+                max_event_age,
+            } => write!(
+                f,
+                "The event was created {} seconds ago, which exceeds the maximum age of {} seconds \
+                 allowed for retries",
+                event_age.whole_seconds(),
+                max_event_age.whole_seconds()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WebhookRetryEventAgeError {}
+
+/// Checks that an event created at `created` is at most `max_event_age` old at `now`, so that
+/// retries do not deliver events the merchant has long moved on from. When `max_event_age` is not
+/// specified, [`DEFAULT_RETRY_MAX_EVENT_AGE`] is used.
+pub fn check_retry_event_age(
+    created: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+    max_event_age: Option<time::Duration>,
+) -> Result<(), WebhookRetryEventAgeError> {
+    let max_event_age = max_event_age.unwrap_or(DEFAULT_RETRY_MAX_EVENT_AGE);
+    let event_age = now - created;
+
+    if event_age > max_event_age {
+        return Err(WebhookRetryEventAgeError::EventTooOld {
+            event_age,
+            max_event_age,
+        });
+    }
+    Ok(())
+}
+
+/// The reasons an event cannot be retried.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookRetryEligibilityError {
+    /// The webhook was already delivered successfully.
+    AlreadyDelivered,
+
+    /// The event is older than the maximum age allowed for retries.
+    EventTooOld(WebhookRetryEventAgeError),
+
+    /// The event was already attempted
+    /// [`MAX_EVENT_DELIVERY_ATTEMPTS`](super::MAX_EVENT_DELIVERY_ATTEMPTS) times.
+    TooManyAttempts {
+        attempts: usize,
+        max_attempts: usize,
+    },
+
+    /// Webhooks are disabled for the class of the event.
+    EventClassDisabled { event_class: EventClass },
+
+    /// The request body of the event was not persisted.
+    BodyNotPersisted,
+}
+
+impl std::fmt::Display for WebhookRetryEligibilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyDelivered => write!(
+                f,
+                "The webhook was already delivered, and the event cannot be retried"
+            ),
+            Self::EventTooOld(error) => error.fmt(f),
+            Self::TooManyAttempts {
+                attempts,
+                max_attempts,
+            } => write!(
+                f,
+                "The event was already attempted {attempts} times, which reaches the maximum of \
+                 {max_attempts} delivery attempts allowed for an event"
+            ),
+            Self::EventClassDisabled { event_class } => write!(
+                f,
+                "Webhooks are disabled for the `{event_class}` event class, and the event cannot \
+                 be retried"
+            ),
+            Self::BodyNotPersisted => write!(
+                f,
+                "The request body of the event was not persisted, and the event cannot be retried"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WebhookRetryEligibilityError {}
+
+/// The response body of the retry api call when `dry_run` is set.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryRetryDryRunResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Indicates that no delivery attempts were created. Always `true`.
+    pub dry_run: bool,
+
+    /// The identifiers of the events that would have been retried.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Vec<String>,
+}
+
+/// The response body of the retry api call when the retry is deduplicated, since a retry with
+/// the same idempotency key was already accepted for the event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryRetryDeduplicatedResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Indicates that no delivery attempt was created. Always `true`.
+    pub deduplicated: bool,
+
+    /// The identifier for the event whose retry was deduplicated.
+    #[schema(value_type = String, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: EventId,
+}
+
+/// The response body of the retry api call.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum WebhookDeliveryRetryResponse {
+    /// The delivery attempt created for the retry.
+    Retried(Box<EventRetrieveResponse>),
+    /// The events resolved for the retry, when `dry_run` is set.
+    DryRun(WebhookDeliveryRetryDryRunResponse),
+    /// The event whose retry was deduplicated, when a retry with the same idempotency key was
+    /// already accepted.
+    Deduplicated(WebhookDeliveryRetryDeduplicatedResponse),
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        let merchant_id = match self {
+            Self::Retried(response) => response.event_information.merchant_id.clone(),
+            Self::DryRun(response) => response.merchant_id.clone(),
+            Self::Deduplicated(response) => response.merchant_id.clone(),
+        };
+
+        Some(common_utils::events::ApiEventsType::Events { merchant_id })
+    }
+}
+
+/// The request body for replaying an event to an alternate URL.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookReplayRequest {
+    /// The URL to replay the event to, instead of the webhook URL configured for the business
+    /// profile. The configured webhook URL is not changed. Must be an `https` URL.
+    #[schema(value_type = String, example = "https://merchant.example.com/webhooks/v2")]
+    pub override_url: Secret<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_id: EventId,
+    pub override_url: Secret<String>,
+}
+
+/// The errors encountered when validating [`WebhookReplayRequestInternal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookReplayRequestError {
+    /// `override_url` is not a valid absolute URL.
+    InvalidOverrideUrl,
+    /// `override_url` uses a scheme other than `https`.
+    InsecureOverrideUrl { scheme: String },
+}
+
+impl std::fmt::Display for WebhookReplayRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidOverrideUrl => write!(f, "`override_url` must be a valid URL"),
+            Self::InsecureOverrideUrl { .. } => {
+                write!(f, "`override_url` must be an `https` URL")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WebhookReplayRequestError {}
+
+impl WebhookReplayRequestInternal {
+    /// Validates that `override_url` is an absolute `https` URL, since the replayed webhook
+    /// carries the same payload and signature as the recorded event.
+    pub fn validate(&self) -> Result<(), WebhookReplayRequestError> {
+        let override_url = url::Url::parse(self.override_url.peek())
+            .map_err(|_| WebhookReplayRequestError::InvalidOverrideUrl)?;
+
+        if override_url.scheme() != "https" {
+            return Err(WebhookReplayRequestError::InsecureOverrideUrl {
+                scheme: override_url.scheme().to_owned(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookRetryScheduleRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_id: EventId,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookRetryScheduleRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body of the retry schedule api call, previewing the automatic retries of an event
+/// that are yet to occur. Retrieving the schedule does not trigger any retries.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookRetrySchedule {
+    /// The times at which the remaining automatic retries of the event are expected to occur, as
+    /// per the retry configuration of the merchant. This is empty for events that are no longer
+    /// being retried automatically, such as events that were delivered successfully or whose
+    /// retries are exhausted.
+    #[schema(value_type = Vec<String>, example = json!(["2022-09-10T10:16:12.000Z"]))]
+    #[serde(serialize_with = "serialize_retry_times")]
+    pub attempts: Vec<PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookRetrySchedule {}
+
+fn serialize_retry_times<S: serde::Serializer>(
+    retry_times: &[PrimitiveDateTime],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct RetryTime(#[serde(with = "common_utils::custom_serde::iso8601")] PrimitiveDateTime);
+
+    serializer.collect_seq(retry_times.iter().copied().map(RetryTime))
+}
+
+/// The request body for retrying the delivery of multiple events. Exactly one of `event_ids` and
+/// `constraints` must be specified.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkWebhookDeliveryRetryRequest {
+    /// The identifiers of the events to retry. At most 100 events can be retried at once.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Option<Vec<String>>,
+
+    /// The constraints used to select the initial delivery attempts to retry. At most 100 events
+    /// are selected.
+    pub constraints: Option<EventListConstraints>,
+
+    /// Only resolve the events to retry, without creating any new delivery attempts.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// The maximum age of the events to retry, in seconds. Events older than this are reported
+    /// as failed, without being retried. Defaults to 259200 seconds (3 days).
+    #[schema(example = 86400)]
+    pub max_event_age_seconds: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct BulkWebhookDeliveryRetryRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub event_ids: Option<Vec<String>>,
+    pub constraints: Option<EventListConstraints>,
+    pub dry_run: bool,
+    pub max_event_age: Option<time::Duration>,
+    pub triggered_by: WebhookRetryTrigger,
+}
+
+impl common_utils::events::ApiEventMetric for BulkWebhookDeliveryRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The status of the retry for an event in a bulk retry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryRetryStatus {
+    /// A new delivery attempt was created and the webhook is queued for delivery.
+    Queued,
+    /// A new delivery attempt could not be created for the event.
+    Failed,
+    /// The event was found, but no delivery attempt was created since `dry_run` was set.
+    Resolved,
+}
+
+/// The result of the retry for an event in a bulk retry.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkWebhookDeliveryRetryResult {
+    /// The identifier of the event that was retried.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The status of the retry for the event.
+    pub status: WebhookDeliveryRetryStatus,
+
+    /// The identifier of the delivery attempt created for the retry.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab960")]
+    pub retry_event_id: Option<String>,
+
+    /// The reason the retry failed, if it failed.
+    pub error_message: Option<String>,
+}
+
+/// The response body of the bulk retry api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkWebhookDeliveryRetryResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Whether the events were only resolved, without creating any new delivery attempts.
+    pub dry_run: bool,
+
+    /// The result of the retry for each of the selected events.
+    pub results: Vec<BulkWebhookDeliveryRetryResult>,
+}
+
+impl common_utils::events::ApiEventMetric for BulkWebhookDeliveryRetryResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::webhook_events::test_utils::{date_time, to_event_id};
+
+    #[test]
+    fn test_webhook_retry_schedule_serialization() {
+        let retry_schedule = WebhookRetrySchedule {
+            attempts: vec![date_time(1), date_time(2)],
+        };
+        assert_eq!(
+            serde_json::to_value(retry_schedule).unwrap(),
+            serde_json::json!({
+                "attempts": ["2024-01-01T00:00:00.000Z", "2024-01-02T00:00:00.000Z"]
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(WebhookRetrySchedule::default()).unwrap(),
+            serde_json::json!({ "attempts": [] })
+        );
+    }
+
+    #[test]
+    fn test_bulk_retry_request_dry_run_defaults_to_false() {
+        let request: BulkWebhookDeliveryRetryRequest =
+            serde_json::from_value(serde_json::json!({ "event_ids": ["evt_1"] })).unwrap();
+        assert!(!request.dry_run);
+
+        let request: BulkWebhookDeliveryRetryRequest =
+            serde_json::from_value(serde_json::json!({ "event_ids": ["evt_1"], "dry_run": true }))
+                .unwrap();
+        assert!(request.dry_run);
+    }
+
+    #[test]
+    fn test_retry_event_age_within_limit() {
+        let now = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap(),
+            time::Time::from_hms(12, 0, 0).unwrap(),
+        );
+
+        assert_eq!(
+            check_retry_event_age(now - time::Duration::days(2), now, None),
+            Ok(())
+        );
+        assert_eq!(
+            check_retry_event_age(
+                now - time::Duration::hours(1),
+                now,
+                Some(time::Duration::hours(1))
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_retry_event_age_beyond_limit() {
+        let now = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap(),
+            time::Time::from_hms(12, 0, 0).unwrap(),
+        );
+
+        assert_eq!(
+            check_retry_event_age(now - time::Duration::days(7), now, None),
+            Err(WebhookRetryEventAgeError::EventTooOld {
+                event_age: time::Duration::days(7),
+                max_event_age: DEFAULT_RETRY_MAX_EVENT_AGE,
+            })
+        );
+        assert_eq!(
+            check_retry_event_age(
+                now - time::Duration::minutes(61),
+                now,
+                Some(time::Duration::hours(1))
+            ),
+            Err(WebhookRetryEventAgeError::EventTooOld {
+                event_age: time::Duration::minutes(61),
+                max_event_age: time::Duration::hours(1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_dry_run_retry_response_serialization() {
+        let response = WebhookDeliveryRetryResponse::DryRun(WebhookDeliveryRetryDryRunResponse {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            dry_run: true,
+            event_ids: vec!["evt_1".to_string()],
+        });
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({ "dry_run": true, "event_ids": ["evt_1"] })
+        );
+    }
+
+    #[test]
+    fn test_deduplicated_retry_response_serialization() {
+        let response =
+            WebhookDeliveryRetryResponse::Deduplicated(WebhookDeliveryRetryDeduplicatedResponse {
+                merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                    "merchant_1",
+                ))
+                .unwrap(),
+                deduplicated: true,
+                event_id: to_event_id("evt_1"),
+            });
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({ "deduplicated": true, "event_id": "evt_1" })
+        );
+
+        let query: WebhookDeliveryRetryQuery =
+            serde_urlencoded::from_str("idempotency_key=retry_1").unwrap();
+        assert_eq!(query.idempotency_key.as_deref(), Some("retry_1"));
+        assert_eq!(
+            serde_urlencoded::from_str::<WebhookDeliveryRetryQuery>("")
+                .unwrap()
+                .idempotency_key,
+            None
+        );
+    }
+
+    fn replay_request(override_url: &str) -> WebhookReplayRequestInternal {
+        WebhookReplayRequestInternal {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            event_id: to_event_id("evt_018e31720d1b7a2b82677d3032cab959"),
+            override_url: Secret::new(override_url.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_replay_request_accepts_https_override_url() {
+        assert_eq!(
+            replay_request("https://merchant.example.com/webhooks/v2").validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_replay_request_rejects_insecure_override_url() {
+        let error = replay_request("http://merchant.example.com/webhooks/v2")
+            .validate()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            WebhookReplayRequestError::InsecureOverrideUrl {
+                scheme: "http".to_string()
+            }
+        );
+        assert_eq!(error.to_string(), "`override_url` must be an `https` URL");
+    }
+
+    #[test]
+    fn test_replay_request_rejects_invalid_override_url() {
+        for override_url in ["", "merchant.example.com/webhooks", "https://"] {
+            assert_eq!(
+                replay_request(override_url).validate(),
+                Err(WebhookReplayRequestError::InvalidOverrideUrl),
+                "{override_url}"
+            );
+        }
+    }
+}
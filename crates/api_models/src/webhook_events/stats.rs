@@ -0,0 +1,607 @@
+//! The delivery stats of events and the health of webhook endpoints.
+
+use std::collections::HashMap;
+
+use common_enums::{EventClass, EventStatsGroupBy, EventType};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use super::{EventListConstraints, MASKED_VALUE_PLACEHOLDER};
+
+/// The request body of the event delivery stats api call.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventDeliveryStatsRequest {
+    /// The constraints to apply when selecting the events. Only `created_after`, `created_before`,
+    /// `profile_id`, `event_classes` and `event_types` are applied.
+    #[serde(flatten)]
+    pub constraints: EventListConstraints,
+
+    /// The attribute by which the delivery stats are grouped. Defaults to `event_type`.
+    #[serde(default)]
+    pub group_by: EventStatsGroupBy,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventDeliveryStatsRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventListConstraints,
+    pub group_by: EventStatsGroupBy,
+}
+
+impl common_utils::events::ApiEventMetric for EventDeliveryStatsRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The key of a group of events in the delivery stats, tagged by the attribute the events are
+/// grouped by.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, ToSchema)]
+#[serde(tag = "group_by", rename_all = "snake_case")]
+pub enum EventStatsGroupKey {
+    EventType {
+        /// The type of the events in the group.
+        event_type: EventType,
+    },
+    EventClass {
+        /// The class of the events in the group.
+        event_class: EventClass,
+    },
+    Profile {
+        /// The identifier for the Business Profile of the events in the group.
+        #[schema(value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+        profile_id: common_utils::id_type::ProfileId,
+    },
+    Day {
+        /// The start of the UTC calendar day the events in the group were created on.
+        #[schema(example = "2022-09-10T00:00:00.000Z")]
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        day: PrimitiveDateTime,
+    },
+}
+
+impl EventStatsGroupKey {
+    /// Returns the key of the UTC calendar day containing the specified creation time.
+    pub fn day(created: PrimitiveDateTime) -> Self {
+        Self::Day {
+            day: PrimitiveDateTime::new(created.date(), time::Time::MIDNIGHT),
+        }
+    }
+
+    fn sort_key(&self) -> String {
+        match self {
+            Self::EventType { event_type } => event_type.to_string(),
+            Self::EventClass { event_class } => event_class.to_string(),
+            Self::Profile { profile_id } => profile_id.get_string_repr().to_owned(),
+            Self::Day { day } => day.date().to_string(),
+        }
+    }
+}
+
+/// The delivery counts of the initial delivery attempts in a group of events.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EventDeliveryStat {
+    /// The key of the group of events.
+    #[serde(flatten)]
+    pub group: EventStatsGroupKey,
+
+    /// Count of events that were delivered successfully.
+    pub succeeded: i64,
+
+    /// Count of events whose delivery attempts failed.
+    pub failed: i64,
+
+    /// Count of events whose delivery is yet to be attempted.
+    pub pending: i64,
+}
+
+/// The response body of the event delivery stats api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventDeliveryStatsResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The delivery counts of each group of events, ordered by the group key.
+    pub stats: Vec<EventDeliveryStat>,
+}
+
+impl EventDeliveryStatsResponse {
+    /// Rolls up the delivery counts of the events, which may be counted separately for each
+    /// delivery status of the events in a group, into the delivery counts of each group of events.
+    pub fn from_delivery_stats(
+        merchant_id: common_utils::id_type::MerchantId,
+        delivery_stats: impl IntoIterator<Item = EventDeliveryStat>,
+    ) -> Self {
+        let mut stats_by_group = HashMap::<EventStatsGroupKey, EventDeliveryStat>::new();
+
+        for delivery_stat in delivery_stats {
+            stats_by_group
+                .entry(delivery_stat.group.clone())
+                .and_modify(|stat| {
+                    stat.succeeded += delivery_stat.succeeded;
+                    stat.failed += delivery_stat.failed;
+                    stat.pending += delivery_stat.pending;
+                })
+                .or_insert(delivery_stat);
+        }
+
+        let mut stats = stats_by_group.into_values().collect::<Vec<_>>();
+        stats.sort_by_cached_key(|stat| stat.group.sort_key());
+
+        Self { merchant_id, stats }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for EventDeliveryStatsResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEndpointHealthRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub constraints: EventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointHealthRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// A delivery attempt of a webhook to an endpoint, used to compute the health of the endpoint.
+#[derive(Clone, Debug)]
+pub struct EndpointDeliveryAttempt {
+    /// The URL of the endpoint the webhook was delivered to.
+    pub delivery_url: Secret<String>,
+    /// Indicates whether the delivery attempt was successful.
+    pub is_delivered: bool,
+    /// The time taken to receive a response for the delivery attempt, if any.
+    pub response_latency_ms: Option<u32>,
+    /// Time at which the delivery attempt was created.
+    pub created: PrimitiveDateTime,
+}
+
+/// The delivery health of an endpoint that webhooks are delivered to.
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct EndpointHealth {
+    /// The URL of the endpoint, with its path and query masked.
+    #[schema(example = "https://merchant.example.com/***")]
+    pub delivery_url: String,
+
+    /// The number of completed delivery attempts made to the endpoint. Attempts which are yet to
+    /// complete are not included.
+    #[schema(example = 120)]
+    pub attempts: usize,
+
+    /// The fraction of the delivery attempts to the endpoint that were successful, ranging from 0
+    /// to 1.
+    #[schema(example = 0.975)]
+    pub success_rate: f32,
+
+    /// The 95th percentile of the time taken by the endpoint to respond, in milliseconds. This is
+    /// unavailable when no latencies were recorded for the endpoint.
+    #[schema(example = 850)]
+    pub p95_latency_ms: Option<u32>,
+
+    /// Time at which a webhook was last delivered to the endpoint successfully.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_success_at: Option<PrimitiveDateTime>,
+}
+
+/// The response body of the endpoint health api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointHealthResponse {
+    /// The identifier for the Merchant Account.
+    #[serde(skip)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The delivery health of each endpoint, ordered from the least to the most healthy.
+    pub endpoints: Vec<EndpointHealth>,
+}
+
+impl WebhookEndpointHealthResponse {
+    /// Rolls up the delivery attempts into the delivery health of each endpoint, grouping the
+    /// attempts by their delivery URL.
+    pub fn from_delivery_attempts(
+        merchant_id: common_utils::id_type::MerchantId,
+        delivery_attempts: impl IntoIterator<Item = EndpointDeliveryAttempt>,
+    ) -> Self {
+        let mut attempts_by_url = HashMap::<String, Vec<EndpointDeliveryAttempt>>::new();
+        for delivery_attempt in delivery_attempts {
+            attempts_by_url
+                .entry(delivery_attempt.delivery_url.peek().to_owned())
+                .or_default()
+                .push(delivery_attempt);
+        }
+
+        let mut endpoints = attempts_by_url
+            .into_iter()
+            .map(|(delivery_url, delivery_attempts)| {
+                let delivered = delivery_attempts
+                    .iter()
+                    .filter(|delivery_attempt| delivery_attempt.is_delivered)
+                    .count();
+                let mut latencies_ms = delivery_attempts
+                    .iter()
+                    .filter_map(|delivery_attempt| delivery_attempt.response_latency_ms)
+                    .collect::<Vec<_>>();
+                latencies_ms.sort_unstable();
+                // Nearest-rank percentile: the smallest latency at or above 95% of the latencies
+                let p95_latency_ms = (latencies_ms.len() * 95)
+                    .div_ceil(100)
+                    .checked_sub(1)
+                    .and_then(|index| latencies_ms.get(index))
+                    .copied();
+
+                #[allow(clippy::as_conversions)]
+                let success_rate = delivered as f32 / delivery_attempts.len() as f32;
+
+                (
+                    delivery_url.clone(),
+                    EndpointHealth {
+                        delivery_url: mask_delivery_url(&delivery_url),
+                        attempts: delivery_attempts.len(),
+                        success_rate,
+                        p95_latency_ms,
+                        last_success_at: delivery_attempts
+                            .iter()
+                            .filter(|delivery_attempt| delivery_attempt.is_delivered)
+                            .map(|delivery_attempt| delivery_attempt.created)
+                            .max(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        endpoints.sort_by(|(url, endpoint), (other_url, other_endpoint)| {
+            endpoint
+                .success_rate
+                .total_cmp(&other_endpoint.success_rate)
+                .then_with(|| url.cmp(other_url))
+        });
+
+        Self {
+            merchant_id,
+            endpoints: endpoints
+                .into_iter()
+                .map(|(_, endpoint)| endpoint)
+                .collect(),
+        }
+    }
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointHealthResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// Masks the path and query of a delivery URL, retaining only its scheme, host and port, since
+/// they may carry secrets such as access tokens.
+fn mask_delivery_url(delivery_url: &str) -> String {
+    match url::Url::parse(delivery_url) {
+        Ok(url) if url.has_host() => {
+            let origin = url.origin().ascii_serialization();
+            if url.path() == "/" && url.query().is_none() {
+                origin
+            } else {
+                format!("{origin}/{MASKED_VALUE_PLACEHOLDER}")
+            }
+        }
+        _ => MASKED_VALUE_PLACEHOLDER.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::WebhookDeliveryState;
+
+    use super::*;
+
+    fn event_type_group(event_type: EventType) -> EventStatsGroupKey {
+        EventStatsGroupKey::EventType { event_type }
+    }
+
+    /// Returns the delivery counts of a single event in the specified group.
+    fn single_event_stat(
+        group: EventStatsGroupKey,
+        delivery_state: WebhookDeliveryState,
+    ) -> EventDeliveryStat {
+        EventDeliveryStat {
+            group,
+            succeeded: i64::from(delivery_state == WebhookDeliveryState::Succeeded),
+            failed: i64::from(delivery_state == WebhookDeliveryState::Failed),
+            pending: i64::from(delivery_state == WebhookDeliveryState::Pending),
+        }
+    }
+
+    #[test]
+    fn test_delivery_stats_rollup() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let stats = EventDeliveryStatsResponse::from_delivery_stats(
+            merchant_id,
+            [
+                (EventType::PaymentSucceeded, WebhookDeliveryState::Succeeded),
+                (EventType::RefundFailed, WebhookDeliveryState::Pending),
+                (EventType::PaymentSucceeded, WebhookDeliveryState::Failed),
+                (EventType::PaymentSucceeded, WebhookDeliveryState::Succeeded),
+                (EventType::PaymentFailed, WebhookDeliveryState::Failed),
+                (EventType::RefundFailed, WebhookDeliveryState::Succeeded),
+                (EventType::PaymentSucceeded, WebhookDeliveryState::Pending),
+            ]
+            .map(|(event_type, delivery_state)| {
+                single_event_stat(event_type_group(event_type), delivery_state)
+            }),
+        )
+        .stats;
+
+        assert_eq!(
+            stats,
+            vec![
+                EventDeliveryStat {
+                    group: event_type_group(EventType::PaymentFailed),
+                    succeeded: 0,
+                    failed: 1,
+                    pending: 0,
+                },
+                EventDeliveryStat {
+                    group: event_type_group(EventType::PaymentSucceeded),
+                    succeeded: 2,
+                    failed: 1,
+                    pending: 1,
+                },
+                EventDeliveryStat {
+                    group: event_type_group(EventType::RefundFailed),
+                    succeeded: 1,
+                    failed: 0,
+                    pending: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delivery_stats_rollup_without_events() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+
+        assert!(
+            EventDeliveryStatsResponse::from_delivery_stats(merchant_id, [])
+                .stats
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_endpoint_health_rollup() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let created = |minute: u8| {
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap(),
+                time::Time::from_hms(12, minute, 0).unwrap(),
+            )
+        };
+        let attempt = |delivery_url: &str, is_delivered: bool, latency_ms: u32, minute: u8| {
+            EndpointDeliveryAttempt {
+                delivery_url: Secret::new(delivery_url.to_string()),
+                is_delivered,
+                response_latency_ms: Some(latency_ms),
+                created: created(minute),
+            }
+        };
+
+        // The healthy endpoint succeeds on every attempt, while the flaky endpoint only succeeds
+        // on one in four attempts, and responds slowly
+        let mut delivery_attempts = (0..20)
+            .map(|minute| {
+                attempt(
+                    "https://healthy.example.com/webhooks?token=secret",
+                    true,
+                    100 + u32::from(minute),
+                    minute,
+                )
+            })
+            .collect::<Vec<_>>();
+        delivery_attempts.extend([
+            attempt("https://flaky.example.com/", false, 30_000, 1),
+            attempt("https://flaky.example.com/", true, 2_000, 2),
+            attempt("https://flaky.example.com/", false, 30_000, 3),
+            EndpointDeliveryAttempt {
+                response_latency_ms: None,
+                ..attempt("https://flaky.example.com/", false, 0, 4)
+            },
+        ]);
+
+        let response =
+            WebhookEndpointHealthResponse::from_delivery_attempts(merchant_id, delivery_attempts);
+
+        assert_eq!(
+            response.endpoints,
+            vec![
+                EndpointHealth {
+                    delivery_url: "https://flaky.example.com".to_string(),
+                    attempts: 4,
+                    success_rate: 0.25,
+                    p95_latency_ms: Some(30_000),
+                    last_success_at: Some(created(2)),
+                },
+                EndpointHealth {
+                    delivery_url: "https://healthy.example.com/***".to_string(),
+                    attempts: 20,
+                    success_rate: 1.0,
+                    p95_latency_ms: Some(118),
+                    last_success_at: Some(created(19)),
+                },
+            ]
+        );
+        assert!(WebhookEndpointHealthResponse::from_delivery_attempts(
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap(),
+            []
+        )
+        .endpoints
+        .is_empty());
+    }
+
+    #[test]
+    fn test_delivery_stats_rollup_by_day_across_day_boundary() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let created = |day: u8, time: time::Time| {
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+                time,
+            )
+        };
+
+        let stats = EventDeliveryStatsResponse::from_delivery_stats(
+            merchant_id,
+            [
+                (
+                    created(2, time::Time::from_hms(0, 0, 0).unwrap()),
+                    WebhookDeliveryState::Failed,
+                ),
+                (
+                    created(1, time::Time::from_hms_milli(23, 59, 59, 999).unwrap()),
+                    WebhookDeliveryState::Succeeded,
+                ),
+                (
+                    created(1, time::Time::from_hms(0, 0, 0).unwrap()),
+                    WebhookDeliveryState::Pending,
+                ),
+                (
+                    created(2, time::Time::from_hms(0, 0, 1).unwrap()),
+                    WebhookDeliveryState::Succeeded,
+                ),
+            ]
+            .map(|(created, delivery_state)| {
+                single_event_stat(EventStatsGroupKey::day(created), delivery_state)
+            }),
+        )
+        .stats;
+
+        assert_eq!(
+            stats,
+            vec![
+                EventDeliveryStat {
+                    group: EventStatsGroupKey::Day {
+                        day: created(1, time::Time::MIDNIGHT),
+                    },
+                    succeeded: 1,
+                    failed: 0,
+                    pending: 1,
+                },
+                EventDeliveryStat {
+                    group: EventStatsGroupKey::Day {
+                        day: created(2, time::Time::MIDNIGHT),
+                    },
+                    succeeded: 1,
+                    failed: 1,
+                    pending: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delivery_stat_serde_tagging() {
+        let stat = |group| EventDeliveryStat {
+            group,
+            succeeded: 3,
+            failed: 2,
+            pending: 1,
+        };
+
+        assert_eq!(
+            serde_json::to_value(stat(event_type_group(EventType::PaymentSucceeded))).unwrap(),
+            serde_json::json!({
+                "group_by": "event_type",
+                "event_type": "payment_succeeded",
+                "succeeded": 3,
+                "failed": 2,
+                "pending": 1,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(stat(EventStatsGroupKey::EventClass {
+                event_class: EventClass::Refunds,
+            }))
+            .unwrap(),
+            serde_json::json!({
+                "group_by": "event_class",
+                "event_class": "refunds",
+                "succeeded": 3,
+                "failed": 2,
+                "pending": 1,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(stat(EventStatsGroupKey::Profile {
+                profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                    "pro_1",
+                ))
+                .unwrap(),
+            }))
+            .unwrap(),
+            serde_json::json!({
+                "group_by": "profile",
+                "profile_id": "pro_1",
+                "succeeded": 3,
+                "failed": 2,
+                "pending": 1,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(stat(EventStatsGroupKey::day(PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(10, 11, 12).unwrap(),
+            ))))
+            .unwrap(),
+            serde_json::json!({
+                "group_by": "day",
+                "day": "2024-01-01T00:00:00.000Z",
+                "succeeded": 3,
+                "failed": 2,
+                "pending": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_delivery_stats_request_group_by() {
+        let request = serde_json::from_str::<EventDeliveryStatsRequest>(
+            r#"{"created_after":"2024-01-01T00:00:00Z","group_by":"profile"}"#,
+        )
+        .unwrap();
+        assert_eq!(request.group_by, EventStatsGroupBy::Profile);
+        assert!(request.constraints.created_after.is_some());
+
+        let request = serde_json::from_str::<EventDeliveryStatsRequest>("{}").unwrap();
+        assert_eq!(request.group_by, EventStatsGroupBy::EventType);
+        assert_eq!(
+            serde_json::to_string(&EventStatsGroupBy::EventClass).unwrap(),
+            r#""event_class""#
+        );
+    }
+}
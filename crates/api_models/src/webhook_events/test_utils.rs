@@ -0,0 +1,114 @@
+//! Fixtures shared by the tests of the webhook event types.
+#![allow(clippy::unwrap_used)]
+
+use common_enums::{EventClass, EventType, WebhookDeliveryAttempt, WebhookOutcome};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use super::{
+    EventId, EventListItemResponse, EventRetrieveResponse, EventRetrySchedule,
+    OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+};
+
+pub(super) fn to_event_id(event_id: &str) -> EventId {
+    EventId::try_from(event_id).unwrap()
+}
+
+pub(super) fn date_time(day: u8) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(
+        time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+        time::Time::MIDNIGHT,
+    )
+}
+
+pub(super) fn merchant_id() -> common_utils::id_type::MerchantId {
+    common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap()
+}
+
+/// Returns a payment event delivered successfully in its initial delivery attempt.
+pub(super) fn list_item() -> EventListItemResponse {
+    EventListItemResponse {
+        event_id: to_event_id("evt_018e31720d1b7a2b82677d3032cab959"),
+        merchant_id: merchant_id(),
+        profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1"))
+            .unwrap(),
+        object_id: "pay_1".to_string(),
+        event_type: EventType::PaymentSucceeded,
+        event_class: EventClass::Payments,
+        class_enabled: true,
+        is_delivery_successful: Some(true),
+        outcome: WebhookOutcome::Delivered,
+        merchant_connector_id: None,
+        connector: Some("stripe".to_string()),
+        initial_attempt_id: to_event_id("evt_018e31720d1b7a2b82677d3032cab959"),
+        delivery_attempt: Some(WebhookDeliveryAttempt::InitialAttempt),
+        created: date_time(1),
+        ingested_at: None,
+        event_delay_ms: None,
+        delivery_url: None,
+        stored_bytes: None,
+        expires_at: None,
+        sla_breached: None,
+        amount: None,
+        currency: None,
+        tags: None,
+    }
+}
+
+pub(super) fn request_with_headers(headers: Vec<(&str, &str)>) -> OutgoingWebhookRequestContent {
+    OutgoingWebhookRequestContent {
+        body: Secret::new("{}".to_string()),
+        headers: headers
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), Secret::new(value.to_string())))
+            .collect(),
+        delivery_url: None,
+        http_method: None,
+        is_truncated: false,
+        idempotency_key: None,
+        content_encoding: None,
+        redacted_header_count: 0,
+        payload_version: None,
+        body_omitted: false,
+        body_redacted: false,
+        signature_algorithm: None,
+    }
+}
+
+pub(super) fn response_with_status_code(
+    status_code: Option<u16>,
+) -> OutgoingWebhookResponseContent {
+    OutgoingWebhookResponseContent {
+        body: None,
+        body_size_bytes: None,
+        headers: None,
+        status_code,
+        error_message: None,
+        response_latency_ms: None,
+        error_category: None,
+        is_truncated: false,
+        redacted_header_count: 0,
+    }
+}
+
+/// Returns a failed delivery attempt of the event created on the specified day.
+pub(super) fn delivery_attempt(event_id: &str, day: u8) -> EventRetrieveResponse {
+    EventRetrieveResponse {
+        event_information: EventListItemResponse {
+            event_id: to_event_id(event_id),
+            created: date_time(day),
+            ..list_item()
+        },
+        request: request_with_headers(Vec::new()),
+        response: response_with_status_code(Some(500)),
+        idempotency_key: None,
+        payload_version: None,
+        request_body_sha256: None,
+        triggered_by: None,
+        body_persisted: true,
+        attempt_number: None,
+        time_to_first_success_ms: None,
+        attempts: None,
+        retry_schedule: EventRetrySchedule::default(),
+    }
+}
@@ -1396,6 +1396,7 @@ impl Currency {
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
@@ -1456,6 +1457,8 @@ impl EventClass {
     Hash,
     Eq,
     PartialEq,
+    Ord,
+    PartialOrd,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
@@ -1466,6 +1469,7 @@ impl EventClass {
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 // Reminder: Whenever an EventType variant is added or removed, make sure to update the `event_types` method in `EventClass`
+// and the `event_class` method in `EventType`
 pub enum EventType {
     /// Authorize + Capture success
     PaymentSucceeded,
@@ -1503,11 +1507,51 @@ pub enum EventType {
     PayoutReversed,
 }
 
+impl EventType {
+    /// Returns the class the event type belongs to.
+    #[inline]
+    pub fn event_class(self) -> EventClass {
+        match self {
+            Self::PaymentSucceeded
+            | Self::PaymentFailed
+            | Self::PaymentProcessing
+            | Self::PaymentCancelled
+            | Self::PaymentAuthorized
+            | Self::PaymentCaptured
+            | Self::ActionRequired => EventClass::Payments,
+            Self::RefundSucceeded | Self::RefundFailed => EventClass::Refunds,
+            Self::DisputeOpened
+            | Self::DisputeExpired
+            | Self::DisputeAccepted
+            | Self::DisputeCancelled
+            | Self::DisputeChallenged
+            | Self::DisputeWon
+            | Self::DisputeLost => EventClass::Disputes,
+            Self::MandateActive | Self::MandateRevoked => EventClass::Mandates,
+            #[cfg(feature = "payouts")]
+            Self::PayoutSuccess
+            | Self::PayoutFailed
+            | Self::PayoutInitiated
+            | Self::PayoutProcessing
+            | Self::PayoutCancelled
+            | Self::PayoutExpired
+            | Self::PayoutReversed => EventClass::Payouts,
+        }
+    }
+
+    /// Checks whether the event type belongs to the specified class.
+    #[inline]
+    pub fn belongs_to(self, event_class: EventClass) -> bool {
+        self.event_class() == event_class
+    }
+}
+
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
@@ -1522,6 +1566,327 @@ pub enum WebhookDeliveryAttempt {
     InitialAttempt,
     AutomaticRetry,
     ManualRetry,
+    ManualReplay,
+}
+
+/// The source that triggered a retry or replay of a webhook delivery attempt.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookRetryTrigger {
+    /// The retry was scheduled automatically after a failed delivery attempt.
+    Scheduler,
+    /// The retry was triggered by an operator from the dashboard.
+    ManualDashboard,
+    /// The retry was triggered using the API.
+    Api,
+    /// The delivery attempt is a replay of a previous delivery attempt to another URL.
+    Replay,
+}
+
+/// The order in which events are sorted when listing events.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventSortBy {
+    /// Sort events by their creation time, oldest first.
+    CreatedAsc,
+    /// Sort events by their creation time, most recent first.
+    #[default]
+    CreatedDesc,
+}
+
+/// The shape of each event included in the response when listing events.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventResponseMode {
+    /// Include all the details of each event.
+    #[default]
+    Full,
+    /// Include only the identifier, type, object, creation time and delivery status of each
+    /// event, for listing a large number of events.
+    Compact,
+}
+
+/// The algorithm used to sign outgoing webhooks.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookSignatureAlgorithm {
+    /// HMAC using SHA-256, keyed with a shared secret.
+    HmacSha256,
+    /// HMAC using SHA-512, keyed with a shared secret.
+    HmacSha512,
+    /// Ed25519 signatures, verified using the public key of the signer.
+    Ed25519,
+}
+
+/// How the total number of matching events is counted when listing events.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CountMode {
+    /// Count all the matching events.
+    #[default]
+    Exact,
+    /// Count the matching events within a recent part of the time window, and extrapolate the
+    /// count to the entire time window.
+    Estimated,
+    /// Skip counting the matching events.
+    None,
+}
+
+/// The state of the delivery of a webhook.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryState {
+    /// The webhook has not been delivered yet, and no response has been recorded for it.
+    Pending,
+    /// The webhook was delivered successfully.
+    Succeeded,
+    /// The webhook could not be delivered.
+    Failed,
+}
+
+/// The overall outcome of the delivery of a webhook, accounting for the retries of the webhook.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookOutcome {
+    /// The webhook has not been delivered yet, and may still be delivered by an upcoming
+    /// automatic retry.
+    Pending,
+    /// The webhook was delivered successfully.
+    Delivered,
+    /// The webhook could not be delivered by a manual retry or replay, which are not retried
+    /// automatically.
+    Failed,
+    /// The webhook could not be delivered, and all of its automatic retries are exhausted.
+    Abandoned,
+}
+
+/// The class of failure encountered when delivering a webhook.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryErrorCategory {
+    /// No response was received from the merchant server within the timeout.
+    Timeout,
+    /// The merchant server refused the connection.
+    ConnectionRefused,
+    /// The host of the webhook URL could not be resolved.
+    DnsResolution,
+    /// The TLS handshake with the merchant server failed.
+    TlsError,
+    /// The merchant server responded with a non-2xx HTTP status code.
+    #[serde(rename = "non_2xx_status")]
+    #[strum(serialize = "non_2xx_status")]
+    Non2xxStatus,
+    /// Any other failure encountered when delivering the webhook.
+    Other,
+}
+
+/// The attribute by which the delivery stats of events are grouped.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventStatsGroupBy {
+    /// Group events by their type.
+    #[default]
+    EventType,
+    /// Group events by their class.
+    EventClass,
+    /// Group events by the business profile they are associated with.
+    Profile,
+    /// Group events by the UTC calendar day they were created on.
+    Day,
+}
+
+/// A field of the listed events that can be selected when exporting events.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventField {
+    EventId,
+    MerchantId,
+    ProfileId,
+    ObjectId,
+    EventType,
+    EventClass,
+    ClassEnabled,
+    IsDeliverySuccessful,
+    Outcome,
+    MerchantConnectorId,
+    Connector,
+    InitialAttemptId,
+    DeliveryAttempt,
+    Created,
+    IngestedAt,
+    EventDelayMs,
+    DeliveryUrl,
+    StoredBytes,
+    ExpiresAt,
+    SlaBreached,
+    Amount,
+    Currency,
+    Tags,
+}
+
+/// A preset time range to list events for, ending at the current time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+pub enum EventTimeRangePreset {
+    /// Events created in the last hour.
+    #[serde(rename = "last_1_hour")]
+    #[strum(serialize = "last_1_hour")]
+    Last1Hour,
+    /// Events created in the last 24 hours.
+    #[serde(rename = "last_24_hours")]
+    #[strum(serialize = "last_24_hours")]
+    Last24Hours,
+    /// Events created in the last 7 days.
+    #[serde(rename = "last_7_days")]
+    #[strum(serialize = "last_7_days")]
+    Last7Days,
+    /// Events created in the last 30 days.
+    #[serde(rename = "last_30_days")]
+    #[strum(serialize = "last_30_days")]
+    Last30Days,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -8167,3 +8532,128 @@ pub enum CryptoPadding {
     PKCS7,
     ZeroPadding,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_event_types() -> Vec<EventType> {
+        #[allow(unused_mut)]
+        let mut event_types = vec![
+            EventType::PaymentSucceeded,
+            EventType::PaymentFailed,
+            EventType::PaymentProcessing,
+            EventType::PaymentCancelled,
+            EventType::PaymentAuthorized,
+            EventType::PaymentCaptured,
+            EventType::ActionRequired,
+            EventType::RefundSucceeded,
+            EventType::RefundFailed,
+            EventType::DisputeOpened,
+            EventType::DisputeExpired,
+            EventType::DisputeAccepted,
+            EventType::DisputeCancelled,
+            EventType::DisputeChallenged,
+            EventType::DisputeWon,
+            EventType::DisputeLost,
+            EventType::MandateActive,
+            EventType::MandateRevoked,
+        ];
+        #[cfg(feature = "payouts")]
+        event_types.extend([
+            EventType::PayoutSuccess,
+            EventType::PayoutFailed,
+            EventType::PayoutInitiated,
+            EventType::PayoutProcessing,
+            EventType::PayoutCancelled,
+            EventType::PayoutExpired,
+            EventType::PayoutReversed,
+        ]);
+
+        // This match is exhaustive so that adding an `EventType` variant fails to compile until
+        // the variant is listed above as well.
+        for event_type in &event_types {
+            match event_type {
+                EventType::PaymentSucceeded
+                | EventType::PaymentFailed
+                | EventType::PaymentProcessing
+                | EventType::PaymentCancelled
+                | EventType::PaymentAuthorized
+                | EventType::PaymentCaptured
+                | EventType::ActionRequired
+                | EventType::RefundSucceeded
+                | EventType::RefundFailed
+                | EventType::DisputeOpened
+                | EventType::DisputeExpired
+                | EventType::DisputeAccepted
+                | EventType::DisputeCancelled
+                | EventType::DisputeChallenged
+                | EventType::DisputeWon
+                | EventType::DisputeLost
+                | EventType::MandateActive
+                | EventType::MandateRevoked => {}
+                #[cfg(feature = "payouts")]
+                EventType::PayoutSuccess
+                | EventType::PayoutFailed
+                | EventType::PayoutInitiated
+                | EventType::PayoutProcessing
+                | EventType::PayoutCancelled
+                | EventType::PayoutExpired
+                | EventType::PayoutReversed => {}
+            }
+        }
+
+        event_types
+    }
+
+    fn all_event_classes() -> Vec<EventClass> {
+        #[allow(unused_mut)]
+        let mut event_classes = vec![
+            EventClass::Payments,
+            EventClass::Refunds,
+            EventClass::Disputes,
+            EventClass::Mandates,
+        ];
+        #[cfg(feature = "payouts")]
+        event_classes.push(EventClass::Payouts);
+
+        event_classes
+    }
+
+    #[test]
+    fn test_event_class_of_every_event_type_lists_the_event_type() {
+        for event_type in all_event_types() {
+            assert!(
+                event_type.event_class().event_types().contains(&event_type),
+                "`{event_type}` is not listed in the event types of `{}`",
+                event_type.event_class()
+            );
+        }
+    }
+
+    #[test]
+    fn test_event_types_of_every_event_class_map_back_to_the_event_class() {
+        let mut listed_event_types = HashSet::new();
+
+        for event_class in all_event_classes() {
+            for event_type in event_class.event_types() {
+                assert_eq!(event_type.event_class(), event_class);
+                assert!(listed_event_types.insert(event_type));
+            }
+        }
+
+        assert_eq!(listed_event_types.len(), all_event_types().len());
+    }
+
+    #[test]
+    fn test_event_type_belongs_to_only_its_event_class() {
+        for event_type in all_event_types() {
+            let matching_event_classes = all_event_classes()
+                .into_iter()
+                .filter(|event_class| event_type.belongs_to(*event_class))
+                .collect::<Vec<_>>();
+
+            assert_eq!(matching_event_classes, vec![event_type.event_class()]);
+        }
+    }
+}
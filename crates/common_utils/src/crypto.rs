@@ -6,7 +6,7 @@ use masking::{ExposeInterface, Secret};
 use md5;
 use ring::{
     aead::{self, BoundKey, OpeningKey, SealingKey, UnboundKey},
-    hmac,
+    hmac, signature,
 };
 
 use crate::{
@@ -238,6 +238,24 @@ impl VerifySignature for HmacSha512 {
     }
 }
 
+/// Represents the Ed25519 signature algorithm, where the secret used for verification is the
+/// public key of the signer
+#[derive(Debug)]
+pub struct Ed25519;
+
+impl VerifySignature for Ed25519 {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        let public_key = signature::UnparsedPublicKey::new(&signature::ED25519, secret);
+
+        Ok(public_key.verify(msg, signature).is_ok())
+    }
+}
+
 /// Blake3
 #[derive(Debug)]
 pub struct Blake3(String);
@@ -703,6 +721,31 @@ mod crypto_tests {
         assert!(!wrong_verified);
     }
 
+    #[test]
+    fn test_ed25519_verify_signature() {
+        // Test vector 2 of RFC 8032, section 7.1
+        let public_key =
+            hex::decode("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c")
+                .expect("Public key decoding");
+        let right_signature = hex::decode("92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00")
+            .expect("Right signature decoding");
+        let wrong_signature = hex::decode("92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c01")
+            .expect("Wrong signature decoding");
+        let data = "r".as_bytes();
+
+        let right_verified = super::Ed25519
+            .verify_signature(&public_key, &right_signature, data)
+            .expect("Right signature verification result");
+
+        assert!(right_verified);
+
+        let wrong_verified = super::Ed25519
+            .verify_signature(&public_key, &wrong_signature, data)
+            .expect("Wrong signature verification result");
+
+        assert!(!wrong_verified);
+    }
+
     #[test]
     fn test_gcm_aes_256_encode_message() {
         let message = r#"{"type":"PAYMENT"}"#.as_bytes();
@@ -682,6 +682,18 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub disabled_event_classes: Option<HashSet<common_enums::EventClass>>,
+}
+
+impl WebhookDetails {
+    /// Returns whether outgoing webhooks are sent for events of the specified class.
+    pub fn is_event_class_enabled(&self, event_class: common_enums::EventClass) -> bool {
+        self.disabled_event_classes
+            .as_ref()
+            .map_or(true, |disabled_event_classes| {
+                !disabled_event_classes.contains(&event_class)
+            })
+    }
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
@@ -27,6 +27,11 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub tags: Option<Vec<String>>,
+    pub triggered_by: Option<storage_enums::WebhookRetryTrigger>,
+    pub ingested_at: Option<PrimitiveDateTime>,
+    pub primary_object_updated_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -35,6 +40,7 @@ pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -60,6 +66,27 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub tags: Option<Vec<String>>,
+    pub triggered_by: Option<storage_enums::WebhookRetryTrigger>,
+    pub ingested_at: Option<PrimitiveDateTime>,
+    pub primary_object_updated_at: Option<PrimitiveDateTime>,
+}
+
+/// The number of initial delivery attempts in a group of events with the same overall delivery
+/// status, as counted by the database. Only the attribute the events are grouped by is set.
+#[derive(Clone, Debug, Queryable)]
+pub struct EventDeliveryStatusCount {
+    pub event_type: Option<storage_enums::EventType>,
+    pub event_class: Option<storage_enums::EventClass>,
+    pub business_profile_id: Option<common_utils::id_type::ProfileId>,
+    /// The start of the UTC calendar day the events were created on.
+    pub created_day: Option<PrimitiveDateTime>,
+    pub is_overall_delivery_successful: Option<bool>,
+    /// The number of initial delivery attempts in the group.
+    pub attempts_count: i64,
+    /// The number of initial delivery attempts in the group for which a response was received.
+    pub responses_count: i64,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -67,18 +94,26 @@ pub struct Event {
 pub enum EventMetadata {
     Payment {
         payment_id: common_utils::id_type::PaymentId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        connector: Option<String>,
     },
     Payout {
         payout_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        connector: Option<String>,
     },
     Refund {
         payment_id: common_utils::id_type::PaymentId,
         refund_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        connector: Option<String>,
     },
     Dispute {
         payment_id: common_utils::id_type::PaymentId,
         attempt_id: String,
         dispute_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        connector: Option<String>,
     },
     Mandate {
         payment_method_id: String,
@@ -86,4 +121,48 @@ pub enum EventMetadata {
     },
 }
 
+impl EventMetadata {
+    /// Returns the name of the connector associated with the object, if any. This is not
+    /// available for mandates, and for events stored before the connector was recorded.
+    pub fn get_connector(&self) -> Option<&str> {
+        match self {
+            Self::Payment { connector, .. }
+            | Self::Payout { connector, .. }
+            | Self::Refund { connector, .. }
+            | Self::Dispute { connector, .. } => connector.as_deref(),
+            Self::Mandate { .. } => None,
+        }
+    }
+}
+
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::EventMetadata;
+
+    #[test]
+    fn test_event_metadata_connector_serde() {
+        let metadata = serde_json::from_str::<EventMetadata>(
+            r#"{"Refund":{"payment_id":"pay_1","refund_id":"ref_1","connector":"adyen"}}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.get_connector(), Some("adyen"));
+
+        // Metadata stored before the connector was recorded
+        let metadata =
+            serde_json::from_str::<EventMetadata>(r#"{"Payment":{"payment_id":"pay_1"}}"#).unwrap();
+        assert_eq!(metadata.get_connector(), None);
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"Payment":{"payment_id":"pay_1"}}"#
+        );
+
+        let metadata = serde_json::from_str::<EventMetadata>(
+            r#"{"Mandate":{"payment_method_id":"pm_1","mandate_id":"man_1"}}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.get_connector(), None);
+    }
+}
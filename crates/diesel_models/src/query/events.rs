@@ -1,16 +1,74 @@
 use std::collections::HashSet;
 
 use diesel::{
-    associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+    associations::HasTable, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, TextExpressionMethods,
 };
 
 use super::generics;
 use crate::{
-    events::{Event, EventNew, EventUpdateInternal},
-    schema::events::dsl,
+    events::{Event, EventDeliveryStatusCount, EventNew, EventUpdateInternal},
+    schema::events::{self, dsl},
     PgPooledConn, StorageResult,
 };
 
+/// The initial attempt identifiers of the delivery chains of a merchant containing a successful
+/// delivery attempt created after a given time.
+type DeliveredInitialAttemptIds = diesel::dsl::Filter<
+    diesel::dsl::Select<events::table, dsl::initial_attempt_id>,
+    diesel::dsl::And<
+        diesel::dsl::And<
+            diesel::dsl::And<
+                diesel::dsl::Eq<dsl::is_webhook_notified, bool>,
+                diesel::dsl::IsNotNull<dsl::initial_attempt_id>,
+            >,
+            diesel::dsl::Eq<dsl::merchant_id, common_utils::id_type::MerchantId>,
+        >,
+        diesel::dsl::GtEq<dsl::created_at, time::PrimitiveDateTime>,
+    >,
+>;
+
+/// The SQL types of the attributes the initial attempts are grouped by when counting them by their
+/// delivery status.
+type EventDeliveryStatusGroupSqlType = (
+    diesel::sql_types::Nullable<crate::enums::DbEventType>,
+    diesel::sql_types::Nullable<crate::enums::DbEventClass>,
+    diesel::sql_types::Nullable<diesel::sql_types::Varchar>,
+    diesel::sql_types::Nullable<diesel::sql_types::Timestamp>,
+    diesel::sql_types::Nullable<diesel::sql_types::Bool>,
+);
+
+/// The SQL types of the attributes the initial attempts are grouped by when counting them by their
+/// delivery status, followed by the SQL types of the counts.
+type EventDeliveryStatusCountSqlType = (
+    diesel::sql_types::Nullable<crate::enums::DbEventType>,
+    diesel::sql_types::Nullable<crate::enums::DbEventClass>,
+    diesel::sql_types::Nullable<diesel::sql_types::Varchar>,
+    diesel::sql_types::Nullable<diesel::sql_types::Timestamp>,
+    diesel::sql_types::Nullable<diesel::sql_types::Bool>,
+    diesel::sql_types::BigInt,
+    diesel::sql_types::BigInt,
+);
+
+/// The initial attempt identifiers of the delivery chains of a merchant with at least a given
+/// number of delivery attempts created after a given time.
+type RepeatedlyAttemptedInitialAttemptIds = diesel::dsl::Having<
+    diesel::dsl::GroupBy<
+        diesel::dsl::Filter<
+            diesel::dsl::Select<events::table, dsl::initial_attempt_id>,
+            diesel::dsl::And<
+                diesel::dsl::And<
+                    diesel::dsl::IsNotNull<dsl::initial_attempt_id>,
+                    diesel::dsl::Eq<dsl::merchant_id, common_utils::id_type::MerchantId>,
+                >,
+                diesel::dsl::GtEq<dsl::created_at, time::PrimitiveDateTime>,
+            >,
+        >,
+        dsl::initial_attempt_id,
+    >,
+    diesel::dsl::GtEq<diesel::dsl::count_star, i64>,
+>;
+
 impl EventNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Event> {
         generics::generic_insert(conn, self).await
@@ -36,34 +94,133 @@ impl Event {
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::event_id
-                .nullable()
-                .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
-                .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
-            None,
-            None,
-            Some(dsl::created_at.desc()),
-        )
-        .await
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .and(dsl::primary_object_id.eq(primary_object_id.to_owned()));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn list_initial_attempts_by_merchant_id_primary_object_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .and(dsl::primary_object_id.eq_any(primary_object_ids.to_owned()));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
     }
 
+    /// Lists a page of the initial attempts created within the specified time range, whose
+    /// primary object ID contains the specified substring. The substring can occur anywhere in the
+    /// primary object ID, so the time range bounds the events scanned.
     #[allow(clippy::too_many_arguments)]
-    pub async fn list_initial_attempts_by_merchant_id_constraints(
+    pub async fn list_initial_attempts_by_merchant_id_primary_object_id_contains(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_id_substring: &str,
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
-        event_types: HashSet<common_enums::EventType>,
-        is_delivered: Option<bool>,
+        sort_by: common_enums::EventSortBy,
     ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .and(dsl::created_at.ge(created_after))
+            .and(dsl::created_at.le(created_before))
+            .and(dsl::primary_object_id.like(like_contains_pattern(primary_object_id_substring)));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Counts the initial attempts of the merchant, or of the specified business profile, created
+    /// within the specified time range whose primary object ID contains the specified substring,
+    /// by their event class.
+    pub async fn count_initial_attempts_by_primary_object_id_contains(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<(common_enums::EventClass, i64)>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
-        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use diesel::{debug_query, QueryDsl};
         use error_stack::ResultExt;
         use router_env::logger;
 
@@ -71,31 +228,148 @@ impl Event {
         use crate::errors::DatabaseError;
 
         let mut query = Self::table()
+            .select((dsl::event_class, diesel::dsl::count_star()))
             .filter(
                 dsl::event_id
                     .nullable()
                     .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                    .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before))
+                    .and(
+                        dsl::primary_object_id
+                            .like(like_contains_pattern(primary_object_id_substring)),
+                    ),
             )
-            .order(dsl::created_at.desc())
+            .group_by(dsl::event_class)
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::business_profile_id.eq(profile_id));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async::<(common_enums::EventClass, i64)>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting events by partial primary object ID")
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_initial_attempts_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        sort_by: common_enums::EventSortBy,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .into_boxed();
 
         query = Self::apply_filters(
             query,
+            merchant_id,
             None,
             (dsl::created_at, created_after, created_before),
             limit,
             offset,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            profile_ids,
         );
 
+        let is_ending_before = ending_before.is_some();
+        query = Self::apply_ordering(query, sort_by, starting_after, ending_before);
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
             .await
             .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
             .attach_printable("Error filtering events by constraints")
+            .map(|mut events: Vec<Self>| {
+                // Events preceding the cursor are fetched in the reverse order so that the page
+                // closest to the cursor is selected, restore the requested order here.
+                if is_ending_before {
+                    events.reverse();
+                }
+                events
+            })
+    }
+
+    /// Lists the initial attempts of the merchant associated with a business profile other than
+    /// the specified business profiles of the merchant. Events without a business profile are not
+    /// considered orphaned.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_orphaned_initial_attempts_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_profile_ids: HashSet<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        sort_by: common_enums::EventSortBy,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::event_id.nullable().eq(dsl::initial_attempt_id))
+            .filter(dsl::business_profile_id.is_not_null())
+            .filter(dsl::business_profile_id.ne_all(merchant_profile_ids))
+            .filter(dsl::created_at.ge(created_after))
+            .filter(dsl::created_at.le(created_before))
+            .into_boxed();
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        query = Self::apply_ordering(query, sort_by, starting_after, None);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering orphaned events")
     }
 
     pub async fn list_by_merchant_id_initial_attempt_id(
@@ -119,34 +393,142 @@ impl Event {
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::event_id
-                .nullable()
-                .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                .and(dsl::business_profile_id.eq(profile_id.to_owned()))
-                .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
-            None,
-            None,
-            Some(dsl::created_at.desc()),
-        )
-        .await
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+            .and(dsl::primary_object_id.eq(primary_object_id.to_owned()));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn list_initial_attempts_by_profile_id_primary_object_ids(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+            .and(dsl::primary_object_id.eq_any(primary_object_ids.to_owned()));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Lists a page of the initial attempts created within the specified time range, whose
+    /// primary object ID contains the specified substring. The substring can occur anywhere in the
+    /// primary object ID, so the time range bounds the events scanned.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_initial_attempts_by_profile_id_primary_object_id_contains(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::event_id
+            .nullable()
+            .eq(dsl::initial_attempt_id) // Filter initial attempts only
+            .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+            .and(dsl::created_at.ge(created_after))
+            .and(dsl::created_at.le(created_before))
+            .and(dsl::primary_object_id.like(like_contains_pattern(primary_object_id_substring)));
+
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            common_enums::EventSortBy::CreatedDesc => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
     pub async fn list_initial_attempts_by_profile_id_constraints(
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
+        merchant_id: &common_utils::id_type::MerchantId,
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        sort_by: common_enums::EventSortBy,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
-        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use diesel::{debug_query, QueryDsl};
         use error_stack::ResultExt;
         use router_env::logger;
 
@@ -154,31 +536,43 @@ impl Event {
         use crate::errors::DatabaseError;
 
         let mut query = Self::table()
-            .filter(
-                dsl::event_id
-                    .nullable()
-                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                    .and(dsl::business_profile_id.eq(profile_id.to_owned())),
-            )
-            .order(dsl::created_at.desc())
+            .filter(dsl::business_profile_id.eq(profile_id.to_owned()))
             .into_boxed();
 
         query = Self::apply_filters(
             query,
+            merchant_id,
             None,
             (dsl::created_at, created_after, created_before),
             limit,
             offset,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            None,
         );
 
+        let is_ending_before = ending_before.is_some();
+        query = Self::apply_ordering(query, sort_by, starting_after, ending_before);
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
             .await
             .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
             .attach_printable("Error filtering events by constraints")
+            .map(|mut events: Vec<Self>| {
+                // Events preceding the cursor are fetched in the reverse order so that the page
+                // closest to the cursor is selected, restore the requested order here.
+                if is_ending_before {
+                    events.reverse();
+                }
+                events
+            })
     }
 
     pub async fn list_by_profile_id_initial_attempt_id(
@@ -219,8 +613,90 @@ impl Event {
         .await
     }
 
+    /// Applies the requested ordering along with keyset pagination on `(created_at, event_id)`.
+    /// `starting_after` selects events listed after the cursor event, while `ending_before`
+    /// selects events listed before the cursor event. Events preceding the cursor are selected in
+    /// the reverse order, so that the page closest to the cursor is picked.
+    fn apply_ordering(
+        query: events::BoxedQuery<'static, Pg>,
+        sort_by: common_enums::EventSortBy,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
+    ) -> events::BoxedQuery<'static, Pg> {
+        use diesel::QueryDsl;
+
+        let is_sort_descending = matches!(sort_by, common_enums::EventSortBy::CreatedDesc);
+        let (cursor, is_descending) = match (starting_after, ending_before) {
+            (Some(cursor), _) => (Some(cursor), is_sort_descending),
+            (None, Some(cursor)) => (Some(cursor), !is_sort_descending),
+            (None, None) => (None, is_sort_descending),
+        };
+
+        match (cursor, is_descending) {
+            (Some((created_at, event_id)), true) => query
+                .filter(
+                    dsl::created_at.lt(created_at).or(dsl::created_at
+                        .eq(created_at)
+                        .and(dsl::event_id.lt(event_id))),
+                )
+                .order((dsl::created_at.desc(), dsl::event_id.desc())),
+            (Some((created_at, event_id)), false) => query
+                .filter(
+                    dsl::created_at.gt(created_at).or(dsl::created_at
+                        .eq(created_at)
+                        .and(dsl::event_id.gt(event_id))),
+                )
+                .order((dsl::created_at.asc(), dsl::event_id.asc())),
+            (None, true) => query.order((dsl::created_at.desc(), dsl::event_id.desc())),
+            (None, false) => query.order((dsl::created_at.asc(), dsl::event_id.asc())),
+        }
+    }
+
+    /// Selects the initial attempt identifiers of the delivered delivery chains of the merchant.
+    /// Delivery attempts are created after the initial attempt of their delivery chain, so only
+    /// the delivery attempts created after the listed initial attempts are scanned.
+    fn delivered_initial_attempt_ids(
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+    ) -> DeliveredInitialAttemptIds {
+        use diesel::QueryDsl;
+
+        events::table.select(dsl::initial_attempt_id).filter(
+            dsl::is_webhook_notified
+                .eq(true)
+                .and(dsl::initial_attempt_id.is_not_null())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::created_at.ge(created_after)),
+        )
+    }
+
+    /// Selects the initial attempt identifiers of the delivery chains of the merchant with at
+    /// least the specified number of delivery attempts. Delivery attempts are created after the
+    /// initial attempt of their delivery chain, so only the delivery attempts created after the
+    /// listed initial attempts are grouped.
+    fn repeatedly_attempted_initial_attempt_ids(
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        min_attempts: u16,
+    ) -> RepeatedlyAttemptedInitialAttemptIds {
+        use diesel::QueryDsl;
+
+        events::table
+            .select(dsl::initial_attempt_id)
+            .filter(
+                dsl::initial_attempt_id
+                    .is_not_null()
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::created_at.ge(created_after)),
+            )
+            .group_by(dsl::initial_attempt_id)
+            .having(diesel::dsl::count_star().ge(i64::from(min_attempts)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn apply_filters<T>(
         mut query: T,
+        merchant_id: &common_utils::id_type::MerchantId,
         profile_id: Option<common_utils::id_type::ProfileId>,
         (column, created_after, created_before): (
             dsl::created_at,
@@ -231,6 +707,12 @@ impl Event {
         offset: Option<i64>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
     ) -> T
     where
         T: diesel::query_dsl::methods::LimitDsl<Output = T>
@@ -255,11 +737,53 @@ impl Event {
             diesel::dsl::Eq<dsl::is_overall_delivery_successful, bool>,
             Output = T,
         >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<
+                dsl::merchant_connector_id,
+                common_utils::id_type::MerchantConnectorAccountId,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<diesel::dsl::Nullable<dsl::event_id>, dsl::initial_attempt_id>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<
+                dsl::delivery_attempt,
+                HashSet<common_enums::WebhookDeliveryAttempt>,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<dsl::business_profile_id, HashSet<common_utils::id_type::ProfileId>>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::NeAny<dsl::initial_attempt_id, DeliveredInitialAttemptIds>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<dsl::initial_attempt_id, RepeatedlyAttemptedInitialAttemptIds>,
+            Output = T,
+        >,
     {
+        if only_initial_attempts {
+            query = query.filter(dsl::event_id.nullable().eq(dsl::initial_attempt_id));
+        }
+
+        if let Some(delivery_attempts) = delivery_attempts {
+            query = query.filter(dsl::delivery_attempt.eq_any(delivery_attempts));
+        }
+
         if let Some(profile_id) = profile_id {
             query = query.filter(dsl::business_profile_id.eq(profile_id));
         }
 
+        if let Some(profile_ids) = profile_ids {
+            query = query.filter(dsl::business_profile_id.eq_any(profile_ids));
+        }
+
         query = query
             .filter(column.ge(created_after))
             .filter(column.le(created_before));
@@ -280,9 +804,39 @@ impl Event {
             query = query.filter(dsl::is_overall_delivery_successful.eq(is_delivered));
         }
 
+        // Delivery chains with a late successful attempt are excluded, even if the overall
+        // delivery status of the initial attempt was not updated.
+        if never_delivered {
+            query = query
+                .filter(dsl::is_overall_delivery_successful.eq(false))
+                .filter(
+                    dsl::initial_attempt_id.ne_all(Self::delivered_initial_attempt_ids(
+                        merchant_id,
+                        created_after,
+                    )),
+                );
+        }
+
+        // Delivery attempts are counted across the delivery chain, irrespective of whether they
+        // match the other filters.
+        if let Some(min_attempts) = min_attempts.filter(|min_attempts| *min_attempts > 1) {
+            query = query.filter(dsl::initial_attempt_id.eq_any(
+                Self::repeatedly_attempted_initial_attempt_ids(
+                    merchant_id,
+                    created_after,
+                    min_attempts,
+                ),
+            ));
+        }
+
+        if let Some(merchant_connector_id) = merchant_connector_id {
+            query = query.filter(dsl::merchant_connector_id.eq(merchant_connector_id));
+        }
+
         query
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn count_initial_attempts_by_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -291,9 +845,15 @@ impl Event {
         created_before: time::PrimitiveDateTime,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
     ) -> StorageResult<i64> {
         use async_bb8_diesel::AsyncRunQueryDsl;
-        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use diesel::{debug_query, QueryDsl};
         use error_stack::ResultExt;
         use router_env::logger;
 
@@ -302,22 +862,24 @@ impl Event {
 
         let mut query = Self::table()
             .count()
-            .filter(
-                dsl::event_id
-                    .nullable()
-                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                    .and(dsl::merchant_id.eq(merchant_id.to_owned())),
-            )
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .into_boxed();
 
         query = Self::apply_filters(
             query,
+            merchant_id,
             profile_id,
             (dsl::created_at, created_after, created_before),
             None,
             None,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            profile_ids,
         );
 
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
@@ -330,4 +892,90 @@ impl Event {
         .change_context(DatabaseError::Others)
         .attach_printable("Error counting events by constraints")
     }
+
+    /// Counts the initial attempts of the merchant grouped by the specified attribute and their
+    /// overall delivery status, along with the number of initial attempts in each group for which
+    /// a response was received.
+    pub async fn count_initial_attempts_by_delivery_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        group_by: common_enums::EventStatsGroupBy,
+    ) -> StorageResult<Vec<EventDeliveryStatusCount>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        // The UTC calendar day the events were created on cannot be expressed using the query
+        // builder, so the grouped attributes are selected as SQL, with `NULL` selected for the
+        // attributes the events are not grouped by.
+        let grouped_attributes = match group_by {
+            common_enums::EventStatsGroupBy::EventType => "event_type, NULL, NULL, NULL",
+            common_enums::EventStatsGroupBy::EventClass => "NULL, event_class, NULL, NULL",
+            common_enums::EventStatsGroupBy::Profile => "NULL, NULL, business_profile_id, NULL",
+            common_enums::EventStatsGroupBy::Day => {
+                "NULL, NULL, NULL, date_trunc('day', created_at)"
+            }
+        };
+
+        let selection = format!(
+            "{grouped_attributes}, is_overall_delivery_successful, COUNT(*), COUNT(response)"
+        );
+
+        // The initial attempts are grouped by the attributes selected ahead of the counts
+        let mut query = Self::table()
+            .select(diesel::dsl::sql::<EventDeliveryStatusCountSqlType>(
+                &selection,
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .group_by(diesel::dsl::sql::<EventDeliveryStatusGroupSqlType>(
+                "1, 2, 3, 4, 5",
+            ))
+            .into_boxed();
+
+        query = Self::apply_filters(
+            query,
+            merchant_id,
+            profile_id,
+            (dsl::created_at, created_after, created_before),
+            None,
+            None,
+            event_types,
+            None,
+            None,
+            None,
+            true,
+            false,
+            None,
+            None,
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async::<EventDeliveryStatusCount>(conn),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error counting events by delivery status")
+    }
+}
+
+/// Builds a `LIKE` pattern matching values containing the specified substring, escaping the
+/// wildcard characters in the substring so that they are matched literally.
+fn like_contains_pattern(substring: &str) -> String {
+    let escaped = substring
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{escaped}%")
 }
@@ -477,6 +477,13 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 128]
+        merchant_connector_id -> Nullable<Varchar>,
+        tags -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 32]
+        triggered_by -> Nullable<Varchar>,
+        ingested_at -> Nullable<Timestamp>,
+        primary_object_updated_at -> Nullable<Timestamp>,
     }
 }
 
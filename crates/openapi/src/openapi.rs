@@ -197,8 +197,18 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
+        routes::webhook_events::count_initial_webhook_delivery_attempts,
+        routes::webhook_events::export_initial_webhook_delivery_attempts,
+        routes::webhook_events::get_webhook_delivery_stats,
+        routes::webhook_events::get_webhook_endpoint_health,
+        routes::webhook_events::sync_events,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::replay_webhook_delivery_attempt,
+        routes::webhook_events::get_webhook_retry_schedule,
+        routes::webhook_events::bulk_retry_webhook_delivery_attempts,
+        routes::webhook_events::retrieve_webhook_events_batch,
+        routes::webhook_events::tag_webhook_event,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -684,11 +694,50 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListConstraints,
         api_models::webhook_events::EventListItemResponse,
+        api_models::webhook_events::EventListItemCompact,
+        api_models::webhook_events::EventListItems,
         api_models::webhook_events::EventRetrieveResponse,
+        api_models::webhook_events::EventRetrySchedule,
+        api_models::webhook_events::WebhookAttemptSummary,
+        api_models::webhook_events::CollapsedAttempt,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::EventsCountResponse,
+        api_models::webhook_events::EventDeliveryStatsResponse,
+        api_models::webhook_events::EventDeliveryStatsRequest,
+        api_models::webhook_events::EventListExportRequest,
+        api_models::webhook_events::EventDeliveryStat,
+        api_models::webhook_events::EventStatsGroupKey,
+        api_models::webhook_events::EventSyncRequest,
+        api_models::webhook_events::EventSyncResponse,
+        api_models::webhook_events::BulkWebhookDeliveryRetryRequest,
+        api_models::webhook_events::BulkWebhookDeliveryRetryResponse,
+        api_models::webhook_events::BulkWebhookDeliveryRetryResult,
+        api_models::webhook_events::WebhookDeliveryRetryStatus,
+        api_models::webhook_events::WebhookDeliveryRetryResponse,
+        api_models::webhook_events::WebhookDeliveryRetryDryRunResponse,
+        api_models::webhook_events::WebhookDeliveryRetryDeduplicatedResponse,
+        api_models::webhook_events::EventBatchRetrieveRequest,
+        api_models::webhook_events::EventBatchRetrieveResult,
+        api_models::webhook_events::EventBatchRetrieveResponse,
+        api_models::webhook_events::EventTagRequest,
+        api_models::webhook_events::EndpointHealth,
+        api_models::webhook_events::WebhookEndpointHealthResponse,
+        api_models::webhook_events::WebhookReplayRequest,
+        api_models::webhook_events::WebhookRetrySchedule,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookRetryTrigger,
+        api_models::enums::EventSortBy,
+        api_models::enums::EventResponseMode,
+        api_models::enums::CountMode,
+        api_models::enums::EventStatsGroupBy,
+        api_models::enums::EventField,
+        api_models::enums::EventTimeRangePreset,
+        api_models::enums::WebhookDeliveryErrorCategory,
+        api_models::enums::WebhookSignatureAlgorithm,
+        api_models::enums::WebhookDeliveryState,
+        api_models::enums::WebhookOutcome,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
@@ -73,6 +73,211 @@ pub fn list_initial_webhook_delivery_attempts() {}
 )]
 pub fn list_initial_webhook_delivery_attempts_with_jwtauth() {}
 
+/// Events - Count
+///
+/// Count all Events associated with a Merchant Account or Profile. The same constraints as the
+/// Events - List API are applied, except for pagination, and the count matches the exact
+/// `total_count` returned by the Events - List API.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/count",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = EventListConstraints,
+        description = "The constraints that can be applied when counting Events.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "created_after": "2023-01-01T00:00:00",
+                    "created_before": "2023-01-31T23:59:59",
+                    "profile_id": "{{profile_id}}",
+                    "event_classes": ["payments", "refunds"],
+                    "event_types": ["payment_succeeded"],
+                    "is_delivered": true
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Count of Events retrieved successfully", body = EventsCountResponse),
+    ),
+    tag = "Event",
+    operation_id = "Count all Events associated with a Merchant Account or Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn count_initial_webhook_delivery_attempts() {}
+
+/// Events - Export
+///
+/// Export all Events associated with a Merchant Account or Profile as newline-delimited JSON, with
+/// one Event per line. The same constraints as the Events - List API are applied. When none of
+/// the pagination constraints are specified, all the Events matching the constraints are exported.
+/// At most 10000 Events can be exported at once; when more Events match the constraints, the
+/// request is rejected, and the Events can be exported a page at a time using `limit` and
+/// `starting_after` instead. When `fields` is specified, only the specified fields of the Events are exported, in the
+/// specified order. The first line is a manifest nested under a `manifest` key, describing the
+/// merchant, the constraints, the number of Events exported, the time of the export and its format.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/export",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = EventListExportRequest,
+        description = "The constraints that can be applied when exporting Events, and the fields of the Events to export.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "created_after": "2023-01-01T00:00:00",
+                    "created_before": "2023-01-31T23:59:59",
+                    "profile_id": "{{profile_id}}",
+                    "event_classes": ["payments", "refunds"],
+                    "event_types": ["payment_succeeded"],
+                    "is_delivered": true,
+                    "fields": ["event_id", "event_type", "created"]
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Events exported successfully", body = EventListItemResponse, content_type = "application/x-ndjson"),
+    ),
+    tag = "Event",
+    operation_id = "Export all Events associated with a Merchant Account or Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn export_initial_webhook_delivery_attempts() {}
+
+/// Events - Delivery Stats
+///
+/// Retrieve the count of succeeded, failed and pending deliveries of the Events associated with a
+/// Merchant Account or Profile, grouped by the event type, event class, profile or UTC calendar
+/// day. Only the `created_after`, `created_before`, `profile_id`, `event_classes` and
+/// `event_types` constraints are applied.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/stats",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = EventDeliveryStatsRequest,
+        description = "The constraints and grouping that can be applied when retrieving the delivery stats of Events.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "created_after": "2023-01-01T00:00:00",
+                    "created_before": "2023-01-31T23:59:59",
+                    "profile_id": "{{profile_id}}",
+                    "event_classes": ["payments", "refunds"],
+                    "group_by": "day"
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Delivery stats of Events retrieved successfully", body = EventDeliveryStatsResponse),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the delivery stats of Events associated with a Merchant Account or Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn get_webhook_delivery_stats() {}
+
+/// Events - Endpoint Health
+///
+/// Retrieve the delivery health of each endpoint the Events associated with a Merchant Account or
+/// Profile were delivered to, including the success rate, the 95th percentile latency and the time
+/// of the last successful delivery. Every delivery attempt, including retries, is accounted for.
+/// Only the `created_after`, `created_before`, `time_range`, `profile_id`, `event_classes` and
+/// `event_types` constraints are applied. At most 10000 delivery attempts are accounted for; when
+/// more delivery attempts match the constraints, the request is rejected.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/endpoint_health",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = EventListConstraints,
+        description = "The constraints that can be applied when retrieving the delivery health of endpoints.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "created_after": "2023-01-01T00:00:00",
+                    "created_before": "2023-01-31T23:59:59",
+                    "profile_id": "{{profile_id}}"
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Delivery health of endpoints retrieved successfully", body = WebhookEndpointHealthResponse),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the delivery health of the endpoints Events are delivered to",
+    security(("admin_api_key" = []))
+)]
+pub fn get_webhook_endpoint_health() {}
+
+/// Events - Sync
+///
+/// Incrementally sync the Events associated with a Merchant Account, such as for mirroring them
+/// into a data warehouse. Returns the initial delivery attempts created strictly after
+/// `since_event_id` in the order of their creation time and event ID, along with the
+/// `last_event_id` to pass in the next sync. A `since_event_id` created more than 90 days ago has
+/// expired, and the sync must be restarted without it.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/sync",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = EventSyncRequest,
+        description = "The position to resume the sync from and the maximum number of Events to return.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "since_event_id": "{{last_event_id}}",
+                    "limit": 100
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Events synced successfully", body = EventSyncResponse),
+        (status = 400, description = "The `since_event_id` cursor has expired"),
+    ),
+    tag = "Event",
+    operation_id = "Sync the Events associated with a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn sync_events() {}
+
 /// Events - Delivery Attempt List
 ///
 /// List all delivery attempts for the specified Event.
@@ -101,13 +306,25 @@ pub fn list_webhook_delivery_attempts() {}
     params(
         ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
         ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        ("dry_run" = Option<bool>, Query, description = "Only resolve the Event to retry, without attempting its delivery"),
+        ("include_attempts" = Option<bool>, Query, description = "Include the timeline of all the delivery attempts of the Event in the response"),
+        ("idempotency_key" = Option<String>, Query, description = "A key identifying the retry. Retries of the Event with the same key within 10 minutes are deduplicated"),
+        ("max_event_age_seconds" = Option<u32>, Query, description = "The maximum age of the Event to retry, in seconds. Events older than this are not retried. Defaults to 3 days"),
     ),
     responses(
         (
             status = 200,
             description = "The delivery of the Event was attempted. \
-                           Check the `response` field in the response payload to identify the status of the delivery attempt.",
-            body = EventRetrieveResponse
+                           Check the `response` field in the response payload to identify the status of the delivery attempt. \
+                           When `dry_run` is set, the identifier of the resolved Event is returned instead. \
+                           When the retry is deduplicated, `deduplicated` is set and no delivery is attempted.",
+            body = WebhookDeliveryRetryResponse
+        ),
+        (
+            status = 400,
+            description = "The Event cannot be retried, since it was already delivered, is older than the maximum age \
+                           allowed for retries, reached the maximum number of delivery attempts, belongs to an event class \
+                           with webhooks disabled, or its request body was not persisted"
         ),
     ),
     tag = "Event",
@@ -115,3 +332,174 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Replay
+///
+/// Replay the delivery of the specified Event to an alternate URL, without changing the webhook URL
+/// configured for the business profile. The recorded request body is sent with its signature
+/// recomputed, and the outcome is recorded as a new delivery attempt of the Event.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/{event_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    request_body(
+        content = WebhookReplayRequest,
+        description = "The URL to replay the Event to.",
+        examples (
+            ("Replay to an alternate URL" = (
+                value = json!({
+                    "override_url": "https://merchant.example.com/webhooks/v2"
+                })
+            )),
+        )
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The delivery of the Event to the alternate URL was attempted. \
+                           Check the `response` field in the response payload to identify the status of the delivery attempt.",
+            body = EventRetrieveResponse
+        ),
+        (status = 400, description = "The alternate URL is not a valid `https` URL"),
+    ),
+    tag = "Event",
+    operation_id = "Replay an Event to an alternate URL",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_webhook_delivery_attempt() {}
+
+/// Events - Tag
+///
+/// Set the tags of the specified Event, replacing any tags previously added to it. Tags allow
+/// operators to annotate Events, such as during incident triage, and Events can be filtered by
+/// their tags when listing them.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/{event_id}/tags",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    request_body(
+        content = EventTagRequest,
+        description = "The tags to set on the Event.",
+        examples (
+            ("Tag an Event under investigation" = (
+                value = json!({
+                    "tags": ["under-investigation"]
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "The tags of the Event were updated", body = EventListItemResponse),
+        (status = 400, description = "The tags are empty, too long or too many"),
+        (status = 404, description = "No Event was found with the specified `event_id`"),
+    ),
+    tag = "Event",
+    operation_id = "Tag an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn tag_webhook_event() {}
+
+/// Events - Retry Schedule
+///
+/// Preview the times at which the remaining automatic retries of the specified Event are
+/// scheduled, based on the retry configuration of the merchant. The schedule is empty once the
+/// Event has been delivered or its automatic retries have been exhausted.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}/retry_schedule",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    responses(
+        (status = 200, description = "The remaining automatic retries of the Event", body = WebhookRetrySchedule),
+    ),
+    tag = "Event",
+    operation_id = "Preview the remaining automatic retries of an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn get_webhook_retry_schedule() {}
+
+/// Events - Bulk Manual Retry
+///
+/// Manually retry the delivery of multiple Events, selected either by their identifiers or by the
+/// constraints used when listing Events. At most 100 Events can be retried at once.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/retry",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = BulkWebhookDeliveryRetryRequest,
+        description = "The Events to retry the delivery for.",
+        examples (
+            ("Retry by event IDs" = (
+                value = json!({
+                    "event_ids": ["evt_018e31720d1b7a2b82677d3032cab959"]
+                })
+            )),
+            ("Retry by constraints" = (
+                value = json!({
+                    "constraints": {
+                        "created_after": "2023-01-01T00:00:00",
+                        "is_delivered": false
+                    }
+                })
+            )),
+        )
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The delivery of the selected Events was queued, unless `dry_run` was set. \
+                           Check the `status` field of each result to identify the Events that could not be retried.",
+            body = BulkWebhookDeliveryRetryResponse
+        ),
+    ),
+    tag = "Event",
+    operation_id = "Manually retry the delivery of multiple Events",
+    security(("admin_api_key" = []))
+)]
+pub fn bulk_retry_webhook_delivery_attempts() {}
+
+/// Events - Batch Retrieve
+///
+/// Retrieve multiple Events at once, including their request and response bodies. At most 20
+/// Events can be retrieved at once. Events which are not found are marked as such in the response.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/retrieve",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = EventBatchRetrieveRequest,
+        description = "The identifiers of the Events to retrieve.",
+        examples (
+            ("Retrieve by event IDs" = (
+                value = json!({
+                    "event_ids": ["evt_018e31720d1b7a2b82677d3032cab959"]
+                })
+            )),
+        )
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The requested Events. \
+                           Check the `status` field of each result to identify the Events that were not found.",
+            body = EventBatchRetrieveResponse
+        ),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve multiple Events",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_events_batch() {}
@@ -1,7 +1,7 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::{
-    enums::{Currency, DisputeStatus, MandateStatus},
+    enums::{Currency, DisputeStatus, MandateStatus, WebhookSignatureAlgorithm},
     webhooks::{self as api},
 };
 #[cfg(feature = "payouts")]
@@ -35,6 +35,8 @@ pub struct StripeOutgoingWebhook {
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
+    const SIGNATURE_ALGORITHM: WebhookSignatureAlgorithm = WebhookSignatureAlgorithm::HmacSha256;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
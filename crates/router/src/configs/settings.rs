@@ -789,6 +789,28 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    /// Mask email addresses and card numbers in the webhook request bodies stored with events.
+    /// Events whose stored body was masked are resent with a regenerated body by automatic
+    /// retries, and cannot be retried manually.
+    pub redact_stored_pii: bool,
+    /// Sort the headers of the webhook requests stored with events by name, so that stored
+    /// events are diff-stable.
+    pub normalize_stored_headers: bool,
+    /// The event classes whose webhook request and response bodies are not stored with events.
+    /// The remaining request and response information is still stored, and automatic retries
+    /// regenerate the request body from the current state of the resource.
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub body_persistence_disabled_event_classes: HashSet<enums::EventClass>,
+}
+
+impl WebhooksSettings {
+    /// Returns whether the webhook request and response bodies are to be stored for events of
+    /// the specified class.
+    pub fn should_persist_body(&self, event_class: enums::EventClass) -> bool {
+        !self
+            .body_persistence_disabled_event_classes
+            .contains(&event_class)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -1483,3 +1505,24 @@ mod hashset_deserialization_test {
         assert!(payment_methods.is_err());
     }
 }
+
+#[cfg(test)]
+mod webhooks_settings_test {
+    use std::collections::HashSet;
+
+    use api_models::enums::EventClass;
+
+    use super::WebhooksSettings;
+
+    #[test]
+    fn test_should_persist_body_for_event_class() {
+        let settings = WebhooksSettings {
+            body_persistence_disabled_event_classes: HashSet::from([EventClass::Disputes]),
+            ..Default::default()
+        };
+
+        assert!(!settings.should_persist_body(EventClass::Disputes));
+        assert!(settings.should_persist_body(EventClass::Payments));
+        assert!(WebhooksSettings::default().should_persist_body(EventClass::Disputes));
+    }
+}
@@ -1,10 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use api_models::{
-    webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
+    webhook_events::{
+        OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+        DEFAULT_RESPONSE_HEADER_ALLOWLIST,
+    },
     webhooks,
 };
 use common_utils::{
+    crypto::SignMessage,
     ext_traits::{Encode, StringExt},
     request::RequestContent,
     type_name,
@@ -46,6 +50,9 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+/// The maximum size of the response body stored for an outgoing webhook, larger bodies are
+/// truncated before being stored.
+const OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_BYTES: usize = 64 * 1024;
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
@@ -78,6 +85,23 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         return Ok(());
     }
 
+    if !business_profile
+        .webhook_details
+        .as_ref()
+        .map_or(true, |webhook_details| {
+            webhook_details.is_event_class_enabled(event_class)
+        })
+    {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            ?event_class,
+            "Outgoing webhooks are disabled for the event class in the business profile; \
+             skipping outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -95,7 +119,28 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("Failed to construct outgoing webhook request content")?;
 
+    let stored_request_content = if state.conf.webhooks.redact_stored_pii {
+        request_content
+            .clone()
+            .redact_pii(&api_models::webhook_events::PiiRedactionRules::default())
+    } else {
+        request_content.clone()
+    };
+    let stored_request_content = if state.conf.webhooks.normalize_stored_headers {
+        stored_request_content.normalize_headers()
+    } else {
+        stored_request_content
+    };
+    let stored_request_content = if state.conf.webhooks.should_persist_body(event_class) {
+        stored_request_content
+    } else {
+        stored_request_content.omit_body()
+    };
+
     let event_metadata = storage::EventMetadata::foreign_from(&content);
+    let merchant_connector_id = get_merchant_connector_id_from_outgoing_webhook_content(&content);
+    let primary_object_updated_at =
+        get_primary_object_updated_at_from_outgoing_webhook_content(&content);
     let key_manager_state = &(&state).into();
     let new_event = domain::Event {
         event_id: event_id.clone(),
@@ -115,7 +160,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
                 key_manager_state,
                 type_name!(domain::Event),
                 CryptoOperation::Encrypt(
-                    request_content
+                    stored_request_content
                         .encode_to_string_of_json()
                         .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
                         .attach_printable("Failed to encode outgoing webhook request content")
@@ -142,6 +187,11 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        merchant_connector_id,
+        tags: None,
+        triggered_by: None,
+        ingested_at: None,
+        primary_object_updated_at,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -294,10 +344,22 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
-        process_tracker.clone(),
-    ) {
+    // Replays are delivered to the URL recorded in the request, rather than to the webhook URL
+    // configured for the business profile.
+    let webhook_url_result = match delivery_attempt {
+        enums::WebhookDeliveryAttempt::InitialAttempt
+        | enums::WebhookDeliveryAttempt::AutomaticRetry
+        | enums::WebhookDeliveryAttempt::ManualRetry => {
+            get_webhook_url_from_business_profile(&business_profile)
+        }
+        enums::WebhookDeliveryAttempt::ManualReplay => request_content
+            .delivery_url
+            .clone()
+            .map(ExposeInterface::expose)
+            .get_required_value("delivery_url")
+            .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured),
+    };
+    let webhook_url = match (webhook_url_result, process_tracker.clone()) {
         (Ok(webhook_url), _) => Ok(webhook_url),
         (Err(error), Some(process_tracker)) => {
             if !error
@@ -319,15 +381,19 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
+    let persist_response_body = state.conf.webhooks.should_persist_body(event.event_class);
     let event_id = event.event_id;
 
+    let http_method = request_content
+        .http_method
+        .unwrap_or(services::Method::Post);
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
     let request = services::RequestBuilder::new()
-        .method(services::Method::Post)
+        .method(http_method)
         .url(&webhook_url)
         .attach_default_headers()
         .headers(headers)
@@ -336,10 +402,12 @@ async fn trigger_webhook_to_merchant(
         ))
         .build();
 
+    let delivery_start_time = std::time::Instant::now();
     let response = state
         .api_client
         .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    let response_latency_ms = u32::try_from(delivery_start_time.elapsed().as_millis()).ok();
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         1,
@@ -356,6 +424,7 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     client_error,
+                    response_latency_ms,
                     delivery_attempt,
                     ScheduleWebhookRetry::NoSchedule,
                 )
@@ -369,6 +438,8 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    response_latency_ms,
+                    persist_response_body,
                 )
                 .await?;
 
@@ -414,6 +485,7 @@ async fn trigger_webhook_to_merchant(
                         &business_profile.merchant_id,
                         &event_id,
                         client_error,
+                        response_latency_ms,
                         delivery_attempt,
                         ScheduleWebhookRetry::WithProcessTracker(Box::new(process_tracker)),
                     )
@@ -427,6 +499,8 @@ async fn trigger_webhook_to_merchant(
                         &business_profile.merchant_id,
                         &event_id,
                         response,
+                        response_latency_ms,
+                        persist_response_body,
                     )
                     .await?;
 
@@ -460,7 +534,8 @@ async fn trigger_webhook_to_merchant(
                 }
             }
         }
-        enums::WebhookDeliveryAttempt::ManualRetry => match response {
+        enums::WebhookDeliveryAttempt::ManualRetry
+        | enums::WebhookDeliveryAttempt::ManualReplay => match response {
             Err(client_error) => {
                 api_client_error_handler(
                     state.clone(),
@@ -468,6 +543,7 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     client_error,
+                    response_latency_ms,
                     delivery_attempt,
                     ScheduleWebhookRetry::NoSchedule,
                 )
@@ -481,6 +557,8 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    response_latency_ms,
+                    persist_response_body,
                 )
                 .await?;
 
@@ -606,11 +684,9 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
+    let task = utils::OUTGOING_WEBHOOK_RETRY_TASK;
     let tag = ["OUTGOING_WEBHOOKS"];
-    let process_tracker_id = scheduler::utils::get_process_tracker_id(
-        runner,
-        task,
+    let process_tracker_id = utils::get_outgoing_webhook_retry_process_tracker_id(
         &event.event_id,
         &business_profile.merchant_id,
     );
@@ -655,6 +731,57 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Prepares the request recorded for an event to be replayed to `override_url`. The stored body is
+/// sent as is, while the signature header, when present, is recomputed over the body using the
+/// current payment response hash key of the business profile. Requests signed using the Stripe
+/// compatible scheme are sent with the signature that was recorded.
+pub(crate) fn get_replayed_outgoing_webhook_request(
+    business_profile: &domain::Profile,
+    request_content: OutgoingWebhookRequestContent,
+    override_url: Secret<String>,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    let mut headers = request_content.headers;
+
+    let is_signed = headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(crate::headers::X_WEBHOOK_SIGNATURE));
+    let mut signature_algorithm = request_content.signature_algorithm;
+    if is_signed {
+        let signature = business_profile
+            .payment_response_hash_key
+            .as_ref()
+            .map(|key| {
+                common_utils::crypto::HmacSha512::sign_message(
+                    &common_utils::crypto::HmacSha512,
+                    key.as_bytes(),
+                    request_content.body.peek().as_bytes(),
+                )
+            })
+            .transpose()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable("Failed to sign the replayed webhook")?
+            .map(hex::encode);
+
+        headers.retain(|(name, _)| !name.eq_ignore_ascii_case(crate::headers::X_WEBHOOK_SIGNATURE));
+        signature_algorithm = signature
+            .is_some()
+            .then_some(enums::WebhookSignatureAlgorithm::HmacSha512);
+        if let Some(signature) = signature {
+            headers.push((
+                crate::headers::X_WEBHOOK_SIGNATURE.to_string(),
+                Secret::new(signature),
+            ));
+        }
+    }
+
+    Ok(OutgoingWebhookRequestContent {
+        headers,
+        delivery_url: Some(override_url),
+        signature_algorithm,
+        ..request_content
+    })
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
@@ -698,18 +825,41 @@ pub(crate) fn get_outgoing_webhook_request(
         };
         let outgoing_webhooks_signature = transformed_outgoing_webhook
             .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+        let signature_algorithm = outgoing_webhooks_signature
+            .signature
+            .is_some()
+            .then_some(WebhookType::SIGNATURE_ALGORITHM);
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
-        Ok(OutgoingWebhookRequestContent {
+        let mut request_content = OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
                 .into_iter()
                 .map(|(name, value)| (name, Secret::new(value.into_inner())))
                 .collect(),
-        })
+            delivery_url: get_webhook_url_from_business_profile(business_profile)
+                .ok()
+                .map(Secret::new),
+            http_method: Some(services::Method::Post),
+            is_truncated: false,
+            idempotency_key: None,
+            content_encoding: None,
+            redacted_header_count: 0,
+            payload_version: None,
+            body_omitted: false,
+            body_redacted: false,
+            signature_algorithm,
+        };
+        request_content.idempotency_key = request_content.get_idempotency_key();
+        request_content.payload_version = request_content.get_payload_version();
+        request_content.content_encoding = request_content
+            .header("content-encoding")
+            .map(|content_encoding| content_encoding.peek().to_owned());
+
+        Ok(request_content)
     }
 
     match merchant_context
@@ -739,14 +889,21 @@ async fn update_event_if_client_error(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     error_message: String,
+    error_category: enums::WebhookDeliveryErrorCategory,
+    response_latency_ms: Option<u32>,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let is_webhook_notified = false;
     let key_manager_state = &(&state).into();
     let response_to_store = OutgoingWebhookResponseContent {
         body: None,
+        body_size_bytes: None,
         headers: None,
         status_code: None,
         error_message: Some(error_message),
+        response_latency_ms,
+        error_category: Some(error_category),
+        is_truncated: false,
+        redacted_header_count: 0,
     };
 
     let event_update = domain::EventUpdate::UpdateResponse {
@@ -792,6 +949,7 @@ async fn api_client_error_handler(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     client_error: error_stack::Report<errors::ApiClientError>,
+    response_latency_ms: Option<u32>,
     delivery_attempt: enums::WebhookDeliveryAttempt,
     schedule_webhook_retry: ScheduleWebhookRetry,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
@@ -803,6 +961,8 @@ async fn api_client_error_handler(
         merchant_id,
         event_id,
         "Unable to send request to merchant server".to_string(),
+        enums::WebhookDeliveryErrorCategory::foreign_from(&client_error),
+        response_latency_ms,
     )
     .await?;
 
@@ -833,6 +993,8 @@ async fn update_event_in_storage(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     response: reqwest::Response,
+    response_latency_ms: Option<u32>,
+    persist_response_body: bool,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let status_code = response.status();
     let is_webhook_notified = status_code.is_success();
@@ -856,20 +1018,40 @@ async fn update_event_in_storage(
             )
         })
         .collect::<Vec<_>>();
-    let response_body = response
+    let content_length = response.content_length();
+    let (response_body, body_size_bytes) = response
         .text()
         .await
-        .map(Secret::from)
+        .map(|body| {
+            let body_size_bytes = body.len();
+            (Secret::from(body), Some(body_size_bytes))
+        })
         .unwrap_or_else(|error| {
             logger::warn!("Response contains non-UTF-8 characters: {error:?}");
-            Secret::from(String::from("Non-UTF-8 response body"))
+            (
+                Secret::from(String::from("Non-UTF-8 response body")),
+                content_length.and_then(|content_length| usize::try_from(content_length).ok()),
+            )
         });
     let response_to_store = OutgoingWebhookResponseContent {
-        body: Some(response_body),
+        body: persist_response_body.then_some(response_body),
+        body_size_bytes,
         headers: Some(response_headers),
         status_code: Some(status_code.as_u16()),
         error_message: None,
-    };
+        response_latency_ms,
+        error_category: (!status_code.is_success())
+            .then_some(enums::WebhookDeliveryErrorCategory::Non2xxStatus),
+        is_truncated: false,
+        redacted_header_count: 0,
+    }
+    .retain_headers(
+        &DEFAULT_RESPONSE_HEADER_ALLOWLIST
+            .iter()
+            .map(|header| header.to_string())
+            .collect::<HashSet<_>>(),
+    )
+    .truncate(OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_BYTES);
 
     let event_update = domain::EventUpdate::UpdateResponse {
         is_webhook_notified,
@@ -1003,20 +1185,63 @@ async fn error_response_handler(
     Err(error)
 }
 
+impl ForeignFrom<&error_stack::Report<errors::ApiClientError>>
+    for enums::WebhookDeliveryErrorCategory
+{
+    fn foreign_from(error: &error_stack::Report<errors::ApiClientError>) -> Self {
+        match error.current_context() {
+            errors::ApiClientError::RequestTimeoutReceived
+            | errors::ApiClientError::GatewayTimeoutReceived => Self::Timeout,
+            errors::ApiClientError::RequestNotSent(_) => error
+                .downcast_ref::<reqwest::Error>()
+                .map_or(Self::Other, Self::foreign_from),
+            _ => Self::Other,
+        }
+    }
+}
+
+impl ForeignFrom<&reqwest::Error> for enums::WebhookDeliveryErrorCategory {
+    fn foreign_from(error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            return Self::Timeout;
+        }
+        if !error.is_connect() {
+            return Self::Other;
+        }
+
+        let io_error =
+            std::iter::successors(std::error::Error::source(error), |error| error.source())
+                .find_map(|error| error.downcast_ref::<std::io::Error>());
+        match io_error.map(|io_error| (io_error.kind(), io_error.raw_os_error())) {
+            Some((std::io::ErrorKind::ConnectionRefused, _)) => Self::ConnectionRefused,
+            Some((std::io::ErrorKind::TimedOut, _)) => Self::Timeout,
+            // Failures of the TLS handshake are reported by the TLS stream as invalid data
+            Some((std::io::ErrorKind::InvalidData, _)) => Self::TlsError,
+            // Unlike the failures of the socket calls, failures to resolve the host carry no error
+            // code of the operating system
+            Some((_, None)) => Self::DnsResolution,
+            Some((_, Some(_))) | None => Self::Other,
+        }
+    }
+}
+
 impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
     fn foreign_from(content: &api::OutgoingWebhookContent) -> Self {
         match content {
             webhooks::OutgoingWebhookContent::PaymentDetails(payments_response) => Self::Payment {
                 payment_id: payments_response.payment_id.clone(),
+                connector: payments_response.connector.clone(),
             },
             webhooks::OutgoingWebhookContent::RefundDetails(refund_response) => Self::Refund {
                 payment_id: refund_response.payment_id.clone(),
                 refund_id: refund_response.refund_id.clone(),
+                connector: Some(refund_response.connector.clone()),
             },
             webhooks::OutgoingWebhookContent::DisputeDetails(dispute_response) => Self::Dispute {
                 payment_id: dispute_response.payment_id.clone(),
                 attempt_id: dispute_response.attempt_id.clone(),
                 dispute_id: dispute_response.dispute_id.clone(),
+                connector: Some(dispute_response.connector.clone()),
             },
             webhooks::OutgoingWebhookContent::MandateDetails(mandate_response) => Self::Mandate {
                 payment_method_id: mandate_response.payment_method_id.clone(),
@@ -1025,28 +1250,74 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             #[cfg(feature = "payouts")]
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
+                connector: payout_response.connector.clone(),
             },
         }
     }
 }
 
+fn get_merchant_connector_id_from_outgoing_webhook_content(
+    content: &api::OutgoingWebhookContent,
+) -> Option<common_utils::id_type::MerchantConnectorAccountId> {
+    match content {
+        webhooks::OutgoingWebhookContent::PaymentDetails(payments_response) => {
+            payments_response.merchant_connector_id.clone()
+        }
+        webhooks::OutgoingWebhookContent::RefundDetails(refund_response) => {
+            refund_response.merchant_connector_id.clone()
+        }
+        webhooks::OutgoingWebhookContent::DisputeDetails(dispute_response) => {
+            dispute_response.merchant_connector_id.clone()
+        }
+        webhooks::OutgoingWebhookContent::MandateDetails(_) => None,
+        #[cfg(feature = "payouts")]
+        webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => {
+            payout_response.merchant_connector_id.clone()
+        }
+    }
+}
+
+/// Returns the time at which the object of the webhook was last changed, for objects which record
+/// their change time.
+fn get_primary_object_updated_at_from_outgoing_webhook_content(
+    content: &api::OutgoingWebhookContent,
+) -> Option<time::PrimitiveDateTime> {
+    match content {
+        webhooks::OutgoingWebhookContent::PaymentDetails(payments_response) => {
+            payments_response.updated
+        }
+        webhooks::OutgoingWebhookContent::RefundDetails(refund_response) => {
+            refund_response.updated_at
+        }
+        webhooks::OutgoingWebhookContent::DisputeDetails(dispute_response) => {
+            dispute_response.connector_updated_at
+        }
+        webhooks::OutgoingWebhookContent::MandateDetails(_) => None,
+        #[cfg(feature = "payouts")]
+        webhooks::OutgoingWebhookContent::PayoutDetails(_) => None,
+    }
+}
+
 fn get_outgoing_webhook_event_content_from_event_metadata(
     event_metadata: Option<storage::EventMetadata>,
 ) -> Option<OutgoingWebhookEventContent> {
     event_metadata.map(|metadata| match metadata {
-        diesel_models::EventMetadata::Payment { payment_id } => {
+        diesel_models::EventMetadata::Payment { payment_id, .. } => {
             OutgoingWebhookEventContent::Payment {
                 payment_id,
                 content: serde_json::Value::Null,
             }
         }
-        diesel_models::EventMetadata::Payout { payout_id } => OutgoingWebhookEventContent::Payout {
-            payout_id,
-            content: serde_json::Value::Null,
-        },
+        diesel_models::EventMetadata::Payout { payout_id, .. } => {
+            OutgoingWebhookEventContent::Payout {
+                payout_id,
+                content: serde_json::Value::Null,
+            }
+        }
         diesel_models::EventMetadata::Refund {
             payment_id,
             refund_id,
+            ..
         } => OutgoingWebhookEventContent::Refund {
             payment_id,
             refund_id,
@@ -1056,6 +1327,7 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             payment_id,
             attempt_id,
             dispute_id,
+            ..
         } => OutgoingWebhookEventContent::Dispute {
             payment_id,
             attempt_id,
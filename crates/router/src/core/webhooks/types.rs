@@ -14,6 +14,9 @@ pub struct OutgoingWebhookPayloadWithSignature {
 pub trait OutgoingWebhookType:
     Serialize + From<webhooks::OutgoingWebhook> + Sync + Send + std::fmt::Debug + 'static
 {
+    /// The algorithm used to sign webhooks of this type.
+    const SIGNATURE_ALGORITHM: enums::WebhookSignatureAlgorithm;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -23,6 +26,9 @@ pub trait OutgoingWebhookType:
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
+    const SIGNATURE_ALGORITHM: enums::WebhookSignatureAlgorithm =
+        enums::WebhookSignatureAlgorithm::HmacSha512;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -148,12 +148,31 @@ pub(crate) fn get_idempotent_event_id(
     let common_prefix = format!("{primary_object_id}_{event_type}");
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
-        WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
+        WebhookDeliveryAttempt::AutomaticRetry
+        | WebhookDeliveryAttempt::ManualRetry
+        | WebhookDeliveryAttempt::ManualReplay => {
             common_utils::generate_id(EVENT_ID_SUFFIX_LENGTH, &common_prefix)
         }
     }
 }
 
+pub(crate) const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
+/// Returns the identifier of the process tracker task scheduling the automatic retries of the
+/// delivery attempts sharing the specified initial delivery attempt.
+#[inline]
+pub(crate) fn get_outgoing_webhook_retry_process_tracker_id(
+    initial_attempt_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        types::storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        initial_attempt_id,
+        merchant_id,
+    )
+}
+
 #[inline]
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
@@ -261,3 +280,66 @@ where
             .attach_printable("Error while deleting redis key"),
     }
 }
+
+const WEBHOOK_RETRY_IDEMPOTENCY_PREFIX: &str = "WEBHOOK_RETRY_IDEMPOTENCY";
+
+/// Claims the idempotency key of a manual retry of the specified event for
+/// [`api_models::webhook_events::RETRY_IDEMPOTENCY_WINDOW_SECONDS`]. Returns `false` if a retry of
+/// the event with the same key was already accepted within the window.
+pub(super) async fn claim_retry_idempotency_key(
+    redis_conn: &redis::RedisConnectionPool,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+    idempotency_key: &str,
+) -> CustomResult<bool, redis::errors::RedisError> {
+    let redis_key = get_retry_idempotency_redis_key(merchant_id, event_id, idempotency_key);
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &redis_key.as_str().into(),
+            event_id.to_owned(),
+            Some(api_models::webhook_events::RETRY_IDEMPOTENCY_WINDOW_SECONDS),
+        )
+        .await?;
+
+    match reply {
+        redis::SetnxReply::KeySet => {
+            logger::info!("Retry idempotency key claimed for {redis_key}");
+            Ok(true)
+        }
+        redis::SetnxReply::KeyNotSet => {
+            logger::info!("Retry idempotency key already claimed for {redis_key}");
+            Ok(false)
+        }
+    }
+}
+
+/// Releases the idempotency key of a manual retry of the specified event, so that the retry can be
+/// attempted again with the same key when creating it failed.
+pub(super) async fn release_retry_idempotency_key(
+    redis_conn: &redis::RedisConnectionPool,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+    idempotency_key: &str,
+) -> CustomResult<(), redis::errors::RedisError> {
+    let redis_key = get_retry_idempotency_redis_key(merchant_id, event_id, idempotency_key);
+
+    redis_conn.delete_key(&redis_key.as_str().into()).await?;
+    logger::info!("Retry idempotency key released for {redis_key}");
+
+    Ok(())
+}
+
+fn get_retry_idempotency_redis_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+    idempotency_key: &str,
+) -> String {
+    format!(
+        "{}_{}_{}_{}",
+        WEBHOOK_RETRY_IDEMPOTENCY_PREFIX,
+        merchant_id.get_string_repr(),
+        event_id,
+        idempotency_key
+    )
+}
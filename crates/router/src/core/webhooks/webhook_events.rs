@@ -1,9 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use common_utils::{self, errors::CustomResult, fp_utils};
 use error_stack::ResultExt;
 use masking::PeekInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
@@ -13,8 +14,12 @@ use crate::{
     utils::{OptionExt, StringExt},
 };
 
-const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const BULK_DELIVERY_RETRY_MAX_EVENTS: usize = 100;
+const INITIAL_DELIVERY_ATTEMPTS_EXPORT_PAGE_SIZE: u16 = 100;
+const INITIAL_DELIVERY_ATTEMPTS_EXPORT_MAX_EVENTS: usize = 10_000;
+const CONTENT_FILTERED_EVENTS_PAGE_SIZE: u16 = 100;
+const ENDPOINT_HEALTH_MAX_DELIVERY_ATTEMPTS: u16 = 10_000;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -28,29 +33,110 @@ pub async fn list_initial_delivery_attempts(
     merchant_id: common_utils::id_type::MerchantId,
     api_constraints: api::webhook_events::EventListConstraints,
 ) -> RouterResponse<api::webhook_events::TotalEventsResponse> {
+    let dropped_filters = api_constraints.dropped_filters.clone();
+    let now = common_utils::date_time::now();
+    let api_constraints = resolve_time_range(api_constraints, now)?;
+
+    let (events, events_counter) =
+        list_initial_events(&state, &merchant_id, api_constraints.clone(), now).await?;
+    let outcome_context = get_webhook_outcome_context(&state, &merchant_id, now).await;
+    let mut events = events
+        .into_iter()
+        .map(|event| {
+            api::webhook_events::EventListItemResponse::foreign_try_from((event, outcome_context))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    mark_disabled_event_classes(&state, &merchant_id, events.iter_mut().collect()).await?;
+
+    let include_breakdown = api_constraints.include_breakdown.unwrap_or(false);
+    let response_mode = api_constraints.response_mode.unwrap_or_default();
+    let count_mode = api_constraints.count_mode.unwrap_or_default();
+    let limit = api_constraints.get_limit();
+    let (total_count, class_breakdown) = events_counter
+        .count(&state, count_mode, include_breakdown)
+        .await?;
+
+    let response = match total_count {
+        Some(total_count) => {
+            let response = api::webhook_events::TotalEventsResponse::new(
+                merchant_id,
+                total_count,
+                events,
+                api_constraints.offset,
+            );
+            if count_mode == common_enums::CountMode::Estimated
+                && !events_counter.is_counted_as_listed()
+            {
+                response.with_estimated_total_count()
+            } else {
+                response
+            }
+        }
+        None => api::webhook_events::TotalEventsResponse::without_total_count(
+            merchant_id,
+            events,
+            api_constraints.offset,
+            limit,
+        ),
+    };
+
+    Ok(ApplicationResponse::Json(
+        response
+            .with_class_breakdown(class_breakdown)
+            .with_dropped_filters(dropped_filters)
+            .with_response_mode(response_mode),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_initial_delivery_attempts_for_profile(
+    state: SessionState,
+    request: api::webhook_events::ProfileEventListRequestInternal,
+) -> RouterResponse<api::webhook_events::TotalEventsResponse> {
+    let request = request.into_event_list_request().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })?;
+
+    list_initial_delivery_attempts(state, request.merchant_id, request.constraints).await
+}
+
+/// Lists the initial delivery attempts matching the specified constraints, including the filters
+/// that can only be applied on the decrypted events. The way the events are to be counted is
+/// returned along with the events, so that the events are counted using the same filters they
+/// were listed with.
+async fn list_initial_events(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    api_constraints: api::webhook_events::EventListConstraints,
+    now: time::PrimitiveDateTime,
+) -> errors::RouterResult<(Vec<domain::Event>, InitialEventsCounter)> {
     let profile_id = api_constraints.profile_id.clone();
-    let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
-        api_constraints.clone(),
-    )?;
+    let constraints =
+        api::webhook_events::EventListConstraintsInternal::foreign_try_from(api_constraints)?;
 
     let store = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+    let key_manager_state = &state.into();
     let (account, key_store) =
-        get_account_and_key_store(state.clone(), merchant_id.clone(), profile_id.clone()).await?;
+        get_account_and_key_store(state.clone(), merchant_id.clone(), profile_id).await?;
 
-    let now = common_utils::date_time::now();
-    let events_list_begin_time =
-        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
-
-    let mut updated_event_types: HashSet<common_enums::EventType> = HashSet::new();
+    let mut content_filters = EventContentFilters::default();
 
     let events = match constraints {
-        api_models::webhook_events::EventListConstraintsInternal::ObjectIdFilter { object_id } => {
+        api_models::webhook_events::EventListConstraintsInternal::ObjectIdFilter {
+            object_id,
+            event_classes,
+            sort_by,
+        } => {
+            content_filters.event_classes = event_classes;
+
             match account {
                 MerchantAccountOrProfile::MerchantAccount(merchant_account) => store
                 .list_initial_events_by_merchant_id_primary_object_id(key_manager_state,
                    merchant_account.get_id(),
                     &object_id,
+                    sort_by,
                     &key_store,
                 )
                 .await,
@@ -58,125 +144,838 @@ pub async fn list_initial_delivery_attempts(
                 .list_initial_events_by_profile_id_primary_object_id(key_manager_state,
                     business_profile.get_id(),
                     &object_id,
+                    sort_by,
+                    &key_store,
+                )
+                .await,
+            }
+        }
+        api_models::webhook_events::EventListConstraintsInternal::ObjectIdsFilter {
+            object_ids,
+            sort_by,
+        } => {
+            match account {
+                MerchantAccountOrProfile::MerchantAccount(merchant_account) => store
+                .list_initial_events_by_merchant_id_primary_object_ids(key_manager_state,
+                   merchant_account.get_id(),
+                    &object_ids,
+                    sort_by,
+                    &key_store,
+                )
+                .await,
+                MerchantAccountOrProfile::Profile(business_profile) => store
+                .list_initial_events_by_profile_id_primary_object_ids(key_manager_state,
+                    business_profile.get_id(),
+                    &object_ids,
+                    sort_by,
                     &key_store,
                 )
                 .await,
             }
         }
+        api_models::webhook_events::EventListConstraintsInternal::ObjectIdContainsFilter {
+            object_id_contains,
+            created_after,
+            created_before,
+            limit,
+            offset,
+            sort_by,
+        } => {
+            let (created_after, created_before) =
+                get_created_at_range(created_after, created_before, now)?;
+            let offset = offset.filter(|offset| *offset > 0);
+
+            let (events, profile_id) = match account {
+                MerchantAccountOrProfile::MerchantAccount(merchant_account) => (
+                    store
+                        .list_initial_events_by_merchant_id_primary_object_id_contains(
+                            key_manager_state,
+                            merchant_account.get_id(),
+                            &object_id_contains,
+                            created_after,
+                            created_before,
+                            limit,
+                            offset,
+                            sort_by,
+                            &key_store,
+                        )
+                        .await,
+                    None,
+                ),
+                MerchantAccountOrProfile::Profile(business_profile) => (
+                    store
+                        .list_initial_events_by_profile_id_primary_object_id_contains(
+                            key_manager_state,
+                            business_profile.get_id(),
+                            &object_id_contains,
+                            created_after,
+                            created_before,
+                            limit,
+                            offset,
+                            sort_by,
+                            &key_store,
+                        )
+                        .await,
+                    Some(business_profile.get_id().to_owned()),
+                ),
+            };
+            let events = events
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list events with specified constraints")?;
+
+            return Ok((
+                events,
+                InitialEventsCounter::ObjectIdContains {
+                    merchant_id: merchant_id.clone(),
+                    profile_id,
+                    object_id_contains,
+                    created_after,
+                    created_before,
+                },
+            ));
+        }
+        api_models::webhook_events::EventListConstraintsInternal::InitialAttemptIdFilter {
+            initial_attempt_id,
+            sort_by,
+        } => {
+            list_events_by_initial_attempt_id(
+                state,
+                &account,
+                &key_store,
+                &initial_attempt_id,
+                sort_by,
+            )
+            .await
+        }
+        api_models::webhook_events::EventListConstraintsInternal::EventIdFilter { event_id } => {
+            find_event_by_event_id(state, &account, &key_store, &event_id).await
+        }
+        api_models::webhook_events::EventListConstraintsInternal::OrphanedFilter {
+            created_after,
+            created_before,
+            limit,
+            starting_after,
+            sort_by,
+        } => {
+            let created_at_range = get_created_at_range(created_after, created_before, now)?;
+            let starting_after = get_pagination_cursor_event(
+                state,
+                merchant_id,
+                &key_store,
+                starting_after,
+                "starting_after",
+            )
+            .await?;
+            let events = list_orphaned_events(
+                state,
+                &account,
+                &key_store,
+                created_at_range,
+                limit,
+                starting_after,
+                sort_by,
+            )
+            .await?;
+
+            return Ok((
+                events,
+                InitialEventsCounter::Orphaned {
+                    account,
+                    key_store,
+                    created_at_range,
+                },
+            ));
+        }
         api_models::webhook_events::EventListConstraintsInternal::GenericFilter {
             created_after,
             created_before,
             limit,
             offset,
+            starting_after,
+            ending_before,
             event_classes,
             event_types,
-            is_delivered
+            delivery_states,
+            merchant_connector_id,
+            connector,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            profile_ids,
+            response_status_code_gte,
+            response_status_code_lte,
+            latency_ms_gte,
+            sla_breached,
+            response_body_size_gte,
+            error_category,
+            has_error,
+            error_message_contains,
+            response_received,
+            response_content_type,
+            payload_version,
+            expiring_before,
+            ingested_after,
+            ingested_before,
+            event_delay_ms_gte,
+            created_hour_of_day,
+            created_day_of_week,
+            tags,
+            triggered_by,
+            sort_by,
+            default_window_applied,
         } => {
-            let limit = match limit {
-                Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
-                Some(limit) if limit > INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Err(
-                    errors::ApiErrorResponse::InvalidRequestData{
-                        message: format!("`limit` must be a number less than {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT}")
-                    }
-                ),
-                _  => Ok(Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT)),
-            }?;
+            if default_window_applied {
+                logger::info!(
+                    ?created_after,
+                    "Listing events within the default time window, since no time bounds were specified"
+                );
+            }
+            let (is_delivered, delivery_states) = get_delivery_state_filters(delivery_states);
+            let content_filters = EventContentFilters {
+                response_status_code_gte,
+                response_status_code_lte,
+                latency_ms_gte,
+                sla_breached,
+                response_body_size_gte,
+                error_category,
+                has_error,
+                error_message_contains,
+                response_received,
+                response_content_type,
+                payload_version,
+                delivery_states,
+                event_classes: None,
+                connector,
+                expiring_before,
+                ingested_after,
+                ingested_before,
+                event_delay_ms_gte,
+                created_hour_of_day,
+                created_day_of_week,
+                tags,
+                triggered_by,
+            };
+
+            // The limit is defaulted and clamped to `MAX_EVENT_LIST_LIMIT` when converting the
+            // constraints, so it never exceeds the maximum here.
+            let limit =
+                limit.unwrap_or(i64::from(api_models::webhook_events::MAX_EVENT_LIST_LIMIT));
             let offset = match offset {
                 Some(offset) if offset > 0 => Some(offset),
                 _ => None,
             };
 
-            let event_classes = event_classes.unwrap_or(HashSet::new());
-            updated_event_types = event_types.unwrap_or(HashSet::new());
-            if !event_classes.is_empty() {
-                updated_event_types = finalize_event_types(event_classes, updated_event_types).await?;
-            }
+            let starting_after = get_pagination_cursor_event(
+                state,
+                merchant_id,
+                &key_store,
+                starting_after,
+                "starting_after",
+            )
+            .await?;
+            let ending_before = get_pagination_cursor_event(
+                state,
+                merchant_id,
+                &key_store,
+                ending_before,
+                "ending_before",
+            )
+            .await?;
 
-            fp_utils::when(!created_after.zip(created_before).map(|(created_after,created_before)| created_after<=created_before).unwrap_or(true), || {
-                Err(errors::ApiErrorResponse::InvalidRequestData { message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string() })
-            })?;
+            let event_types = get_event_types(event_classes, event_types).await?;
 
-            let created_after = match created_after {
-                Some(created_after) => {
-                    if created_after < events_list_begin_time {
-                        Err(errors::ApiErrorResponse::InvalidRequestData { message: format!("`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days.") })
-                    }else{
-                        Ok(created_after)
-                    }
-                },
-                None => Ok(events_list_begin_time)
-            }?;
+            let (created_after, created_before) =
+                get_created_at_range(created_after, created_before, now)?;
 
-            let created_before = match created_before{
-                Some(created_before) => {
-                    if created_before < events_list_begin_time{
-                        Err(errors::ApiErrorResponse::InvalidRequestData { message: format!("`created_before` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days.") })
-                    }
-                    else{
-                        Ok(created_before)
+            let events_query = InitialEventsQuery {
+                merchant_id: merchant_id.clone(),
+                profile_id: match account {
+                    MerchantAccountOrProfile::MerchantAccount(_) => None,
+                    MerchantAccountOrProfile::Profile(business_profile) => {
+                        Some(business_profile.get_id().to_owned())
                     }
                 },
-                None => Ok(now)
-            }?;
+                key_store,
+                created_after,
+                created_before,
+                event_types,
+                is_delivered,
+                merchant_connector_id,
+                delivery_attempts,
+                only_initial_attempts,
+                never_delivered,
+                min_attempts,
+                profile_ids,
+                sort_by,
+                content_filters,
+            };
+            let events = events_query
+                .list_events(state, limit, offset, starting_after, ending_before)
+                .await?;
 
-            match account {
-                MerchantAccountOrProfile::MerchantAccount(merchant_account) => store
-                .list_initial_events_by_merchant_id_constraints(key_manager_state,
-                   merchant_account.get_id(),
+            return Ok((events, InitialEventsCounter::Query(events_query)));
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list events with specified constraints")?;
+
+    let events = events
+        .into_iter()
+        .filter(|event| content_filters.is_matching(event))
+        .collect::<Vec<_>>();
+    let events_counter = InitialEventsCounter::from_listed_events(&events);
+
+    Ok((events, events_counter))
+}
+
+#[instrument(skip(state))]
+pub async fn export_initial_delivery_attempts(
+    state: SessionState,
+    request: api::webhook_events::EventListExportRequestInternal,
+) -> RouterResponse<serde_json::Value> {
+    request.validate().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })?;
+
+    let api::webhook_events::EventListExportRequestInternal {
+        merchant_id,
+        constraints: api_constraints,
+        fields,
+    } = request;
+    let now = common_utils::date_time::now();
+
+    // Pagination and object specific constraints select a single page of events, all the events
+    // matching the remaining constraints are exported otherwise.
+    let is_single_page = api_constraints.limit.is_some()
+        || api_constraints.offset.is_some()
+        || api_constraints.starting_after.is_some()
+        || api_constraints.ending_before.is_some()
+        || api_constraints.object_id.is_some()
+        || api_constraints.object_ids.is_some()
+        || api_constraints.initial_attempt_id.is_some()
+        || api_constraints.event_id.is_some();
+
+    let is_object_id_contains = api_constraints.object_id_contains.is_some();
+    let mut page_constraints = api_constraints.clone();
+    if !is_single_page {
+        page_constraints.limit = Some(INITIAL_DELIVERY_ATTEMPTS_EXPORT_PAGE_SIZE);
+    }
+
+    let outcome_context = get_webhook_outcome_context(&state, &merchant_id, now).await;
+    let mut exported_events = Vec::new();
+    loop {
+        let (events, _) =
+            list_initial_events(&state, &merchant_id, page_constraints.clone(), now).await?;
+        let is_last_page = events.len() < usize::from(INITIAL_DELIVERY_ATTEMPTS_EXPORT_PAGE_SIZE);
+        let last_event_id = events.last().map(|event| event.event_id.clone());
+
+        for event in events {
+            exported_events.push(
+                api::webhook_events::EventListItemResponse::foreign_try_from((
+                    event,
+                    outcome_context,
+                ))?,
+            );
+        }
+
+        // The export is built in memory, so the number of exported events is bounded
+        fp_utils::when(
+            exported_events.len() > INITIAL_DELIVERY_ATTEMPTS_EXPORT_MAX_EVENTS,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "At most {INITIAL_DELIVERY_ATTEMPTS_EXPORT_MAX_EVENTS} events can be exported at once, narrow down the constraints or export the events a page at a time using `limit` and `starting_after`"
+                    ),
+                })
+            },
+        )?;
+
+        match last_event_id {
+            // The events matching a partial object ID cannot be paginated using cursors
+            Some(_) if !is_single_page && !is_last_page && is_object_id_contains => {
+                page_constraints.offset = Some(
+                    page_constraints
+                        .offset
+                        .unwrap_or(0)
+                        .saturating_add(INITIAL_DELIVERY_ATTEMPTS_EXPORT_PAGE_SIZE),
+                );
+            }
+            Some(last_event_id) if !is_single_page && !is_last_page => {
+                page_constraints.starting_after = Some(last_event_id);
+            }
+            _ => break,
+        }
+    }
+
+    mark_disabled_event_classes(&state, &merchant_id, exported_events.iter_mut().collect()).await?;
+
+    // The manifest is exported as the first line, ahead of the events it describes
+    let (_, export) = api_models::webhook_events::export::events_to_ndjson_with_manifest(
+        merchant_id,
+        api_constraints,
+        &exported_events,
+        fields.as_deref(),
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize events into NDJSON")?;
+
+    let content_type = "application/x-ndjson"
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse NDJSON content type")?;
+
+    Ok(ApplicationResponse::FileData((
+        export.into_bytes(),
+        content_type,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn sync_events(
+    state: SessionState,
+    request: api::webhook_events::EventSyncRequestInternal,
+) -> RouterResponse<api::webhook_events::EventSyncResponse> {
+    let now = common_utils::date_time::now();
+    let (_, key_store) =
+        get_account_and_key_store(state.clone(), request.merchant_id.clone(), None).await?;
+
+    let since_event = get_pagination_cursor_event(
+        &state,
+        &request.merchant_id,
+        &key_store,
+        request.since_event_id.clone(),
+        "since_event_id",
+    )
+    .await?;
+    let events_list_begin_time = get_events_list_begin_time(now);
+    // Events older than the listing window cannot be synced, so resuming from a cursor outside the
+    // window would silently skip the events created between the cursor and the window.
+    fp_utils::when(
+        since_event
+            .as_ref()
+            .is_some_and(|(created_at, _)| *created_at < events_list_begin_time),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The `since_event_id` cursor has expired, as events older than {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days cannot be synced. Restart the sync without `since_event_id`."
+                ),
+            })
+        },
+    )?;
+    let created_after = since_event
+        .as_ref()
+        .map_or(events_list_begin_time, |(created_at, _)| *created_at);
+
+    let outcome_context = get_webhook_outcome_context(&state, &request.merchant_id, now).await;
+
+    // Events are listed in the ascending `(created_at, event_id)` order strictly after the event
+    // synced last, so that consecutive syncs neither skip nor repeat events.
+    let events_query = InitialEventsQuery {
+        merchant_id: request.merchant_id.clone(),
+        profile_id: None,
+        key_store,
+        created_after,
+        created_before: now,
+        event_types: HashSet::new(),
+        is_delivered: None,
+        merchant_connector_id: None,
+        delivery_attempts: None,
+        only_initial_attempts: true,
+        never_delivered: false,
+        min_attempts: None,
+        profile_ids: None,
+        sort_by: common_enums::EventSortBy::CreatedAsc,
+        content_filters: EventContentFilters::default(),
+    };
+    let mut events = events_query
+        .list_stored_events(
+            &state,
+            created_after,
+            Some(i64::from(request.limit)),
+            None,
+            since_event,
+            None,
+        )
+        .await?
+        .into_iter()
+        .map(|event| {
+            api::webhook_events::EventListItemResponse::foreign_try_from((event, outcome_context))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    mark_disabled_event_classes(&state, &request.merchant_id, events.iter_mut().collect()).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventSyncResponse::new(events, request.since_event_id),
+    ))
+}
+
+/// Obtains the context the outcome of the delivery of the events of the merchant is determined in
+/// as of `now`, with the window of automatic retries derived from the configured retry schedule.
+async fn get_webhook_outcome_context(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    now: time::PrimitiveDateTime,
+) -> api::webhook_events::WebhookOutcomeContext {
+    let mapping = crate::workflows::outgoing_webhook_retry::get_webhook_delivery_retry_mapping(
+        state.store.as_ref(),
+    )
+    .await;
+
+    api::webhook_events::WebhookOutcomeContext {
+        now,
+        automatic_retry_window: scheduler::utils::get_outgoing_webhook_retry_window(
+            mapping,
+            merchant_id,
+        ),
+    }
+}
+
+/// Marks the events whose class is disabled for their business profile, as per the webhook
+/// configuration of the business profiles the events belong to.
+async fn mark_disabled_event_classes(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    events: Vec<&mut api::webhook_events::EventListItemResponse>,
+) -> errors::RouterResult<()> {
+    let profile_ids = events
+        .iter()
+        .map(|event| event.profile_id.clone())
+        .collect::<HashSet<_>>();
+    let disabled_event_classes =
+        get_disabled_event_classes(state, merchant_id, &profile_ids).await?;
+    set_class_enabled(events, &disabled_event_classes);
+
+    Ok(())
+}
+
+/// Returns the event classes disabled in the webhook configuration of each of the specified
+/// business profiles. The business profiles of the merchant are listed at once, instead of being
+/// fetched one at a time.
+async fn get_disabled_event_classes(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_ids: &HashSet<common_utils::id_type::ProfileId>,
+) -> errors::RouterResult<
+    HashMap<common_utils::id_type::ProfileId, HashSet<common_enums::EventClass>>,
+> {
+    if profile_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profiles = store
+        .list_profile_by_merchant_id(key_manager_state, &key_store, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list business profiles of merchant")?;
+
+    Ok(get_profiles_disabled_event_classes(
+        business_profiles
+            .iter()
+            .filter(|business_profile| profile_ids.contains(business_profile.get_id())),
+    ))
+}
+
+/// Returns the event classes disabled in the webhook configuration of each of the specified
+/// business profiles, omitting the business profiles with no disabled event classes.
+fn get_profiles_disabled_event_classes<'a>(
+    business_profiles: impl IntoIterator<Item = &'a domain::Profile>,
+) -> HashMap<common_utils::id_type::ProfileId, HashSet<common_enums::EventClass>> {
+    business_profiles
+        .into_iter()
+        .filter_map(|business_profile| {
+            business_profile
+                .webhook_details
+                .as_ref()
+                .and_then(|webhook_details| webhook_details.disabled_event_classes.clone())
+                .map(|disabled_event_classes| {
+                    (business_profile.get_id().to_owned(), disabled_event_classes)
+                })
+        })
+        .collect()
+}
+
+/// Marks the events whose class is disabled for their business profile.
+fn set_class_enabled<'a>(
+    events: impl IntoIterator<Item = &'a mut api::webhook_events::EventListItemResponse>,
+    disabled_event_classes: &HashMap<
+        common_utils::id_type::ProfileId,
+        HashSet<common_enums::EventClass>,
+    >,
+) {
+    for event in events {
+        event.class_enabled = disabled_event_classes
+            .get(&event.profile_id)
+            .map_or(true, |disabled_event_classes| {
+                !disabled_event_classes.contains(&event.event_class)
+            });
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn get_delivery_stats(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    api_constraints: api::webhook_events::EventListConstraints,
+    group_by: common_enums::EventStatsGroupBy,
+) -> RouterResponse<api::webhook_events::EventDeliveryStatsResponse> {
+    // Ensures that the merchant account and the specified business profile exist
+    let profile_id = api_constraints.profile_id.clone();
+    get_account_and_key_store(state.clone(), merchant_id.clone(), profile_id.clone()).await?;
+
+    let now = common_utils::date_time::now();
+    let api_constraints = resolve_time_range(api_constraints, now)?;
+    let (created_after, created_before) = get_created_at_range(
+        api_constraints.created_after,
+        api_constraints.created_before,
+        now,
+    )?;
+    let event_types =
+        get_event_types(api_constraints.event_classes, api_constraints.event_types).await?;
+
+    // The events are grouped and counted by the database, by their overall delivery status and
+    // whether a response was received for them.
+    let delivery_status_counts = state
+        .store
+        .count_initial_events_by_delivery_status(
+            &merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            event_types,
+            group_by,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count events by delivery status")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventDeliveryStatsResponse::from_delivery_stats(
+            merchant_id,
+            delivery_status_counts
+                .into_iter()
+                .filter_map(|delivery_status_count| {
+                    get_delivery_stat(delivery_status_count, group_by)
+                }),
+        ),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn get_endpoint_health(
+    state: SessionState,
+    request: api::webhook_events::WebhookEndpointHealthRequestInternal,
+) -> RouterResponse<api::webhook_events::WebhookEndpointHealthResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let (account, key_store) = get_account_and_key_store(
+        state.clone(),
+        request.merchant_id.clone(),
+        request.profile_id,
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let api_constraints = resolve_time_range(request.constraints, now)?;
+    let (created_after, created_before) = get_created_at_range(
+        api_constraints.created_after,
+        api_constraints.created_before,
+        now,
+    )?;
+    let event_types =
+        get_event_types(api_constraints.event_classes, api_constraints.event_types).await?;
+
+    // One more delivery attempt than the maximum is listed, to detect when the maximum is exceeded
+    let limit = Some(i64::from(ENDPOINT_HEALTH_MAX_DELIVERY_ATTEMPTS) + 1);
+
+    // Every delivery attempt, including retries, is made to an endpoint, and therefore counts
+    // towards its health.
+    let events = match account {
+        MerchantAccountOrProfile::MerchantAccount(merchant_account) => {
+            store
+                .list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    merchant_account.get_id(),
                     created_after,
                     created_before,
                     limit,
-                    offset,
-                    updated_event_types.clone(),
-                    is_delivered,
+                    None,
+                    None,
+                    None,
+                    event_types,
+                    None,
+                    None,
+                    None,
+                    false,
+                    false,
+                    None,
+                    None,
+                    common_enums::EventSortBy::default(),
                     &key_store,
                 )
-                .await,
-                MerchantAccountOrProfile::Profile(business_profile) => store
-                .list_initial_events_by_profile_id_constraints(key_manager_state,
+                .await
+        }
+        MerchantAccountOrProfile::Profile(business_profile) => {
+            store
+                .list_initial_events_by_profile_id_constraints(
+                    key_manager_state,
                     business_profile.get_id(),
                     created_after,
                     created_before,
                     limit,
-                    offset,
-                    updated_event_types.clone(),
-                    is_delivered,
+                    None,
+                    None,
+                    None,
+                    event_types,
+                    None,
+                    None,
+                    None,
+                    false,
+                    false,
+                    None,
+                    common_enums::EventSortBy::default(),
                     &key_store,
                 )
-                .await,
-            }
+                .await
         }
     }
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Failed to list events with specified constraints")?;
 
-    let events = events
-        .into_iter()
-        .map(api::webhook_events::EventListItemResponse::try_from)
-        .collect::<Result<Vec<_>, _>>()?;
+    // The delivery URLs are stored encrypted, so the health of the endpoints is rolled up from the
+    // listed delivery attempts, and the number of delivery attempts is bounded
+    fp_utils::when(
+        events.len() > usize::from(ENDPOINT_HEALTH_MAX_DELIVERY_ATTEMPTS),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Endpoint health can be computed over at most {ENDPOINT_HEALTH_MAX_DELIVERY_ATTEMPTS} delivery attempts, narrow down the time range"
+                ),
+            })
+        },
+    )?;
 
-    let created_after = api_constraints
-        .created_after
-        .unwrap_or(events_list_begin_time);
-    let created_before = api_constraints.created_before.unwrap_or(now);
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookEndpointHealthResponse::from_delivery_attempts(
+            request.merchant_id,
+            events.iter().filter_map(get_endpoint_delivery_attempt),
+        ),
+    ))
+}
 
-    let is_delivered = api_constraints.is_delivered;
+/// Returns the delivery attempt made by the event to its endpoint, or `None` when the URL the
+/// event was delivered to was not recorded, or the delivery is yet to be attempted. Replays are
+/// delivered to a URL other than the configured endpoint, and are not included either.
+fn get_endpoint_delivery_attempt(
+    event: &domain::Event,
+) -> Option<api::webhook_events::EndpointDeliveryAttempt> {
+    if event.delivery_attempt == Some(storage::enums::WebhookDeliveryAttempt::ManualReplay) {
+        return None;
+    }
+    let delivery_url = get_request_content(event)?.delivery_url?;
+    let response = get_response_content(event)?;
 
-    let total_count = store
-        .count_initial_events_by_constraints(
-            &merchant_id,
-            profile_id,
-            created_after,
-            created_before,
-            updated_event_types,
-            is_delivered,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to get total events count")?;
+    Some(api::webhook_events::EndpointDeliveryAttempt {
+        delivery_url,
+        is_delivered: event.is_webhook_notified,
+        response_latency_ms: response.response_latency_ms,
+        created: event.created_at,
+    })
+}
+
+/// Returns the delivery counts of the events in a group with the same overall delivery status,
+/// or `None` when the events lack the attribute they are grouped by. Events whose delivery did not
+/// succeed are pending until a response is received for them, and failed afterwards.
+fn get_delivery_stat(
+    delivery_status_count: storage::EventDeliveryStatusCount,
+    group_by: common_enums::EventStatsGroupBy,
+) -> Option<api::webhook_events::EventDeliveryStat> {
+    let group = match group_by {
+        common_enums::EventStatsGroupBy::EventType => delivery_status_count
+            .event_type
+            .map(|event_type| api::webhook_events::EventStatsGroupKey::EventType { event_type }),
+        common_enums::EventStatsGroupBy::EventClass => delivery_status_count
+            .event_class
+            .map(|event_class| api::webhook_events::EventStatsGroupKey::EventClass { event_class }),
+        common_enums::EventStatsGroupBy::Profile => delivery_status_count
+            .business_profile_id
+            .map(|profile_id| api::webhook_events::EventStatsGroupKey::Profile { profile_id }),
+        common_enums::EventStatsGroupBy::Day => delivery_status_count
+            .created_day
+            .map(api::webhook_events::EventStatsGroupKey::day),
+    }?;
+
+    let storage::EventDeliveryStatusCount {
+        is_overall_delivery_successful,
+        attempts_count,
+        responses_count,
+        ..
+    } = delivery_status_count;
+
+    Some(match is_overall_delivery_successful {
+        Some(true) => api::webhook_events::EventDeliveryStat {
+            group,
+            succeeded: attempts_count,
+            failed: 0,
+            pending: 0,
+        },
+        _ => api::webhook_events::EventDeliveryStat {
+            group,
+            succeeded: 0,
+            failed: responses_count,
+            pending: attempts_count - responses_count,
+        },
+    })
+}
+
+#[instrument(skip(state))]
+pub async fn count_initial_delivery_attempts(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    api_constraints: api::webhook_events::EventListConstraints,
+) -> RouterResponse<api::webhook_events::EventsCountResponse> {
+    let now = common_utils::date_time::now();
+
+    // The events are counted the same way the total count is computed when listing events, so
+    // that the two never diverge. The listed events are not returned, so only a single event is
+    // listed.
+    let (_, events_counter) = list_initial_events(
+        &state,
+        &merchant_id,
+        api::webhook_events::EventListConstraints {
+            limit: Some(1),
+            offset: None,
+            ..api_constraints
+        },
+        now,
+    )
+    .await?;
+    let (total_count, _) = events_counter
+        .count(&state, common_enums::CountMode::Exact, false)
+        .await?;
+    let total_count = total_count
+        .get_required_value("total_count")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     Ok(ApplicationResponse::Json(
-        api::webhook_events::TotalEventsResponse::new(total_count, events),
+        api::webhook_events::EventsCountResponse {
+            merchant_id,
+            total_count,
+        },
     ))
 }
 
@@ -184,7 +983,7 @@ pub async fn list_initial_delivery_attempts(
 pub async fn list_delivery_attempts(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
-    initial_attempt_id: String,
+    initial_attempt_id: api::webhook_events::EventId,
 ) -> RouterResponse<Vec<api::webhook_events::EventRetrieveResponse>> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -202,7 +1001,7 @@ pub async fn list_delivery_attempts(
         .list_events_by_merchant_id_initial_attempt_id(
             key_manager_state,
             &merchant_id,
-            &initial_attempt_id,
+            initial_attempt_id.get_string_repr(),
             &key_store,
         )
         .await
@@ -215,22 +1014,103 @@ pub async fn list_delivery_attempts(
         ))
         .attach_printable("No delivery attempts found with the specified `initial_attempt_id`")
     } else {
-        Ok(ApplicationResponse::Json(
-            events
-                .into_iter()
-                .map(api::webhook_events::EventRetrieveResponse::try_from)
-                .collect::<Result<Vec<_>, _>>()?,
-        ))
+        // All the delivery attempts share the same initial delivery attempt, and hence the same
+        // schedule of automatic retries.
+        let retry_schedule = get_event_retry_schedule(
+            &state,
+            &merchant_id,
+            initial_attempt_id.get_string_repr(),
+            &events,
+        )
+        .await?;
+
+        let outcome_context =
+            get_webhook_outcome_context(&state, &merchant_id, common_utils::date_time::now()).await;
+        let mut delivery_attempts = events
+            .into_iter()
+            .map(|event| {
+                api::webhook_events::EventRetrieveResponse::foreign_try_from((
+                    event,
+                    outcome_context,
+                ))
+                .map(|response| api::webhook_events::EventRetrieveResponse {
+                    retry_schedule: retry_schedule.clone(),
+                    ..response
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        api::webhook_events::EventRetrieveResponse::assign_attempt_numbers(&mut delivery_attempts);
+        let time_to_first_success_ms =
+            api::webhook_events::EventRetrieveResponse::time_to_first_success_ms(
+                &delivery_attempts,
+            );
+        for delivery_attempt in &mut delivery_attempts {
+            delivery_attempt.time_to_first_success_ms = time_to_first_success_ms;
+        }
+        mark_disabled_event_classes(
+            &state,
+            &merchant_id,
+            delivery_attempts
+                .iter_mut()
+                .map(|delivery_attempt| &mut delivery_attempt.event_information)
+                .collect(),
+        )
+        .await?;
+
+        Ok(ApplicationResponse::Json(delivery_attempts))
     }
 }
 
-#[instrument(skip(state))]
-#[cfg(feature = "v1")]
-pub async fn retry_delivery_attempt(
+/// Obtains the schedule of the automatic retries of the delivery attempts sharing the specified
+/// initial delivery attempt from the process tracker. The schedule is unavailable once any of the
+/// delivery attempts succeeds, or once the process tracker task has finished.
+async fn get_event_retry_schedule(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    initial_attempt_id: &str,
+    events: &[domain::Event],
+) -> errors::RouterResult<api::webhook_events::EventRetrySchedule> {
+    if events
+        .iter()
+        .any(|event| event.is_overall_delivery_successful == Some(true))
+    {
+        return Ok(api::webhook_events::EventRetrySchedule::default());
+    }
+
+    let process_tracker_id = super::utils::get_outgoing_webhook_retry_process_tracker_id(
+        initial_attempt_id,
+        merchant_id,
+    );
+    let process_tracker = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find outgoing webhook retry process tracker")?;
+
+    Ok(process_tracker
+        .filter(|process_tracker| {
+            process_tracker.status != common_enums::ProcessTrackerStatus::Finish
+        })
+        .map(|process_tracker| api::webhook_events::EventRetrySchedule {
+            next_retry_at: process_tracker.schedule_time,
+            retry_count: u16::try_from(process_tracker.retry_count).ok(),
+        })
+        .unwrap_or_default())
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retry_delivery_attempt(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
-    event_id: String,
-) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
+    event_id: api::webhook_events::EventId,
+    dry_run: bool,
+    include_attempts: bool,
+    idempotency_key: Option<String>,
+    max_event_age: Option<time::Duration>,
+    triggered_by: storage::enums::WebhookRetryTrigger,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryRetryResponse> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -243,72 +1123,112 @@ pub async fn retry_delivery_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let event_to_retry = store
+    // Retries of events which cannot be retried are rejected before the idempotency key is
+    // claimed, so that the key can be reused once the event becomes retryable
+    let event = store
         .find_event_by_merchant_id_event_id(
             key_manager_state,
             &key_store.merchant_id,
-            &event_id,
+            event_id.get_string_repr(),
             &key_store,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+    let disabled_event_classes = get_disabled_event_classes(
+        &state,
+        &merchant_id,
+        &event.business_profile_id.iter().cloned().collect(),
+    )
+    .await?;
+    let outcome_context =
+        get_webhook_outcome_context(&state, &merchant_id, common_utils::date_time::now()).await;
+    check_event_retryable(
+        &state,
+        &key_store,
+        &event,
+        max_event_age,
+        &disabled_event_classes,
+        outcome_context,
+    )
+    .await?;
+    let business_profile = find_event_business_profile(&state, &key_store, &event).await?;
 
-    let business_profile_id = event_to_retry
-        .business_profile_id
-        .get_required_value("business_profile_id")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to read business profile ID from event to retry")?;
-    let business_profile = store
-        .find_business_profile_by_profile_id(key_manager_state, &key_store, &business_profile_id)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find business profile")?;
+    if dry_run {
+        return Ok(ApplicationResponse::Json(
+            api::webhook_events::WebhookDeliveryRetryResponse::DryRun(
+                api::webhook_events::WebhookDeliveryRetryDryRunResponse {
+                    merchant_id,
+                    dry_run: true,
+                    event_ids: vec![event.event_id],
+                },
+            ),
+        ));
+    }
 
-    let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
-    let new_event_id = super::utils::generate_event_id();
-    let idempotent_event_id = super::utils::get_idempotent_event_id(
-        &event_to_retry.primary_object_id,
-        event_to_retry.event_type,
-        delivery_attempt,
-    );
+    let claimed_idempotency_key = match idempotency_key {
+        Some(idempotency_key) => {
+            let redis_conn = store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error connecting to redis")?;
+            let is_claimed = super::utils::claim_retry_idempotency_key(
+                &redis_conn,
+                &merchant_id,
+                event_id.get_string_repr(),
+                &idempotency_key,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to claim retry idempotency key")?;
 
-    let now = common_utils::date_time::now();
-    let new_event = domain::Event {
-        event_id: new_event_id.clone(),
-        event_type: event_to_retry.event_type,
-        event_class: event_to_retry.event_class,
-        is_webhook_notified: false,
-        primary_object_id: event_to_retry.primary_object_id,
-        primary_object_type: event_to_retry.primary_object_type,
-        created_at: now,
-        merchant_id: Some(business_profile.merchant_id.clone()),
-        business_profile_id: Some(business_profile.get_id().to_owned()),
-        primary_object_created_at: event_to_retry.primary_object_created_at,
-        idempotent_event_id: Some(idempotent_event_id),
-        initial_attempt_id: event_to_retry.initial_attempt_id,
-        request: event_to_retry.request,
-        response: None,
-        delivery_attempt: Some(delivery_attempt),
-        metadata: event_to_retry.metadata,
-        is_overall_delivery_successful: Some(false),
+            if !is_claimed {
+                return Ok(ApplicationResponse::Json(
+                    api::webhook_events::WebhookDeliveryRetryResponse::Deduplicated(
+                        api::webhook_events::WebhookDeliveryRetryDeduplicatedResponse {
+                            merchant_id,
+                            deduplicated: true,
+                            event_id,
+                        },
+                    ),
+                ));
+            }
+
+            Some((redis_conn, idempotency_key))
+        }
+        None => None,
     };
 
-    let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to insert event")?;
+    let retry_event = create_manual_retry_event(
+        &state,
+        &key_store,
+        business_profile,
+        event,
+        None,
+        max_event_age,
+        triggered_by,
+    )
+    .await;
+    let (business_profile, event, request_content) = match retry_event {
+        Ok(retry_event) => retry_event,
+        Err(error) => {
+            // No retry was accepted, so the idempotency key is released for the retry to be
+            // attempted again with the same key
+            if let Some((redis_conn, idempotency_key)) = claimed_idempotency_key {
+                super::utils::release_retry_idempotency_key(
+                    &redis_conn,
+                    &merchant_id,
+                    event_id.get_string_repr(),
+                    &idempotency_key,
+                )
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to release retry idempotency key"))
+                .ok();
+            }
 
-    // We only allow retrying deliveries for events with `request` populated.
-    let request_content = event
-        .request
-        .as_ref()
-        .get_required_value("request")
-        .change_context(errors::ApiErrorResponse::InternalServerError)?
-        .peek()
-        .parse_struct("OutgoingWebhookRequestContent")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to parse webhook event request information")?;
+            return Err(error);
+        }
+    };
+    let new_event_id = event.event_id.clone();
 
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
@@ -316,7 +1236,7 @@ pub async fn retry_delivery_attempt(
         &key_store,
         event,
         request_content,
-        delivery_attempt,
+        storage::enums::WebhookDeliveryAttempt::ManualRetry,
         None,
         None,
     ))
@@ -332,115 +1252,3674 @@ pub async fn retry_delivery_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
 
+    let initial_attempt_id = updated_event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| updated_event.event_id.clone());
+    let retry_schedule = get_event_retry_schedule(
+        &state,
+        &key_store.merchant_id,
+        &initial_attempt_id,
+        std::slice::from_ref(&updated_event),
+    )
+    .await?;
+
+    let delivery_attempts =
+        get_numbered_delivery_attempts(&state, &key_store, &initial_attempt_id, outcome_context)
+            .await?;
+    let attempt_number = delivery_attempts
+        .iter()
+        .find(|delivery_attempt| {
+            delivery_attempt
+                .event_information
+                .event_id
+                .get_string_repr()
+                == updated_event.event_id
+        })
+        .and_then(|delivery_attempt| delivery_attempt.attempt_number);
+    let time_to_first_success_ms =
+        api::webhook_events::EventRetrieveResponse::time_to_first_success_ms(&delivery_attempts);
+    let attempts = include_attempts
+        .then(|| api::webhook_events::EventRetrieveResponse::attempt_timeline(&delivery_attempts));
+
+    let mut retried_event = api::webhook_events::EventRetrieveResponse {
+        attempt_number,
+        time_to_first_success_ms,
+        attempts,
+        retry_schedule,
+        ..api::webhook_events::EventRetrieveResponse::foreign_try_from((
+            updated_event,
+            outcome_context,
+        ))?
+    };
+    mark_disabled_event_classes(
+        &state,
+        &key_store.merchant_id,
+        vec![&mut retried_event.event_information],
+    )
+    .await?;
+
     Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+        api::webhook_events::WebhookDeliveryRetryResponse::Retried(Box::new(retried_event)),
     ))
 }
 
-async fn get_account_and_key_store(
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn replay_delivery_attempt(
     state: SessionState,
-    merchant_id: common_utils::id_type::MerchantId,
-    profile_id: Option<common_utils::id_type::ProfileId>,
-) -> errors::RouterResult<(MerchantAccountOrProfile, domain::MerchantKeyStore)> {
+    request: api::webhook_events::WebhookReplayRequestInternal,
+) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
+    request.validate().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })?;
+
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
-    let merchant_key_store = store
+
+    let key_store = store
         .get_merchant_key_store_by_merchant_id(
             key_manager_state,
-            &merchant_id,
+            &request.merchant_id,
             &store.get_master_key().to_vec().into(),
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    match profile_id {
-        // If profile ID is specified, return business profile, since a business profile is more
-        // specific than a merchant account.
-        Some(profile_id) => {
-            let business_profile = store
-                .find_business_profile_by_merchant_id_profile_id(
-                    key_manager_state,
-                    &merchant_key_store,
-                    &merchant_id,
-                    &profile_id,
-                )
-                .await
-                .attach_printable_lazy(|| {
-                    format!(
-                        "Failed to find business profile by merchant_id `{merchant_id:?}` and profile_id `{profile_id:?}`. \
-                        The merchant_id associated with the business profile `{profile_id:?}` may be \
-                        different than the merchant_id specified (`{merchant_id:?}`)."
-                    )
-                })
-                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-                    id: profile_id.get_string_repr().to_owned(),
-                })?;
+    let event_to_replay = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            request.event_id.get_string_repr(),
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+    let business_profile =
+        find_event_business_profile(&state, &key_store, &event_to_replay).await?;
+    let outcome_context = get_webhook_outcome_context(
+        &state,
+        &key_store.merchant_id,
+        common_utils::date_time::now(),
+    )
+    .await;
 
-            Ok((
-                MerchantAccountOrProfile::Profile(Box::new(business_profile)),
-                merchant_key_store,
-            ))
-        }
+    let (business_profile, event, request_content) = create_manual_retry_event(
+        &state,
+        &key_store,
+        business_profile,
+        event_to_replay,
+        Some(request.override_url),
+        None,
+        storage::enums::WebhookRetryTrigger::Replay,
+    )
+    .await?;
+    let new_event_id = event.event_id.clone();
 
-        None => {
-            let merchant_account = store
-                .find_merchant_account_by_merchant_id(
-                    key_manager_state,
-                    &merchant_id,
-                    &merchant_key_store,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    Box::pin(super::outgoing::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        storage::enums::WebhookDeliveryAttempt::ManualReplay,
+        None,
+        None,
+    ))
+    .await;
 
-            Ok((
-                MerchantAccountOrProfile::MerchantAccount(Box::new(merchant_account)),
-                merchant_key_store,
-            ))
-        }
-    }
+    let updated_event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &new_event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let initial_attempt_id = updated_event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| updated_event.event_id.clone());
+    let delivery_attempts =
+        get_numbered_delivery_attempts(&state, &key_store, &initial_attempt_id, outcome_context)
+            .await?;
+    let attempt_number = delivery_attempts
+        .iter()
+        .find(|delivery_attempt| {
+            delivery_attempt
+                .event_information
+                .event_id
+                .get_string_repr()
+                == updated_event.event_id
+        })
+        .and_then(|delivery_attempt| delivery_attempt.attempt_number);
+    let time_to_first_success_ms =
+        api::webhook_events::EventRetrieveResponse::time_to_first_success_ms(&delivery_attempts);
+
+    let mut replayed_event = api::webhook_events::EventRetrieveResponse {
+        attempt_number,
+        time_to_first_success_ms,
+        ..api::webhook_events::EventRetrieveResponse::foreign_try_from((
+            updated_event,
+            outcome_context,
+        ))?
+    };
+    mark_disabled_event_classes(
+        &state,
+        &key_store.merchant_id,
+        vec![&mut replayed_event.event_information],
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(replayed_event))
 }
 
-async fn finalize_event_types(
-    event_classes: HashSet<common_enums::EventClass>,
-    mut event_types: HashSet<common_enums::EventType>,
-) -> CustomResult<HashSet<common_enums::EventType>, errors::ApiErrorResponse> {
-    // Examples:
-    // 1. event_classes = ["payments", "refunds"], event_types = ["payment_succeeded"]
-    // 2. event_classes = ["refunds"], event_types = ["payment_succeeded"]
+/// Previews the automatic retries of the specified event that are yet to occur, as per the retry
+/// configuration of the merchant. This is read-only, and does not trigger any retries.
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn get_retry_schedule(
+    state: SessionState,
+    request: api::webhook_events::WebhookRetryScheduleRequestInternal,
+) -> RouterResponse<api::webhook_events::WebhookRetrySchedule> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
 
-    // Create possible_event_types based on event_classes
-    // Example 1: possible_event_types = ["payment_*", "refund_*"]
-    // Example 2: possible_event_types = ["refund_*"]
-    let possible_event_types = event_classes
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            request.event_id.get_string_repr(),
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+    let initial_attempt_id = event
+        .initial_attempt_id
         .clone()
-        .into_iter()
-        .flat_map(common_enums::EventClass::event_types)
-        .collect::<HashSet<_>>();
+        .unwrap_or_else(|| event.event_id.clone());
+    let events = store
+        .list_events_by_merchant_id_initial_attempt_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &initial_attempt_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list delivery attempts for initial event")?;
 
-    if event_types.is_empty() {
-        return Ok(possible_event_types);
-    }
+    let retry_schedule =
+        get_event_retry_schedule(&state, &key_store.merchant_id, &initial_attempt_id, &events)
+            .await?;
+    let mapping =
+        crate::workflows::outgoing_webhook_retry::get_webhook_delivery_retry_mapping(store).await;
 
-    // Extend event_types if disjoint with event_classes
-    // Example 1: event_types = ["payment_succeeded", "refund_*"], is_disjoint is used to extend "refund_*" and ignore "payment_*".
-    // Example 2: event_types = ["payment_succeeded", "refund_*"], is_disjoint is only used to extend "refund_*".
-    event_classes.into_iter().for_each(|class| {
-        let valid_event_types = class.event_types();
-        if event_types.is_disjoint(&valid_event_types) {
-            event_types.extend(valid_event_types);
-        }
-    });
+    Ok(ApplicationResponse::Json(get_remaining_retry_schedule(
+        mapping,
+        &key_store.merchant_id,
+        &retry_schedule,
+    )))
+}
 
-    // Validate event_types is a subset of possible_event_types
-    // Example 1: event_types is a subset of possible_event_types (valid)
-    // Example 2: event_types is not a subset of possible_event_types (error due to "payment_succeeded")
-    if !event_types.is_subset(&possible_event_types) {
-        return Err(error_stack::report!(
-            errors::ApiErrorResponse::InvalidRequestData {
-                message: "`event_types` must be a subset of `event_classes`".to_string(),
-            }
-        ));
-    }
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn tag_event(
+    state: SessionState,
+    request: api::webhook_events::EventTagRequestInternal,
+) -> RouterResponse<api::webhook_events::EventListItemResponse> {
+    request.validate().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })?;
 
-    Ok(event_types.clone())
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let event = store
+        .update_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            request.event_id.get_string_repr(),
+            domain::EventUpdate::TagsUpdate {
+                tags: request.normalized_tags(),
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let outcome_context = get_webhook_outcome_context(
+        &state,
+        &key_store.merchant_id,
+        common_utils::date_time::now(),
+    )
+    .await;
+    let mut event =
+        api::webhook_events::EventListItemResponse::foreign_try_from((event, outcome_context))?;
+    mark_disabled_event_classes(&state, &key_store.merchant_id, vec![&mut event]).await?;
+
+    Ok(ApplicationResponse::Json(event))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_events_batch(
+    state: SessionState,
+    request: api::webhook_events::EventBatchRetrieveRequestInternal,
+) -> RouterResponse<api::webhook_events::EventBatchRetrieveResponse> {
+    request.validate().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })?;
+
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let outcome_context = get_webhook_outcome_context(
+        &state,
+        &key_store.merchant_id,
+        common_utils::date_time::now(),
+    )
+    .await;
+    let mut events = Vec::with_capacity(request.event_ids.len());
+    for event_id in request.event_ids.iter().collect::<HashSet<_>>() {
+        match store
+            .find_event_by_merchant_id_event_id(
+                key_manager_state,
+                &key_store.merchant_id,
+                event_id,
+                &key_store,
+            )
+            .await
+        {
+            Ok(event) => events.push(
+                api::webhook_events::EventRetrieveResponse::foreign_try_from((
+                    event,
+                    outcome_context,
+                ))?,
+            ),
+            // Events which are not found are marked as such in the response
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find event to retrieve")
+            }
+        }
+    }
+    mark_disabled_event_classes(
+        &state,
+        &key_store.merchant_id,
+        events
+            .iter_mut()
+            .map(|event| &mut event.event_information)
+            .collect(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventBatchRetrieveResponse::new(
+            request.merchant_id,
+            request.event_ids,
+            events,
+        ),
+    ))
+}
+
+/// Computes the times of the remaining automatic retries, starting with the next scheduled retry.
+/// Events that are no longer being retried automatically have no retries remaining.
+#[cfg(feature = "v1")]
+fn get_remaining_retry_schedule(
+    mapping: scheduler::types::process_data::OutgoingWebhookRetryProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_schedule: &api::webhook_events::EventRetrySchedule,
+) -> api::webhook_events::WebhookRetrySchedule {
+    let attempts = retry_schedule
+        .next_retry_at
+        .zip(retry_schedule.retry_count)
+        .map(|(next_retry_at, retry_count)| {
+            scheduler::utils::get_outgoing_webhook_retry_schedule(
+                mapping,
+                merchant_id,
+                i32::from(retry_count),
+                next_retry_at,
+            )
+        })
+        .unwrap_or_default();
+
+    api::webhook_events::WebhookRetrySchedule { attempts }
+}
+
+/// Obtains the delivery attempts sharing the specified initial delivery attempt, numbered in the
+/// order they were attempted.
+#[cfg(feature = "v1")]
+async fn get_numbered_delivery_attempts(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    initial_attempt_id: &str,
+    outcome_context: api::webhook_events::WebhookOutcomeContext,
+) -> errors::RouterResult<Vec<api::webhook_events::EventRetrieveResponse>> {
+    let events = state
+        .store
+        .list_events_by_merchant_id_initial_attempt_id(
+            &state.into(),
+            &key_store.merchant_id,
+            initial_attempt_id,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list delivery attempts for initial event")?;
+
+    let mut delivery_attempts = events
+        .into_iter()
+        .map(|event| {
+            api::webhook_events::EventRetrieveResponse::foreign_try_from((event, outcome_context))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    api::webhook_events::EventRetrieveResponse::assign_attempt_numbers(&mut delivery_attempts);
+
+    Ok(delivery_attempts)
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn bulk_retry_delivery_attempts(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    event_ids: Option<Vec<String>>,
+    constraints: Option<api::webhook_events::EventListConstraints>,
+    dry_run: bool,
+    max_event_age: Option<time::Duration>,
+    triggered_by: storage::enums::WebhookRetryTrigger,
+) -> RouterResponse<api::webhook_events::BulkWebhookDeliveryRetryResponse> {
+    use router_env::Instrument;
+
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let mut event_ids = match (event_ids, constraints) {
+        (Some(_), Some(_)) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only one of `event_ids` and `constraints` can be specified".to_string(),
+        })?,
+        (None, None) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Either `event_ids` or `constraints` must be specified".to_string(),
+        })?,
+        (Some(event_ids), None) => {
+            fp_utils::when(event_ids.is_empty(), || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "`event_ids` must contain at least one event ID".to_string(),
+                })
+            })?;
+            fp_utils::when(event_ids.len() > BULK_DELIVERY_RETRY_MAX_EVENTS, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "At most {BULK_DELIVERY_RETRY_MAX_EVENTS} events can be retried at once"
+                    ),
+                })
+            })?;
+
+            event_ids
+        }
+        (None, Some(constraints)) => {
+            let (events, _) = list_initial_events(
+                &state,
+                &merchant_id,
+                constraints,
+                common_utils::date_time::now(),
+            )
+            .await?;
+
+            events
+                .into_iter()
+                .take(BULK_DELIVERY_RETRY_MAX_EVENTS)
+                .map(|event| event.event_id)
+                .collect()
+        }
+    };
+
+    // Retry each event at most once, even if it was specified multiple times.
+    let mut seen_event_ids = HashSet::new();
+    event_ids.retain(|event_id| seen_event_ids.insert(event_id.clone()));
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // The business profiles are listed once for all the events, instead of for each event
+    let business_profiles = store
+        .list_profile_by_merchant_id(key_manager_state, &key_store, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list business profiles of merchant")?
+        .into_iter()
+        .map(|business_profile| (business_profile.get_id().to_owned(), business_profile))
+        .collect::<HashMap<_, _>>();
+    let disabled_event_classes = get_profiles_disabled_event_classes(business_profiles.values());
+    let outcome_context =
+        get_webhook_outcome_context(&state, &merchant_id, common_utils::date_time::now()).await;
+
+    let mut results = Vec::with_capacity(event_ids.len());
+
+    if dry_run {
+        for event_id in event_ids {
+            let result = match find_retryable_event(
+                &state,
+                &key_store,
+                &event_id,
+                max_event_age,
+                &disabled_event_classes,
+                outcome_context,
+            )
+            .await
+            {
+                Ok(event) => get_dry_run_retry_result(&event),
+                Err(error) => get_failed_retry_result(event_id, &error),
+            };
+            results.push(result);
+        }
+
+        return Ok(ApplicationResponse::Json(
+            api::webhook_events::BulkWebhookDeliveryRetryResponse {
+                merchant_id,
+                dry_run: true,
+                results,
+            },
+        ));
+    }
+
+    for event_id in event_ids {
+        let retryable_event = find_retryable_event(
+            &state,
+            &key_store,
+            &event_id,
+            max_event_age,
+            &disabled_event_classes,
+            outcome_context,
+        )
+        .await
+        .and_then(|event| {
+            let business_profile = event
+                .business_profile_id
+                .as_ref()
+                .and_then(|business_profile_id| business_profiles.get(business_profile_id))
+                .cloned()
+                .get_required_value("business_profile")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find business profile of event to retry")?;
+            Ok((business_profile, event))
+        });
+        let retry_event = match retryable_event {
+            Ok((business_profile, event)) => {
+                create_manual_retry_event(
+                    &state,
+                    &key_store,
+                    business_profile,
+                    event,
+                    None,
+                    max_event_age,
+                    triggered_by,
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        };
+        let result = match retry_event {
+            Ok((business_profile, event, request_content)) => {
+                let retry_event_id = event.event_id.clone();
+                let cloned_state = state.clone();
+                let cloned_key_store = key_store.clone();
+                // Using a tokio spawn here so that the response is not blocked on delivering the
+                // webhooks for all the selected events.
+                tokio::spawn(
+                    async move {
+                        Box::pin(super::outgoing::trigger_webhook_and_raise_event(
+                            cloned_state,
+                            business_profile,
+                            &cloned_key_store,
+                            event,
+                            request_content,
+                            storage::enums::WebhookDeliveryAttempt::ManualRetry,
+                            None,
+                            None,
+                        ))
+                        .await;
+                    }
+                    .in_current_span(),
+                );
+
+                api::webhook_events::BulkWebhookDeliveryRetryResult {
+                    event_id,
+                    status: api::webhook_events::WebhookDeliveryRetryStatus::Queued,
+                    retry_event_id: Some(retry_event_id),
+                    error_message: None,
+                }
+            }
+            Err(error) => get_failed_retry_result(event_id, &error),
+        };
+        results.push(result);
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::BulkWebhookDeliveryRetryResponse {
+            merchant_id,
+            dry_run: false,
+            results,
+        },
+    ))
+}
+
+/// Obtains the bulk retry result for an event resolved in a dry run, for which no delivery
+/// attempt is created.
+#[cfg(feature = "v1")]
+fn get_dry_run_retry_result(
+    event: &domain::Event,
+) -> api::webhook_events::BulkWebhookDeliveryRetryResult {
+    api::webhook_events::BulkWebhookDeliveryRetryResult {
+        event_id: event.event_id.clone(),
+        status: api::webhook_events::WebhookDeliveryRetryStatus::Resolved,
+        retry_event_id: None,
+        error_message: None,
+    }
+}
+
+/// Obtains the bulk retry result for an event that could not be retried.
+#[cfg(feature = "v1")]
+fn get_failed_retry_result(
+    event_id: String,
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) -> api::webhook_events::BulkWebhookDeliveryRetryResult {
+    logger::error!(?error, %event_id, "Failed to retry delivery for event");
+    let error_message = match error.current_context() {
+        errors::ApiErrorResponse::EventNotFound => {
+            "No event was found with the specified `event_id`"
+        }
+        errors::ApiErrorResponse::PreconditionFailed { message } => message.as_str(),
+        _ => "Failed to create a delivery attempt for the event",
+    };
+
+    api::webhook_events::BulkWebhookDeliveryRetryResult {
+        event_id,
+        status: api::webhook_events::WebhookDeliveryRetryStatus::Failed,
+        retry_event_id: None,
+        error_message: Some(error_message.to_string()),
+    }
+}
+
+/// Checks that the event is recent enough to be retried, failing with a precondition error
+/// otherwise.
+#[cfg(feature = "v1")]
+fn check_retry_event_age(
+    event: &domain::Event,
+    max_event_age: Option<time::Duration>,
+) -> errors::RouterResult<()> {
+    api_models::webhook_events::check_retry_event_age(
+        event.created_at,
+        common_utils::date_time::now(),
+        max_event_age,
+    )
+    .map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string()
+        })
+    })
+}
+
+/// Checks that the specified event can still be retried, as per
+/// [`api::webhook_events::EventRetrieveResponse::check_retryable`], accounting for all the delivery
+/// attempts sharing its initial delivery attempt and for the event classes disabled for its
+/// business profile.
+#[cfg(feature = "v1")]
+async fn check_event_retryable(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    event: &domain::Event,
+    max_event_age: Option<time::Duration>,
+    disabled_event_classes: &HashMap<
+        common_utils::id_type::ProfileId,
+        HashSet<common_enums::EventClass>,
+    >,
+    outcome_context: api::webhook_events::WebhookOutcomeContext,
+) -> errors::RouterResult<()> {
+    let initial_attempt_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+    let delivery_attempts =
+        get_numbered_delivery_attempts(state, key_store, &initial_attempt_id, outcome_context)
+            .await?;
+
+    let mut event = api::webhook_events::EventRetrieveResponse {
+        attempts: Some(
+            api::webhook_events::EventRetrieveResponse::attempt_timeline(&delivery_attempts),
+        ),
+        ..api::webhook_events::EventRetrieveResponse::foreign_try_from((
+            event.clone(),
+            outcome_context,
+        ))?
+    };
+    set_class_enabled(
+        std::slice::from_mut(&mut event.event_information),
+        disabled_event_classes,
+    );
+
+    event
+        .check_retryable(outcome_context.now, max_event_age)
+        .map_err(|error| {
+            error_stack::report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: error.to_string()
+            })
+        })
+}
+
+/// Obtains the specified event, if it can be manually retried.
+#[cfg(feature = "v1")]
+async fn find_retryable_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    event_id: &str,
+    max_event_age: Option<time::Duration>,
+    disabled_event_classes: &HashMap<
+        common_utils::id_type::ProfileId,
+        HashSet<common_enums::EventClass>,
+    >,
+    outcome_context: api::webhook_events::WebhookOutcomeContext,
+) -> errors::RouterResult<domain::Event> {
+    let event = state
+        .store
+        .find_event_by_merchant_id_event_id(
+            &state.into(),
+            &key_store.merchant_id,
+            event_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+    check_event_retryable(
+        state,
+        key_store,
+        &event,
+        max_event_age,
+        disabled_event_classes,
+        outcome_context,
+    )
+    .await?;
+
+    Ok(event)
+}
+
+/// Obtains the business profile the specified event was delivered for.
+#[cfg(feature = "v1")]
+async fn find_event_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    event: &domain::Event,
+) -> errors::RouterResult<domain::Profile> {
+    let business_profile_id = event
+        .business_profile_id
+        .as_ref()
+        .get_required_value("business_profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read business profile ID from event to retry")?;
+
+    state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, business_profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find business profile")
+}
+
+/// Creates a new manual retry delivery attempt for the specified event of the business profile,
+/// returning the business profile the webhook is to be delivered for, the new event and the
+/// request to be sent. When
+/// `override_url` is specified, the new delivery attempt is instead a replay of the recorded
+/// request to `override_url`, with its signature recomputed. Retries of events older than
+/// `max_event_age` are rejected, while replays are not subject to the age limit. The new delivery
+/// attempt records `triggered_by` as the source that triggered it.
+#[cfg(feature = "v1")]
+async fn create_manual_retry_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    event_to_retry: domain::Event,
+    override_url: Option<masking::Secret<String>>,
+    max_event_age: Option<time::Duration>,
+    triggered_by: storage::enums::WebhookRetryTrigger,
+) -> errors::RouterResult<(
+    domain::Profile,
+    domain::Event,
+    api::webhook_events::OutgoingWebhookRequestContent,
+)> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    if override_url.is_none() {
+        check_retry_event_age(&event_to_retry, max_event_age)?;
+    }
+
+    let request_content = parse_request_content(event_to_retry.request.as_ref())?;
+    if request_content.body_omitted {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: "The request body of the event was not persisted, and the event cannot \
+                          be retried"
+                    .to_string()
+            }
+        ));
+    }
+    if request_content.body_redacted {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: "Personally identifiable information was redacted from the stored \
+                          request body of the event, and the event cannot be retried"
+                    .to_string()
+            }
+        ));
+    }
+
+    let (delivery_attempt, request) = match override_url {
+        None => (
+            storage::enums::WebhookDeliveryAttempt::ManualRetry,
+            event_to_retry.request,
+        ),
+        Some(override_url) => {
+            let request_content = super::outgoing::get_replayed_outgoing_webhook_request(
+                &business_profile,
+                request_content,
+                override_url,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct replayed webhook request content")?;
+
+            let request = hyperswitch_domain_models::type_encryption::crypto_operation(
+                key_manager_state,
+                common_utils::type_name!(domain::Event),
+                hyperswitch_domain_models::type_encryption::CryptoOperation::Encrypt(
+                    common_utils::ext_traits::Encode::encode_to_string_of_json(&request_content)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to encode replayed webhook request content")
+                        .map(masking::Secret::new)?,
+                ),
+                common_utils::types::keymanager::Identifier::Merchant(
+                    key_store.merchant_id.clone(),
+                ),
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt replayed webhook request content")?;
+
+            (
+                storage::enums::WebhookDeliveryAttempt::ManualReplay,
+                Some(request),
+            )
+        }
+    };
+    let new_event_id = super::utils::generate_event_id();
+    let idempotent_event_id = super::utils::get_idempotent_event_id(
+        &event_to_retry.primary_object_id,
+        event_to_retry.event_type,
+        delivery_attempt,
+    );
+
+    let now = common_utils::date_time::now();
+    let new_event = domain::Event {
+        event_id: new_event_id,
+        event_type: event_to_retry.event_type,
+        event_class: event_to_retry.event_class,
+        is_webhook_notified: false,
+        primary_object_id: event_to_retry.primary_object_id,
+        primary_object_type: event_to_retry.primary_object_type,
+        created_at: now,
+        merchant_id: Some(business_profile.merchant_id.clone()),
+        business_profile_id: Some(business_profile.get_id().to_owned()),
+        primary_object_created_at: event_to_retry.primary_object_created_at,
+        idempotent_event_id: Some(idempotent_event_id),
+        initial_attempt_id: event_to_retry.initial_attempt_id,
+        request,
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+        metadata: event_to_retry.metadata,
+        is_overall_delivery_successful: Some(false),
+        merchant_connector_id: event_to_retry.merchant_connector_id,
+        tags: None,
+        triggered_by: Some(triggered_by),
+        ingested_at: None,
+        primary_object_updated_at: event_to_retry.primary_object_updated_at,
+    };
+
+    let event = store
+        .insert_event(key_manager_state, new_event, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert event")?;
+
+    let request_content = parse_request_content(event.request.as_ref())?;
+
+    Ok((business_profile, event, request_content))
+}
+
+/// Parses the request information stored for an event.
+#[cfg(feature = "v1")]
+fn parse_request_content(
+    request: Option<&common_utils::crypto::Encryptable<masking::Secret<String>>>,
+) -> errors::RouterResult<api::webhook_events::OutgoingWebhookRequestContent> {
+    // We only allow retrying deliveries for events with `request` populated.
+    request
+        .get_required_value("request")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event request information")
+}
+
+async fn get_account_and_key_store(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+) -> errors::RouterResult<(MerchantAccountOrProfile, domain::MerchantKeyStore)> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    match profile_id {
+        // If profile ID is specified, return business profile, since a business profile is more
+        // specific than a merchant account.
+        Some(profile_id) => {
+            let business_profile = store
+                .find_business_profile_by_merchant_id_profile_id(
+                    key_manager_state,
+                    &merchant_key_store,
+                    &merchant_id,
+                    &profile_id,
+                )
+                .await
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to find business profile by merchant_id `{merchant_id:?}` and profile_id `{profile_id:?}`. \
+                        The merchant_id associated with the business profile `{profile_id:?}` may be \
+                        different than the merchant_id specified (`{merchant_id:?}`)."
+                    )
+                })
+                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })?;
+
+            Ok((
+                MerchantAccountOrProfile::Profile(Box::new(business_profile)),
+                merchant_key_store,
+            ))
+        }
+
+        None => {
+            let merchant_account = store
+                .find_merchant_account_by_merchant_id(
+                    key_manager_state,
+                    &merchant_id,
+                    &merchant_key_store,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+            Ok((
+                MerchantAccountOrProfile::MerchantAccount(Box::new(merchant_account)),
+                merchant_key_store,
+            ))
+        }
+    }
+}
+
+/// Lists all the delivery attempts sharing the specified initial delivery attempt, restricted to
+/// the business profile if one was specified.
+async fn list_events_by_initial_attempt_id(
+    state: &SessionState,
+    account: &MerchantAccountOrProfile,
+    key_store: &domain::MerchantKeyStore,
+    initial_attempt_id: &str,
+    sort_by: common_enums::EventSortBy,
+) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+    let mut events = state
+        .store
+        .list_events_by_merchant_id_initial_attempt_id(
+            &state.into(),
+            &key_store.merchant_id,
+            initial_attempt_id,
+            key_store,
+        )
+        .await?;
+
+    if let MerchantAccountOrProfile::Profile(business_profile) = account {
+        events
+            .retain(|event| event.business_profile_id.as_ref() == Some(business_profile.get_id()));
+    }
+
+    events.sort_by(|a, b| {
+        let ordering = (a.created_at, &a.event_id).cmp(&(b.created_at, &b.event_id));
+        match sort_by {
+            common_enums::EventSortBy::CreatedAsc => ordering,
+            common_enums::EventSortBy::CreatedDesc => ordering.reverse(),
+        }
+    });
+
+    Ok(events)
+}
+
+/// Finds the event with the specified identifier, listing an unknown event as an empty list of
+/// events.
+async fn find_event_by_event_id(
+    state: &SessionState,
+    account: &MerchantAccountOrProfile,
+    key_store: &domain::MerchantKeyStore,
+    event_id: &str,
+) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+    let event = match state
+        .store
+        .find_event_by_merchant_id_event_id(
+            &state.into(),
+            &key_store.merchant_id,
+            event_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(event) => event,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let is_accessible = match account {
+        MerchantAccountOrProfile::MerchantAccount(_) => true,
+        MerchantAccountOrProfile::Profile(business_profile) => {
+            event.business_profile_id.as_ref() == Some(business_profile.get_id())
+        }
+    };
+
+    Ok(if is_accessible {
+        vec![event]
+    } else {
+        Vec::new()
+    })
+}
+
+/// Lists the initial delivery attempts of the merchant associated with a business profile which
+/// does not belong to the merchant. Orphaned events can only be listed for merchant accounts, since
+/// the events of a business profile are looked up by the business profile itself.
+async fn list_orphaned_events(
+    state: &SessionState,
+    account: &MerchantAccountOrProfile,
+    key_store: &domain::MerchantKeyStore,
+    (created_after, created_before): (time::PrimitiveDateTime, time::PrimitiveDateTime),
+    limit: Option<i64>,
+    starting_after: Option<(time::PrimitiveDateTime, String)>,
+    sort_by: common_enums::EventSortBy,
+) -> errors::RouterResult<Vec<domain::Event>> {
+    let MerchantAccountOrProfile::MerchantAccount(merchant_account) = account else {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: "`orphaned` cannot be specified along with `profile_id`".to_string()
+            }
+        ));
+    };
+
+    let key_manager_state = &state.into();
+    let merchant_profile_ids = state
+        .store
+        .list_profile_by_merchant_id(key_manager_state, key_store, merchant_account.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list business profiles of merchant")?
+        .into_iter()
+        .map(|business_profile| business_profile.get_id().to_owned())
+        .collect::<HashSet<_>>();
+
+    state
+        .store
+        .list_orphaned_initial_events_by_merchant_id(
+            key_manager_state,
+            merchant_account.get_id(),
+            merchant_profile_ids,
+            created_after,
+            created_before,
+            limit,
+            starting_after,
+            sort_by,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list orphaned events")
+}
+
+/// The initial delivery attempts selected by the generic list constraints. The content filters are
+/// applied on the decrypted events, while the remaining constraints are applied by the database.
+#[derive(Debug)]
+struct InitialEventsQuery {
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    created_after: time::PrimitiveDateTime,
+    created_before: time::PrimitiveDateTime,
+    event_types: HashSet<common_enums::EventType>,
+    is_delivered: Option<bool>,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+    only_initial_attempts: bool,
+    never_delivered: bool,
+    min_attempts: Option<u16>,
+    profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+    sort_by: common_enums::EventSortBy,
+    content_filters: EventContentFilters,
+}
+
+impl InitialEventsQuery {
+    /// Lists a page of the events created after the specified time matching the constraints
+    /// applied by the database, without applying the content filters.
+    async fn list_stored_events(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
+    ) -> errors::RouterResult<Vec<domain::Event>> {
+        let store = state.store.as_ref();
+        let key_manager_state = &state.into();
+
+        match &self.profile_id {
+            Some(profile_id) => {
+                store
+                    .list_initial_events_by_profile_id_constraints(
+                        key_manager_state,
+                        profile_id,
+                        created_after,
+                        self.created_before,
+                        limit,
+                        offset,
+                        starting_after,
+                        ending_before,
+                        self.event_types.clone(),
+                        self.is_delivered,
+                        self.merchant_connector_id.clone(),
+                        self.delivery_attempts.clone(),
+                        self.only_initial_attempts,
+                        self.never_delivered,
+                        self.min_attempts,
+                        self.sort_by,
+                        &self.key_store,
+                    )
+                    .await
+            }
+            None => {
+                store
+                    .list_initial_events_by_merchant_id_constraints(
+                        key_manager_state,
+                        &self.merchant_id,
+                        created_after,
+                        self.created_before,
+                        limit,
+                        offset,
+                        starting_after,
+                        ending_before,
+                        self.event_types.clone(),
+                        self.is_delivered,
+                        self.merchant_connector_id.clone(),
+                        self.delivery_attempts.clone(),
+                        self.only_initial_attempts,
+                        self.never_delivered,
+                        self.min_attempts,
+                        self.profile_ids.clone(),
+                        self.sort_by,
+                        &self.key_store,
+                    )
+                    .await
+            }
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events with specified constraints")
+    }
+
+    /// Lists a page of the events matching all the constraints. The content filters can only be
+    /// applied on the decrypted events, so pages of events are fetched from the database until
+    /// the page of matching events is filled. This way, pages are not cut short by the content
+    /// filters, and the pagination cursors do not skip over matching events.
+    async fn list_events(
+        &self,
+        state: &SessionState,
+        limit: i64,
+        offset: Option<i64>,
+        mut starting_after: Option<(time::PrimitiveDateTime, String)>,
+        mut ending_before: Option<(time::PrimitiveDateTime, String)>,
+    ) -> errors::RouterResult<Vec<domain::Event>> {
+        if self.content_filters.is_empty() {
+            return self
+                .list_stored_events(
+                    state,
+                    self.created_after,
+                    Some(limit),
+                    offset,
+                    starting_after,
+                    ending_before,
+                )
+                .await;
+        }
+
+        let limit = usize::try_from(limit)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert i64 to usize")?;
+        let offset = offset
+            .map(usize::try_from)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert i64 to usize")?
+            .unwrap_or(0);
+
+        // The events preceding the `ending_before` cursor are fetched moving away from the cursor,
+        // which is the reverse of the order they are listed in.
+        let is_ending_before = ending_before.is_some();
+        let mut events = Vec::new();
+        let mut skipped_events_count = 0;
+        loop {
+            let mut stored_events = self
+                .list_stored_events(
+                    state,
+                    self.created_after,
+                    Some(i64::from(CONTENT_FILTERED_EVENTS_PAGE_SIZE)),
+                    None,
+                    starting_after.take(),
+                    ending_before.take(),
+                )
+                .await?;
+            let is_last_page = stored_events.len() < usize::from(CONTENT_FILTERED_EVENTS_PAGE_SIZE);
+            if is_ending_before {
+                stored_events.reverse();
+            }
+            let cursor = stored_events
+                .last()
+                .map(|event| (event.created_at, event.event_id.clone()));
+
+            for event in stored_events
+                .into_iter()
+                .filter(|event| self.content_filters.is_matching(event))
+            {
+                if events.len() >= limit {
+                    break;
+                }
+                if skipped_events_count < offset {
+                    skipped_events_count += 1;
+                } else {
+                    events.push(event);
+                }
+            }
+
+            match cursor {
+                Some(cursor) if !is_last_page && events.len() < limit => {
+                    if is_ending_before {
+                        ending_before = Some(cursor);
+                    } else {
+                        starting_after = Some(cursor);
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        if is_ending_before {
+            events.reverse();
+        }
+
+        Ok(events)
+    }
+
+    /// Counts the events created after the specified time matching all the constraints, by their
+    /// event class. The content filters can only be applied on the decrypted events, so the events
+    /// are fetched from the database a page at a time, retaining only their counts.
+    async fn count_events_by_class(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+    ) -> errors::RouterResult<HashMap<common_enums::EventClass, i64>> {
+        let mut class_counts = get_event_types_by_class(&self.event_types)
+            .into_keys()
+            .map(|event_class| (event_class, 0))
+            .collect::<HashMap<_, i64>>();
+
+        let mut starting_after = None;
+        loop {
+            let stored_events = self
+                .list_stored_events(
+                    state,
+                    created_after,
+                    Some(i64::from(CONTENT_FILTERED_EVENTS_PAGE_SIZE)),
+                    None,
+                    starting_after,
+                    None,
+                )
+                .await?;
+            let is_last_page = stored_events.len() < usize::from(CONTENT_FILTERED_EVENTS_PAGE_SIZE);
+
+            for event in stored_events
+                .iter()
+                .filter(|event| self.content_filters.is_matching(event))
+            {
+                class_counts
+                    .entry(event.event_class)
+                    .and_modify(|count| *count = count.saturating_add(1))
+                    .or_insert(1);
+            }
+
+            starting_after = stored_events
+                .last()
+                .map(|event| (event.created_at, event.event_id.clone()));
+            if is_last_page || starting_after.is_none() {
+                break;
+            }
+        }
+
+        Ok(class_counts)
+    }
+
+    /// Counts the events created after the specified time matching all the constraints. Without
+    /// content filters, the events are counted by the database.
+    async fn count_events(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+    ) -> errors::RouterResult<i64> {
+        if !self.content_filters.is_empty() {
+            let class_counts = self.count_events_by_class(state, created_after).await?;
+            return Ok(sum_class_counts(&class_counts));
+        }
+
+        state
+            .store
+            .count_initial_events_by_constraints(
+                &self.merchant_id,
+                self.profile_id.clone(),
+                created_after,
+                self.created_before,
+                self.event_types.clone(),
+                self.is_delivered,
+                self.merchant_connector_id.clone(),
+                self.delivery_attempts.clone(),
+                self.only_initial_attempts,
+                self.never_delivered,
+                self.min_attempts,
+                self.profile_ids.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get total events count")
+    }
+
+    /// Counts the events created after the specified time matching all the constraints, by their
+    /// event class. Without content filters, the events of each class are counted by the
+    /// database.
+    async fn count_events_by_class_in_database(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+    ) -> errors::RouterResult<HashMap<common_enums::EventClass, i64>> {
+        if !self.content_filters.is_empty() {
+            return self.count_events_by_class(state, created_after).await;
+        }
+
+        let mut class_counts = HashMap::new();
+        for (event_class, event_types) in get_event_types_by_class(&self.event_types) {
+            let count = state
+                .store
+                .count_initial_events_by_constraints(
+                    &self.merchant_id,
+                    self.profile_id.clone(),
+                    created_after,
+                    self.created_before,
+                    event_types,
+                    self.is_delivered,
+                    self.merchant_connector_id.clone(),
+                    self.delivery_attempts.clone(),
+                    self.only_initial_attempts,
+                    self.never_delivered,
+                    self.min_attempts,
+                    self.profile_ids.clone(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get events count of event class")?;
+            class_counts.insert(event_class, count);
+        }
+
+        Ok(class_counts)
+    }
+}
+
+/// The way the events listed by [`list_initial_events`] are counted, so that the events are
+/// counted using the same constraints they were listed with.
+#[derive(Debug)]
+enum InitialEventsCounter {
+    /// The events are all listed at once, and are counted as listed, by their event class.
+    Listed(HashMap<common_enums::EventClass, i64>),
+    /// The orphaned events are listed a page at a time, and are counted by listing all of them.
+    Orphaned {
+        account: MerchantAccountOrProfile,
+        key_store: domain::MerchantKeyStore,
+        created_at_range: (time::PrimitiveDateTime, time::PrimitiveDateTime),
+    },
+    /// The events whose primary object ID contains a substring are listed a page at a time, and
+    /// are counted by the database.
+    ObjectIdContains {
+        merchant_id: common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        object_id_contains: String,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    },
+    /// The events matching the generic list constraints are counted using the query they were
+    /// listed with.
+    Query(InitialEventsQuery),
+}
+
+impl InitialEventsCounter {
+    fn from_listed_events(events: &[domain::Event]) -> Self {
+        Self::Listed(get_class_counts(events))
+    }
+
+    /// Indicates whether the events are counted by listing them, which is cheap, and is therefore
+    /// never estimated.
+    fn is_counted_as_listed(&self) -> bool {
+        matches!(self, Self::Listed(_) | Self::Orphaned { .. })
+    }
+
+    /// Counts the events created after the specified time, by their event class. The events
+    /// counted by listing them are all counted, irrespective of the time they were created at.
+    async fn count_events_by_class(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+    ) -> errors::RouterResult<HashMap<common_enums::EventClass, i64>> {
+        match self {
+            Self::Listed(class_counts) => Ok(class_counts.clone()),
+            Self::Orphaned {
+                account,
+                key_store,
+                created_at_range,
+            } => {
+                let events = list_orphaned_events(
+                    state,
+                    account,
+                    key_store,
+                    *created_at_range,
+                    None,
+                    None,
+                    common_enums::EventSortBy::default(),
+                )
+                .await?;
+
+                Ok(get_class_counts(&events))
+            }
+            Self::ObjectIdContains {
+                merchant_id,
+                profile_id,
+                object_id_contains,
+                created_before,
+                ..
+            } => Ok(state
+                .store
+                .count_initial_events_by_primary_object_id_contains(
+                    merchant_id,
+                    profile_id.clone(),
+                    object_id_contains,
+                    created_after,
+                    *created_before,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get events count by partial object ID")?
+                .into_iter()
+                .collect()),
+            Self::Query(events_query) => {
+                events_query
+                    .count_events_by_class_in_database(state, created_after)
+                    .await
+            }
+        }
+    }
+
+    /// Counts the events created after the specified time.
+    async fn count_events(
+        &self,
+        state: &SessionState,
+        created_after: time::PrimitiveDateTime,
+    ) -> errors::RouterResult<i64> {
+        match self {
+            Self::Listed(_) | Self::Orphaned { .. } | Self::ObjectIdContains { .. } => Ok(
+                sum_class_counts(&self.count_events_by_class(state, created_after).await?),
+            ),
+            Self::Query(events_query) => events_query.count_events(state, created_after).await,
+        }
+    }
+
+    /// Counts the events as per the count mode, along with their breakdown by event class if
+    /// requested. Estimated counts are extrapolated from the events created within
+    /// [`ESTIMATED_TOTAL_COUNT_SAMPLE_WINDOW`][api_models::webhook_events::ESTIMATED_TOTAL_COUNT_SAMPLE_WINDOW]
+    /// before the end of the time range, unless the events are counted as listed.
+    async fn count(
+        &self,
+        state: &SessionState,
+        count_mode: common_enums::CountMode,
+        include_breakdown: bool,
+    ) -> errors::RouterResult<(Option<i64>, Option<HashMap<common_enums::EventClass, i64>>)> {
+        if count_mode == common_enums::CountMode::None && !include_breakdown {
+            return Ok((None, None));
+        }
+
+        let (created_after, created_before) = match self {
+            Self::Listed(_) | Self::Orphaned { .. } => {
+                let class_counts = self
+                    .count_events_by_class(state, time::PrimitiveDateTime::MIN)
+                    .await?;
+                let total_count = (count_mode != common_enums::CountMode::None)
+                    .then(|| sum_class_counts(&class_counts));
+
+                return Ok((total_count, include_breakdown.then_some(class_counts)));
+            }
+            Self::ObjectIdContains {
+                created_after,
+                created_before,
+                ..
+            } => (*created_after, *created_before),
+            Self::Query(events_query) => (events_query.created_after, events_query.created_before),
+        };
+
+        let class_breakdown = if include_breakdown {
+            Some(self.count_events_by_class(state, created_after).await?)
+        } else {
+            None
+        };
+
+        let count_created_after = match count_mode {
+            common_enums::CountMode::Exact => Some(created_after),
+            common_enums::CountMode::Estimated => Some(created_after.max(
+                created_before - api_models::webhook_events::ESTIMATED_TOTAL_COUNT_SAMPLE_WINDOW,
+            )),
+            common_enums::CountMode::None => None,
+        };
+        let total_count = match count_created_after {
+            Some(count_created_after) => {
+                let count = match &class_breakdown {
+                    Some(class_breakdown) if count_created_after == created_after => {
+                        sum_class_counts(class_breakdown)
+                    }
+                    _ => self.count_events(state, count_created_after).await?,
+                };
+
+                Some(api_models::webhook_events::estimate_total_count(
+                    count,
+                    created_before - count_created_after,
+                    created_before - created_after,
+                ))
+            }
+            None => None,
+        };
+
+        Ok((total_count, class_breakdown))
+    }
+}
+
+fn get_class_counts(events: &[domain::Event]) -> HashMap<common_enums::EventClass, i64> {
+    events
+        .iter()
+        .fold(HashMap::new(), |mut class_counts, event| {
+            class_counts
+                .entry(event.event_class)
+                .and_modify(|count: &mut i64| *count = count.saturating_add(1))
+                .or_insert(1);
+            class_counts
+        })
+}
+
+fn sum_class_counts(class_counts: &HashMap<common_enums::EventClass, i64>) -> i64 {
+    class_counts
+        .values()
+        .fold(0, |count, class_count| count.saturating_add(*class_count))
+}
+
+/// Filters applied on the fetched events instead of by the database. The request and response
+/// information is stored encrypted, so filters on them cannot be applied by the database. The
+/// delivery states which cannot be expressed using `is_overall_delivery_successful` alone, the
+/// connector recorded in the event metadata, the expiry of the events as per the retention
+/// duration of their class, the hour of the day and day of the week the events were created in,
+/// the tags of the events, the sources that triggered the events, and the event classes of the
+/// events fetched by object ID are also checked here.
+#[derive(Debug, Default)]
+struct EventContentFilters {
+    response_status_code_gte: Option<u16>,
+    response_status_code_lte: Option<u16>,
+    latency_ms_gte: Option<u32>,
+    sla_breached: Option<bool>,
+    response_body_size_gte: Option<usize>,
+    error_category: Option<HashSet<common_enums::WebhookDeliveryErrorCategory>>,
+    has_error: Option<bool>,
+    error_message_contains: Option<String>,
+    response_received: Option<bool>,
+    response_content_type: Option<String>,
+    payload_version: Option<String>,
+    delivery_states: Option<HashSet<common_enums::WebhookDeliveryState>>,
+    event_classes: Option<HashSet<common_enums::EventClass>>,
+    connector: Option<String>,
+    expiring_before: Option<time::PrimitiveDateTime>,
+    ingested_after: Option<time::PrimitiveDateTime>,
+    ingested_before: Option<time::PrimitiveDateTime>,
+    event_delay_ms_gte: Option<u64>,
+    created_hour_of_day: Option<HashSet<u8>>,
+    created_day_of_week: Option<HashSet<u8>>,
+    tags: Option<HashSet<String>>,
+    triggered_by: Option<HashSet<common_enums::WebhookRetryTrigger>>,
+}
+
+impl EventContentFilters {
+    fn is_empty(&self) -> bool {
+        self.is_response_filters_empty()
+            && self.delivery_states.is_none()
+            && self.event_classes.is_none()
+            && self.connector.is_none()
+            && self.payload_version.is_none()
+            && self.expiring_before.is_none()
+            && self.ingested_after.is_none()
+            && self.ingested_before.is_none()
+            && self.event_delay_ms_gte.is_none()
+            && self.created_hour_of_day.is_none()
+            && self.created_day_of_week.is_none()
+            && self.tags.is_none()
+            && self.triggered_by.is_none()
+    }
+
+    fn is_response_filters_empty(&self) -> bool {
+        self.response_status_code_gte.is_none()
+            && self.response_status_code_lte.is_none()
+            && self.latency_ms_gte.is_none()
+            && self.sla_breached.is_none()
+            && self.response_body_size_gte.is_none()
+            && self
+                .error_category
+                .as_ref()
+                .map_or(true, |error_category| error_category.is_empty())
+            && self.has_error.is_none()
+            && self.error_message_contains.is_none()
+            && self.response_received.is_none()
+            && self.response_content_type.is_none()
+    }
+
+    fn is_matching(&self, event: &domain::Event) -> bool {
+        let is_delivery_state_matching = self
+            .delivery_states
+            .as_ref()
+            .map_or(true, |delivery_states| {
+                delivery_states.contains(&get_delivery_state(event))
+            });
+
+        let is_event_class_matching = self.event_classes.as_ref().map_or(true, |event_classes| {
+            event_classes.contains(&event.event_class)
+        });
+
+        let is_connector_matching = self.connector.as_ref().map_or(true, |connector| {
+            event
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get_connector())
+                .is_some_and(|event_connector| event_connector == connector)
+        });
+
+        let is_payload_version_matching = self.payload_version.is_none()
+            || get_request_content(event).is_some_and(|request| {
+                request.is_payload_version_matching(self.payload_version.as_deref())
+            });
+
+        let is_expiry_matching = self.expiring_before.map_or(true, |expiring_before| {
+            api_models::webhook_events::get_expires_at(event.event_class, event.created_at)
+                .is_some_and(|expires_at| expires_at < expiring_before)
+        });
+
+        let is_ingestion_time_matching = (self.ingested_after.is_none()
+            && self.ingested_before.is_none())
+            || event.ingested_at.is_some_and(|ingested_at| {
+                self.ingested_after
+                    .map_or(true, |ingested_after| ingested_at >= ingested_after)
+                    && self
+                        .ingested_before
+                        .map_or(true, |ingested_before| ingested_at <= ingested_before)
+            });
+
+        let is_event_delay_matching = self.event_delay_ms_gte.map_or(true, |event_delay_ms_gte| {
+            api_models::webhook_events::get_event_delay_ms(
+                event.created_at,
+                event.primary_object_updated_at,
+            )
+            .is_some_and(|event_delay_ms| event_delay_ms >= event_delay_ms_gte)
+        });
+
+        let is_created_hour_of_day_matching = self
+            .created_hour_of_day
+            .as_ref()
+            .map_or(true, |created_hour_of_day| {
+                created_hour_of_day.contains(&event.created_at.hour())
+            });
+
+        let is_created_day_of_week_matching =
+            self.created_day_of_week
+                .as_ref()
+                .map_or(true, |created_day_of_week| {
+                    created_day_of_week
+                        .contains(&event.created_at.weekday().number_days_from_sunday())
+                });
+
+        let is_tag_matching = self.tags.as_ref().map_or(true, |tags| {
+            event
+                .tags
+                .as_ref()
+                .is_some_and(|event_tags| event_tags.iter().any(|tag| tags.contains(tag)))
+        });
+
+        let is_triggered_by_matching = self.triggered_by.as_ref().map_or(true, |triggered_by| {
+            api_models::webhook_events::get_retry_trigger(
+                event.delivery_attempt,
+                event.triggered_by,
+            )
+            .is_some_and(|event_triggered_by| triggered_by.contains(&event_triggered_by))
+        });
+
+        is_delivery_state_matching
+            && is_event_class_matching
+            && is_connector_matching
+            && is_payload_version_matching
+            && is_expiry_matching
+            && is_ingestion_time_matching
+            && is_event_delay_matching
+            && is_created_hour_of_day_matching
+            && is_created_day_of_week_matching
+            && is_tag_matching
+            && is_triggered_by_matching
+            && (self.is_response_filters_empty()
+                || get_response_content(event).is_some_and(|response| {
+                    response.is_status_code_within(
+                        self.response_status_code_gte,
+                        self.response_status_code_lte,
+                    ) && response.is_latency_at_least(self.latency_ms_gte)
+                        && self.sla_breached.map_or(true, |sla_breached| {
+                            response.is_sla_breached() == Some(sla_breached)
+                        })
+                        && response.is_body_size_at_least(self.response_body_size_gte)
+                        && response.is_error_category_within(self.error_category.as_ref())
+                        && response.is_error_message_present(self.has_error)
+                        && response
+                            .is_error_message_containing(self.error_message_contains.as_deref())
+                        && response.is_response_received(self.response_received)
+                        && response.is_content_type_matching(self.response_content_type.as_deref())
+                }))
+    }
+}
+
+fn get_delivery_state(event: &domain::Event) -> common_enums::WebhookDeliveryState {
+    match event.is_overall_delivery_successful {
+        Some(true) => common_enums::WebhookDeliveryState::Succeeded,
+        _ if event.response.is_none() => common_enums::WebhookDeliveryState::Pending,
+        _ => common_enums::WebhookDeliveryState::Failed,
+    }
+}
+
+/// Splits the delivery states events must be in into the `is_overall_delivery_successful`
+/// filter applied by the database, and the delivery states that must additionally be checked on
+/// the fetched events, since pending and failed deliveries cannot be told apart using
+/// `is_overall_delivery_successful` alone.
+fn get_delivery_state_filters(
+    delivery_states: Option<HashSet<common_enums::WebhookDeliveryState>>,
+) -> (
+    Option<bool>,
+    Option<HashSet<common_enums::WebhookDeliveryState>>,
+) {
+    let Some(delivery_states) = delivery_states else {
+        return (None, None);
+    };
+
+    match (
+        delivery_states.contains(&common_enums::WebhookDeliveryState::Succeeded),
+        delivery_states.contains(&common_enums::WebhookDeliveryState::Pending),
+        delivery_states.contains(&common_enums::WebhookDeliveryState::Failed),
+    ) {
+        (true, true, true) => (None, None),
+        (true, false, false) => (Some(true), None),
+        (false, true, true) => (Some(false), None),
+        (false, _, _) => (Some(false), Some(delivery_states)),
+        (true, _, _) => (None, Some(delivery_states)),
+    }
+}
+
+fn get_events_list_begin_time(now: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight()
+}
+
+/// Resolves the `time_range` constraint into concrete `created_after` and `created_before`
+/// constraints.
+fn resolve_time_range(
+    api_constraints: api::webhook_events::EventListConstraints,
+    now: time::PrimitiveDateTime,
+) -> errors::RouterResult<api::webhook_events::EventListConstraints> {
+    api_constraints.resolve_time_range(now).map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string()
+        })
+    })
+}
+
+/// Validates the `created_after` and `created_before` constraints, and defaults them to the
+/// widest time range events can be listed for.
+fn get_created_at_range(
+    created_after: Option<time::PrimitiveDateTime>,
+    created_before: Option<time::PrimitiveDateTime>,
+    now: time::PrimitiveDateTime,
+) -> errors::RouterResult<(time::PrimitiveDateTime, time::PrimitiveDateTime)> {
+    let events_list_begin_time = get_events_list_begin_time(now);
+
+    fp_utils::when(
+        !created_after
+            .zip(created_before)
+            .map(|(created_after, created_before)| created_after <= created_before)
+            .unwrap_or(true),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+            })
+        },
+    )?;
+
+    let created_after = match created_after {
+        Some(created_after) => {
+            if created_after < events_list_begin_time {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+                })
+            } else {
+                Ok(created_after)
+            }
+        }
+        None => Ok(events_list_begin_time),
+    }?;
+
+    let created_before = match created_before {
+        Some(created_before) => {
+            if created_before < events_list_begin_time {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`created_before` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+                })
+            } else {
+                Ok(created_before)
+            }
+        }
+        None => Ok(now),
+    }?;
+
+    Ok((created_after, created_before))
+}
+
+async fn get_event_types(
+    event_classes: Option<HashSet<common_enums::EventClass>>,
+    event_types: Option<HashSet<common_enums::EventType>>,
+) -> errors::RouterResult<HashSet<common_enums::EventType>> {
+    let event_classes = event_classes.unwrap_or_default();
+    let event_types = event_types.unwrap_or_default();
+
+    if event_classes.is_empty() {
+        Ok(event_types)
+    } else {
+        finalize_event_types(event_classes, event_types).await
+    }
+}
+
+/// Groups the event types events are filtered by into the classes they belong to, so that the
+/// events of each class can be counted separately. All the classes and their event types are
+/// included when events are not filtered by their type.
+fn get_event_types_by_class(
+    event_types: &HashSet<common_enums::EventType>,
+) -> HashMap<common_enums::EventClass, HashSet<common_enums::EventType>> {
+    if event_types.is_empty() {
+        return common_enums::EventClass::iter()
+            .map(|event_class| (event_class, event_class.event_types()))
+            .collect();
+    }
+
+    let mut event_types_by_class = HashMap::<_, HashSet<_>>::new();
+    for event_type in event_types {
+        event_types_by_class
+            .entry(event_type.event_class())
+            .or_default()
+            .insert(*event_type);
+    }
+
+    event_types_by_class
+}
+
+fn get_request_content(
+    event: &domain::Event,
+) -> Option<api::webhook_events::OutgoingWebhookRequestContent> {
+    event.request.as_ref().and_then(|request| {
+        request
+            .peek()
+            .parse_struct("OutgoingWebhookRequestContent")
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to parse webhook event request information");
+            })
+            .ok()
+    })
+}
+
+fn get_response_content(
+    event: &domain::Event,
+) -> Option<api::webhook_events::OutgoingWebhookResponseContent> {
+    event.response.as_ref().and_then(|response| {
+        response
+            .peek()
+            .parse_struct("OutgoingWebhookResponseContent")
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to parse webhook event response information");
+            })
+            .ok()
+    })
+}
+
+/// Resolves the event referenced by a pagination cursor into the `(created_at, event_id)` pair
+/// used for keyset pagination.
+async fn get_pagination_cursor_event(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    cursor: Option<String>,
+    field_name: &'static str,
+) -> errors::RouterResult<Option<(time::PrimitiveDateTime, String)>> {
+    let Some(cursor) = cursor else {
+        return Ok(None);
+    };
+
+    let event = state
+        .store
+        .find_event_by_merchant_id_event_id(&state.into(), merchant_id, &cursor, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{field_name}` must be the `event_id` of an existing event"),
+        })?;
+
+    Ok(Some((event.created_at, event.event_id)))
+}
+
+async fn finalize_event_types(
+    event_classes: HashSet<common_enums::EventClass>,
+    mut event_types: HashSet<common_enums::EventType>,
+) -> CustomResult<HashSet<common_enums::EventType>, errors::ApiErrorResponse> {
+    // Examples:
+    // 1. event_classes = ["payments", "refunds"], event_types = ["payment_succeeded"]
+    // 2. event_classes = ["refunds"], event_types = ["payment_succeeded"]
+
+    // Create possible_event_types based on event_classes
+    // Example 1: possible_event_types = ["payment_*", "refund_*"]
+    // Example 2: possible_event_types = ["refund_*"]
+    let possible_event_types = event_classes
+        .clone()
+        .into_iter()
+        .flat_map(common_enums::EventClass::event_types)
+        .collect::<HashSet<_>>();
+
+    if event_types.is_empty() {
+        return Ok(possible_event_types);
+    }
+
+    // Extend event_types if disjoint with event_classes
+    // Example 1: event_types = ["payment_succeeded", "refund_*"], is_disjoint is used to extend "refund_*" and ignore "payment_*".
+    // Example 2: event_types = ["payment_succeeded", "refund_*"], is_disjoint is only used to extend "refund_*".
+    event_classes.into_iter().for_each(|class| {
+        let valid_event_types = class.event_types();
+        if event_types.is_disjoint(&valid_event_types) {
+            event_types.extend(valid_event_types);
+        }
+    });
+
+    // Validate event_types is a subset of possible_event_types
+    // Example 1: event_types is a subset of possible_event_types (valid)
+    // Example 2: event_types is not a subset of possible_event_types (error due to "payment_succeeded")
+    if !event_types.is_subset(&possible_event_types) {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "`event_types` must be a subset of `event_classes`".to_string(),
+            }
+        ));
+    }
+
+    Ok(event_types.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::collections::{HashMap, HashSet};
+
+    use diesel_models::enums;
+
+    use super::EventContentFilters;
+    use crate::types::{api, domain, transformers::ForeignTryFrom};
+
+    fn event_for_object(
+        event_id: &str,
+        event_type: enums::EventType,
+        event_class: enums::EventClass,
+    ) -> domain::Event {
+        domain::Event {
+            event_id: event_id.into(),
+            event_type,
+            event_class,
+            is_webhook_notified: true,
+            primary_object_id: "shared_object_id".into(),
+            primary_object_type: enums::EventObjectType::PaymentDetails,
+            created_at: common_utils::date_time::now(),
+            merchant_id: None,
+            business_profile_id: None,
+            primary_object_created_at: None,
+            idempotent_event_id: Some(event_id.into()),
+            initial_attempt_id: Some(event_id.into()),
+            request: None,
+            response: None,
+            delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+            metadata: None,
+            is_overall_delivery_successful: Some(true),
+            merchant_connector_id: None,
+            tags: None,
+            triggered_by: None,
+            ingested_at: None,
+            primary_object_updated_at: None,
+        }
+    }
+
+    fn outcome_context() -> api::webhook_events::WebhookOutcomeContext {
+        api::webhook_events::WebhookOutcomeContext {
+            now: common_utils::date_time::now(),
+            automatic_retry_window: time::Duration::days(1),
+        }
+    }
+
+    fn constraints_from(value: serde_json::Value) -> api::webhook_events::EventListConstraints {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_object_id_filter_includes_event_classes() {
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({
+                "object_id": "shared_object_id",
+                "event_classes": ["payments"]
+            })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::ObjectIdFilter {
+                object_id,
+                event_classes: Some(event_classes),
+                ..
+            } if object_id == "shared_object_id"
+                && event_classes == HashSet::from([enums::EventClass::Payments])
+        ));
+
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({ "object_id": "shared_object_id" })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::ObjectIdFilter {
+                event_classes: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_object_id_filter_conflicts_name_the_constraints() {
+        let error_message = |value: serde_json::Value| {
+            match api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+                constraints_from(value),
+            )
+            .unwrap_err()
+            .current_context()
+            {
+                crate::core::errors::ApiErrorResponse::PreconditionFailed { message } => {
+                    Some(message.clone())
+                }
+                _ => None,
+            }
+        };
+
+        assert_eq!(
+            error_message(serde_json::json!({
+                "object_id": "shared_object_id",
+                "initial_attempt_id": "evt_1"
+            }))
+            .as_deref(),
+            Some(
+                "Only one of `object_id`, `object_ids`, `object_id_contains` and \
+                 `initial_attempt_id` can be specified"
+            )
+        );
+
+        let message = error_message(serde_json::json!({
+            "object_id_contains": "shared",
+            "event_classes": ["payments"]
+        }))
+        .unwrap();
+        assert!(message.starts_with(
+            "Either only one of `object_id`, `object_ids`, `object_id_contains` and \
+             `initial_attempt_id` must be specified, or one or more of `created_after`, \
+             `created_before`, "
+        ));
+        assert!(message.contains(", `tags` and `triggered_by` must be specified."));
+    }
+
+    #[test]
+    fn test_content_filters_match_events() {
+        let event = |event_id: &str| {
+            event_for_object(
+                event_id,
+                enums::EventType::PaymentSucceeded,
+                enums::EventClass::Payments,
+            )
+        };
+        let date_time = |day: u8, hour: u8, minute: u8| {
+            time::PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+                time::Time::from_hms(hour, minute, 0).unwrap(),
+            )
+        };
+        let classified_events = || {
+            vec![
+                event_for_object(
+                    "evt_payment",
+                    enums::EventType::PaymentSucceeded,
+                    enums::EventClass::Payments,
+                ),
+                event_for_object(
+                    "evt_refund",
+                    enums::EventType::RefundSucceeded,
+                    enums::EventClass::Refunds,
+                ),
+            ]
+        };
+        let connector_events = || {
+            [
+                ("evt_stripe", Some("stripe")),
+                ("evt_adyen", Some("adyen")),
+                ("evt_unknown", None),
+            ]
+            .into_iter()
+            .map(|(event_id, connector)| domain::Event {
+                metadata: Some(diesel_models::EventMetadata::Payment {
+                    payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::from(
+                        "pay_1",
+                    ))
+                    .unwrap(),
+                    connector: connector.map(ToOwned::to_owned),
+                }),
+                ..event(event_id)
+            })
+            .chain([event("evt_without_metadata")])
+            .collect::<Vec<_>>()
+        };
+        let expiring_events = || {
+            [
+                (
+                    "evt_payment",
+                    enums::EventType::PaymentSucceeded,
+                    enums::EventClass::Payments,
+                ),
+                (
+                    "evt_dispute",
+                    enums::EventType::DisputeOpened,
+                    enums::EventClass::Disputes,
+                ),
+            ]
+            .into_iter()
+            .map(|(event_id, event_type, event_class)| domain::Event {
+                created_at: date_time(1, 0, 0),
+                ..event_for_object(event_id, event_type, event_class)
+            })
+            .collect::<Vec<_>>()
+        };
+        let delayed_events = || {
+            [
+                ("evt_prompt", Some(time::Duration::milliseconds(200))),
+                ("evt_slow", Some(time::Duration::seconds(30))),
+                ("evt_unrecorded", None),
+            ]
+            .into_iter()
+            .map(|(event_id, event_delay)| domain::Event {
+                created_at: date_time(1, 0, 10),
+                primary_object_updated_at: event_delay
+                    .map(|event_delay| date_time(1, 0, 10) - event_delay),
+                ..event(event_id)
+            })
+            .collect::<Vec<_>>()
+        };
+        let ingested_events = || {
+            [
+                ("evt_early", Some(date_time(1, 0, 1))),
+                ("evt_lagging", Some(date_time(1, 0, 30))),
+                ("evt_unrecorded", None),
+            ]
+            .into_iter()
+            .map(|(event_id, ingested_at)| domain::Event {
+                ingested_at,
+                ..event(event_id)
+            })
+            .collect::<Vec<_>>()
+        };
+        // 2024-01-01 is a Monday
+        let scheduled_events = || {
+            [
+                ("evt_monday_morning", date_time(1, 9, 30)),
+                ("evt_monday_evening", date_time(1, 18, 30)),
+                ("evt_saturday_morning", date_time(6, 9, 30)),
+                ("evt_sunday_night", date_time(7, 23, 30)),
+            ]
+            .into_iter()
+            .map(|(event_id, created_at)| domain::Event {
+                created_at,
+                ..event(event_id)
+            })
+            .collect::<Vec<_>>()
+        };
+        let tagged_events = || {
+            [
+                ("evt_investigating", Some(vec!["under-investigation"])),
+                ("evt_resolved", Some(vec!["resolved", "incident-42"])),
+                ("evt_cleared", Some(Vec::new())),
+                ("evt_untagged", None),
+            ]
+            .into_iter()
+            .map(|(event_id, tags)| domain::Event {
+                tags: tags.map(|tags| tags.into_iter().map(ToOwned::to_owned).collect()),
+                ..event(event_id)
+            })
+            .collect::<Vec<_>>()
+        };
+        let retried_events = || {
+            [
+                (
+                    "evt_initial",
+                    enums::WebhookDeliveryAttempt::InitialAttempt,
+                    None,
+                ),
+                (
+                    "evt_scheduled",
+                    enums::WebhookDeliveryAttempt::AutomaticRetry,
+                    Some(enums::WebhookRetryTrigger::Scheduler),
+                ),
+                (
+                    "evt_dashboard",
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                    Some(enums::WebhookRetryTrigger::ManualDashboard),
+                ),
+                (
+                    "evt_api",
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                    Some(enums::WebhookRetryTrigger::Api),
+                ),
+                (
+                    "evt_legacy_manual_retry",
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                    None,
+                ),
+                (
+                    "evt_legacy_replay",
+                    enums::WebhookDeliveryAttempt::ManualReplay,
+                    None,
+                ),
+            ]
+            .into_iter()
+            .map(|(event_id, delivery_attempt, triggered_by)| domain::Event {
+                delivery_attempt: Some(delivery_attempt),
+                triggered_by,
+                ..event(event_id)
+            })
+            .collect::<Vec<_>>()
+        };
+
+        let cases: Vec<(EventContentFilters, Vec<domain::Event>, Vec<&str>)> = vec![
+            (
+                EventContentFilters {
+                    event_classes: Some(HashSet::from([enums::EventClass::Payments])),
+                    ..Default::default()
+                },
+                classified_events(),
+                vec!["evt_payment"],
+            ),
+            (
+                EventContentFilters {
+                    connector: Some("stripe".into()),
+                    ..Default::default()
+                },
+                connector_events(),
+                vec!["evt_stripe"],
+            ),
+            // Events that have not been attempted yet have no response to check for errors, are
+            // not transport failures, and have no latency to compare against the SLA
+            (
+                EventContentFilters {
+                    has_error: Some(false),
+                    ..Default::default()
+                },
+                vec![event("evt_pending")],
+                vec![],
+            ),
+            (
+                EventContentFilters {
+                    response_received: Some(false),
+                    ..Default::default()
+                },
+                vec![event("evt_pending")],
+                vec![],
+            ),
+            (
+                EventContentFilters {
+                    sla_breached: Some(true),
+                    ..Default::default()
+                },
+                vec![event("evt_pending")],
+                vec![],
+            ),
+            (
+                EventContentFilters {
+                    sla_breached: Some(false),
+                    ..Default::default()
+                },
+                vec![event("evt_pending")],
+                vec![],
+            ),
+            // Events without any stored request have no known payload version
+            (
+                EventContentFilters {
+                    payload_version: Some("2023-10-16".to_string()),
+                    ..Default::default()
+                },
+                vec![event("evt_pending")],
+                vec![],
+            ),
+            // Payment events expire after 180 days, while dispute events expire after 365 days
+            (
+                EventContentFilters {
+                    expiring_before: Some(date_time(1, 0, 0) + time::Duration::days(200)),
+                    ..Default::default()
+                },
+                expiring_events(),
+                vec!["evt_payment"],
+            ),
+            (
+                EventContentFilters {
+                    event_delay_ms_gte: Some(5000),
+                    ..Default::default()
+                },
+                delayed_events(),
+                vec!["evt_slow"],
+            ),
+            (
+                EventContentFilters {
+                    event_delay_ms_gte: Some(200),
+                    ..Default::default()
+                },
+                delayed_events(),
+                vec!["evt_prompt", "evt_slow"],
+            ),
+            (
+                EventContentFilters {
+                    ingested_after: Some(date_time(1, 0, 10)),
+                    ..Default::default()
+                },
+                ingested_events(),
+                vec!["evt_lagging"],
+            ),
+            (
+                EventContentFilters {
+                    ingested_before: Some(date_time(1, 0, 10)),
+                    ..Default::default()
+                },
+                ingested_events(),
+                vec!["evt_early"],
+            ),
+            (
+                EventContentFilters {
+                    ingested_after: Some(date_time(1, 0, 1)),
+                    ingested_before: Some(date_time(1, 0, 30)),
+                    ..Default::default()
+                },
+                ingested_events(),
+                vec!["evt_early", "evt_lagging"],
+            ),
+            (
+                EventContentFilters {
+                    created_hour_of_day: Some(HashSet::from([9])),
+                    ..Default::default()
+                },
+                scheduled_events(),
+                vec!["evt_monday_morning", "evt_saturday_morning"],
+            ),
+            (
+                EventContentFilters {
+                    created_day_of_week: Some(HashSet::from([0, 6])),
+                    ..Default::default()
+                },
+                scheduled_events(),
+                vec!["evt_saturday_morning", "evt_sunday_night"],
+            ),
+            (
+                EventContentFilters {
+                    created_hour_of_day: Some(HashSet::from([9])),
+                    created_day_of_week: Some(HashSet::from([0, 6])),
+                    ..Default::default()
+                },
+                scheduled_events(),
+                vec!["evt_saturday_morning"],
+            ),
+            (
+                EventContentFilters {
+                    tags: Some(HashSet::from(["under-investigation".to_string()])),
+                    ..Default::default()
+                },
+                tagged_events(),
+                vec!["evt_investigating"],
+            ),
+            // Events tagged with any of the specified tags are matched
+            (
+                EventContentFilters {
+                    tags: Some(HashSet::from([
+                        "under-investigation".to_string(),
+                        "incident-42".to_string(),
+                    ])),
+                    ..Default::default()
+                },
+                tagged_events(),
+                vec!["evt_investigating", "evt_resolved"],
+            ),
+            (
+                EventContentFilters {
+                    triggered_by: Some(HashSet::from([
+                        enums::WebhookRetryTrigger::ManualDashboard,
+                        enums::WebhookRetryTrigger::Api,
+                    ])),
+                    ..Default::default()
+                },
+                retried_events(),
+                vec!["evt_dashboard", "evt_api"],
+            ),
+            // Delivery attempts recorded before their source was stored fall back to their type
+            (
+                EventContentFilters {
+                    triggered_by: Some(HashSet::from([enums::WebhookRetryTrigger::Replay])),
+                    ..Default::default()
+                },
+                retried_events(),
+                vec!["evt_legacy_replay"],
+            ),
+        ];
+
+        for (content_filters, events, expected_event_ids) in cases {
+            assert!(!content_filters.is_empty());
+            assert_eq!(
+                events
+                    .iter()
+                    .filter(|event| content_filters.is_matching(event))
+                    .map(|event| event.event_id.as_str())
+                    .collect::<Vec<_>>(),
+                expected_event_ids,
+                "{content_filters:?}"
+            );
+        }
+
+        let no_filter = EventContentFilters::default();
+        assert!(no_filter.is_empty());
+        assert!(classified_events()
+            .iter()
+            .all(|event| no_filter.is_matching(event)));
+
+        // The payload version is read from the request, rather than the response
+        assert!(EventContentFilters {
+            payload_version: Some("2023-10-16".to_string()),
+            ..Default::default()
+        }
+        .is_response_filters_empty());
+
+        assert_eq!(
+            delayed_events()
+                .iter()
+                .map(|event| api_models::webhook_events::get_event_delay_ms(
+                    event.created_at,
+                    event.primary_object_updated_at
+                ))
+                .collect::<Vec<_>>(),
+            vec![Some(200), Some(30_000), None]
+        );
+    }
+
+    #[test]
+    fn test_generic_filter_conversion() {
+        fn date_time(month: time::Month, day: u8, minute: u8) -> time::PrimitiveDateTime {
+            time::PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, month, day).unwrap(),
+                time::Time::from_hms(0, minute, 0).unwrap(),
+            )
+        }
+
+        let cases: &[(
+            serde_json::Value,
+            fn(&api::webhook_events::EventListConstraintsInternal) -> bool,
+        )] = &[
+            (
+                serde_json::json!({ "connector": "stripe" }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            connector: Some(connector),
+                            ..
+                        } if connector == "stripe"
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "delivery_attempt": ["automatic_retry", "manual_retry"] }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            delivery_attempts: Some(delivery_attempts),
+                            ..
+                        } if *delivery_attempts == HashSet::from([
+                            enums::WebhookDeliveryAttempt::AutomaticRetry,
+                            enums::WebhookDeliveryAttempt::ManualRetry,
+                        ])
+                    )
+                },
+            ),
+            (serde_json::json!({}), |constraints| {
+                matches!(
+                    constraints,
+                    api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                        delivery_attempts: None,
+                        ..
+                    }
+                )
+            }),
+            (
+                serde_json::json!({ "profile_ids": ["pro_1", "pro_2"] }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            profile_ids: Some(profile_ids),
+                            ..
+                        } if profile_ids
+                            .iter()
+                            .map(|profile_id| profile_id.get_string_repr())
+                            .collect::<HashSet<_>>()
+                            == HashSet::from(["pro_1", "pro_2"])
+                    )
+                },
+            ),
+            (serde_json::json!({ "has_error": true }), |constraints| {
+                matches!(
+                    constraints,
+                    api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                        has_error: Some(true),
+                        ..
+                    }
+                )
+            }),
+            (
+                serde_json::json!({ "response_received": false }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            response_received: Some(false),
+                            ..
+                        }
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "payloadVersion": "2023-10-16" }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            payload_version: Some(payload_version),
+                            ..
+                        } if payload_version == "2023-10-16"
+                    )
+                },
+            ),
+            (serde_json::json!({ "sla_breached": true }), |constraints| {
+                matches!(
+                    constraints,
+                    api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                        sla_breached: Some(true),
+                        ..
+                    }
+                )
+            }),
+            (
+                serde_json::json!({ "expiring_before": "2024-07-19T00:00:00Z" }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            expiring_before: Some(expiring_before),
+                            ..
+                        } if *expiring_before == date_time(time::Month::July, 19, 0)
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "event_delay_ms_gte": 5000 }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            event_delay_ms_gte: Some(5000),
+                            ..
+                        }
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "ingested_after": "2024-01-01T00:10:00Z" }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            ingested_after: Some(ingested_after),
+                            ingested_before: None,
+                            ..
+                        } if *ingested_after == date_time(time::Month::January, 1, 10)
+                    )
+                },
+            ),
+            (
+                serde_json::json!({
+                    "created_hour_of_day": [9],
+                    "created_day_of_week": [0, 6],
+                }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            created_hour_of_day: Some(created_hour_of_day),
+                            created_day_of_week: Some(created_day_of_week),
+                            ..
+                        } if *created_hour_of_day == HashSet::from([9])
+                            && *created_day_of_week == HashSet::from([0, 6])
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "tags": ["under-investigation"] }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            tags: Some(tags),
+                            ..
+                        } if *tags == HashSet::from(["under-investigation".to_string()])
+                    )
+                },
+            ),
+            (
+                serde_json::json!({ "triggered_by": ["scheduler"] }),
+                |constraints| {
+                    matches!(
+                        constraints,
+                        api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                            triggered_by: Some(triggered_by),
+                            only_initial_attempts: false,
+                            ..
+                        } if *triggered_by
+                            == HashSet::from([enums::WebhookRetryTrigger::Scheduler])
+                    )
+                },
+            ),
+        ];
+
+        for (constraints, is_expected_conversion) in cases {
+            let converted_constraints =
+                api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+                    constraints_from(constraints.clone()),
+                )
+                .unwrap();
+            assert!(
+                is_expected_conversion(&converted_constraints),
+                "{constraints}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_constraints_rejected_by_conversion() {
+        for constraints in [
+            serde_json::json!({
+                "object_ids": ["shared_object_id"],
+                "event_classes": ["payments"]
+            }),
+            serde_json::json!({
+                "object_id": "shared_object_id",
+                "only_initial_attempts": false
+            }),
+            serde_json::json!({
+                "object_id": "shared_object_id",
+                "delivery_attempt": ["manual_retry"]
+            }),
+            serde_json::json!({ "object_id": "shared_object_id", "profile_ids": ["pro_1"] }),
+            serde_json::json!({ "object_id": "pay_1", "event_delay_ms_gte": 5000 }),
+            serde_json::json!({ "profile_id": "pro_1", "profile_ids": ["pro_2"] }),
+            serde_json::json!({ "orphaned": true, "profile_id": "pro_1" }),
+            serde_json::json!({ "orphaned": true, "object_id": "pay_1" }),
+            serde_json::json!({ "orphaned": true, "is_delivered": false }),
+            serde_json::json!({ "created_hour_of_day": [24] }),
+        ] {
+            assert!(
+                api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+                    constraints_from(constraints.clone())
+                )
+                .is_err(),
+                "{constraints}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_generic_filter_includes_only_initial_attempts() {
+        for (constraints, expected_only_initial_attempts) in [
+            (serde_json::json!({}), true),
+            (
+                serde_json::json!({ "delivery_attempt": ["manual_retry"] }),
+                false,
+            ),
+            (serde_json::json!({ "only_initial_attempts": false }), false),
+            (
+                serde_json::json!({
+                    "delivery_attempt": ["manual_retry"],
+                    "only_initial_attempts": true
+                }),
+                true,
+            ),
+        ] {
+            let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+                constraints_from(constraints),
+            )
+            .unwrap();
+            assert!(matches!(
+                constraints,
+                api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                    only_initial_attempts,
+                    ..
+                } if only_initial_attempts == expected_only_initial_attempts
+            ));
+        }
+    }
+
+    #[test]
+    fn test_generic_filter_applies_default_time_window() {
+        let now = common_utils::date_time::now();
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({ "event_classes": ["payments"] })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                created_after: Some(created_after),
+                created_before: None,
+                default_window_applied: true,
+                ..
+            } if created_after >= now - api_models::webhook_events::DEFAULT_EVENT_LIST_TIME_WINDOW
+                && created_after <= common_utils::date_time::now()
+                    - api_models::webhook_events::DEFAULT_EVENT_LIST_TIME_WINDOW
+        ));
+
+        for constraints in [
+            serde_json::json!({ "created_after": "2024-01-01T00:00:00Z" }),
+            serde_json::json!({ "created_before": "2024-01-01T00:00:00Z" }),
+            serde_json::json!({ "time_range": "last_7_days" }),
+        ] {
+            let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+                constraints_from(constraints),
+            )
+            .unwrap();
+            assert!(matches!(
+                constraints,
+                api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                    default_window_applied: false,
+                    ..
+                }
+            ));
+        }
+
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({ "created_before": "2024-01-01T00:00:00Z" })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::GenericFilter {
+                created_after: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_orphaned_filter_conversion() {
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({
+                "orphaned": true,
+                "created_after": "2024-01-01T00:00:00Z",
+                "limit": 5,
+                "sort_by": "created_asc",
+            })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::OrphanedFilter {
+                created_after: Some(_),
+                created_before: None,
+                limit: Some(5),
+                starting_after: None,
+                sort_by: enums::EventSortBy::CreatedAsc,
+            }
+        ));
+
+        // Not listing orphaned events is the same as not specifying the filter
+        let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
+            constraints_from(serde_json::json!({ "orphaned": false, "profile_ids": ["pro_1"] })),
+        )
+        .unwrap();
+        assert!(matches!(
+            constraints,
+            api::webhook_events::EventListConstraintsInternal::GenericFilter { .. }
+        ));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_remaining_retry_schedule() {
+        use scheduler::types::process_data;
+
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        // 5 automatic retries in total, at 5 minute intervals after the first
+        let mapping = || process_data::OutgoingWebhookRetryProcessTrackerMapping {
+            default_mapping: process_data::RetryMapping {
+                start_after: 60,
+                frequencies: vec![(300, 4)],
+            },
+            custom_merchant_mapping: HashMap::new(),
+        };
+        let next_retry_at = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+
+        let retry_schedule = super::get_remaining_retry_schedule(
+            mapping(),
+            &merchant_id,
+            &api::webhook_events::EventRetrySchedule {
+                next_retry_at: Some(next_retry_at),
+                retry_count: Some(2),
+            },
+        );
+        assert_eq!(
+            retry_schedule.attempts,
+            vec![
+                next_retry_at,
+                next_retry_at + time::Duration::minutes(5),
+                next_retry_at + time::Duration::minutes(10),
+            ]
+        );
+
+        // Terminal events, such as delivered events, have no retry schedule
+        let retry_schedule = super::get_remaining_retry_schedule(
+            mapping(),
+            &merchant_id,
+            &api::webhook_events::EventRetrySchedule::default(),
+        );
+        assert!(retry_schedule.attempts.is_empty());
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_dry_run_retry_result_does_not_create_delivery_attempt() {
+        let event = event_for_object(
+            "evt_dry_run",
+            enums::EventType::PaymentSucceeded,
+            enums::EventClass::Payments,
+        );
+
+        let result = super::get_dry_run_retry_result(&event);
+
+        assert_eq!(result.event_id, "evt_dry_run");
+        assert_eq!(
+            result.status,
+            api::webhook_events::WebhookDeliveryRetryStatus::Resolved
+        );
+        assert!(result.retry_event_id.is_none());
+        assert!(result.error_message.is_none());
+    }
+
+    #[test]
+    fn test_event_types_grouped_by_class_for_breakdown() {
+        let event_types_by_class = super::get_event_types_by_class(&HashSet::from([
+            enums::EventType::PaymentSucceeded,
+            enums::EventType::PaymentFailed,
+            enums::EventType::RefundSucceeded,
+            enums::EventType::DisputeOpened,
+        ]));
+
+        assert_eq!(
+            event_types_by_class,
+            HashMap::from([
+                (
+                    enums::EventClass::Payments,
+                    HashSet::from([
+                        enums::EventType::PaymentSucceeded,
+                        enums::EventType::PaymentFailed,
+                    ])
+                ),
+                (
+                    enums::EventClass::Refunds,
+                    HashSet::from([enums::EventType::RefundSucceeded])
+                ),
+                (
+                    enums::EventClass::Disputes,
+                    HashSet::from([enums::EventType::DisputeOpened])
+                ),
+            ])
+        );
+
+        // Every class is counted when events are not filtered by their type
+        let event_types_by_class = super::get_event_types_by_class(&HashSet::new());
+        assert!(event_types_by_class.contains_key(&enums::EventClass::Mandates));
+        assert!(event_types_by_class
+            .iter()
+            .all(|(event_class, event_types)| *event_types == event_class.event_types()));
+    }
+
+    /// Creates a session state backed by the mock store, holding a merchant account with the
+    /// specified events.
+    #[cfg(feature = "v1")]
+    async fn get_mock_state_with_events(
+        merchant_id: &common_utils::id_type::MerchantId,
+        events: Vec<domain::Event>,
+    ) -> crate::routes::SessionState {
+        use std::sync::Arc;
+
+        use common_utils::{type_name, types::keymanager::Identifier};
+
+        use crate::{
+            routes::{
+                self,
+                app::{settings::Settings, StorageImpl},
+            },
+            services,
+        };
+
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let store = state.store.as_ref();
+        let key_manager_state = &(&state).into();
+        let master_key = store.get_master_key();
+
+        let merchant_key_store = store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: common_utils::date_time::now(),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        store
+            .insert_merchant(
+                key_manager_state,
+                domain::MerchantAccount::from(domain::MerchantAccountSetter {
+                    merchant_id: merchant_id.clone(),
+                    return_url: None,
+                    enable_payment_response_hash: false,
+                    payment_response_hash_key: None,
+                    redirect_to_merchant_with_http_post: false,
+                    merchant_name: None,
+                    merchant_details: None,
+                    webhook_details: None,
+                    sub_merchants_enabled: None,
+                    parent_merchant_id: None,
+                    publishable_key: "pk_test".to_string(),
+                    storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+                    locker_id: None,
+                    metadata: None,
+                    routing_algorithm: None,
+                    primary_business_details: serde_json::json!([]),
+                    frm_routing_algorithm: None,
+                    created_at: common_utils::date_time::now(),
+                    modified_at: common_utils::date_time::now(),
+                    intent_fulfillment_time: None,
+                    payout_routing_algorithm: None,
+                    organization_id: common_utils::id_type::OrganizationId::default(),
+                    is_recon_enabled: false,
+                    default_profile: None,
+                    recon_status: enums::ReconStatus::NotRequested,
+                    payment_link_config: None,
+                    pm_collect_link_config: None,
+                    version: common_types::consts::API_VERSION,
+                    is_platform_account: false,
+                    product_type: None,
+                }),
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+
+        for event in events {
+            store
+                .insert_event(key_manager_state, event, &merchant_key_store)
+                .await
+                .unwrap();
+        }
+
+        state
+    }
+
+    /// Lists the initial delivery attempts of the merchant, returning the listed event IDs,
+    /// `total_count` and `has_more` from the response.
+    #[cfg(feature = "v1")]
+    async fn list_event_ids(
+        state: &crate::routes::SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: serde_json::Value,
+    ) -> (Vec<String>, Option<i64>, bool) {
+        let response = Box::pin(super::list_initial_delivery_attempts(
+            state.clone(),
+            merchant_id.clone(),
+            constraints_from(constraints),
+        ))
+        .await
+        .unwrap()
+        .get_json_body()
+        .unwrap();
+        let response = serde_json::to_value(response).unwrap();
+
+        let event_ids = response
+            .get("events")
+            .and_then(serde_json::Value::as_array)
+            .unwrap()
+            .iter()
+            .filter_map(|event| event.get("event_id").and_then(serde_json::Value::as_str))
+            .map(ToOwned::to_owned)
+            .collect();
+        let total_count = response
+            .get("total_count")
+            .and_then(serde_json::Value::as_i64);
+        let has_more = response
+            .get("has_more")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap();
+
+        (event_ids, total_count, has_more)
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_content_filtered_pages_are_filled_and_counted() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        let created_at = common_utils::date_time::now() - time::Duration::hours(1);
+
+        // Every tenth event is tagged, so that the tagged events span several pages of the events
+        // fetched from the database
+        let events = (0..150)
+            .map(|index| {
+                let event_id = format!("evt_{index:03}");
+                domain::Event {
+                    created_at: created_at + time::Duration::seconds(index),
+                    merchant_id: Some(merchant_id.clone()),
+                    business_profile_id: Some(profile_id.clone()),
+                    tags: (index % 10 == 0).then(|| vec!["under-investigation".to_string()]),
+                    ..event_for_object(
+                        &event_id,
+                        enums::EventType::PaymentSucceeded,
+                        enums::EventClass::Payments,
+                    )
+                }
+            })
+            .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+        let tagged_event_ids = |indices: &[u16]| {
+            indices
+                .iter()
+                .map(|index| format!("evt_{index:03}"))
+                .collect::<Vec<_>>()
+        };
+
+        for (constraints, expected_event_ids, expected_has_more) in [
+            (
+                serde_json::json!({ "tags": ["under-investigation"], "limit": 2 }),
+                tagged_event_ids(&[140, 130]),
+                true,
+            ),
+            (
+                serde_json::json!({ "tags": ["under-investigation"], "limit": 5, "offset": 10 }),
+                tagged_event_ids(&[40, 30, 20, 10, 0]),
+                false,
+            ),
+            (
+                serde_json::json!({
+                    "tags": ["under-investigation"],
+                    "limit": 12,
+                    "starting_after": "evt_145"
+                }),
+                tagged_event_ids(&[140, 130, 120, 110, 100, 90, 80, 70, 60, 50, 40, 30]),
+                true,
+            ),
+            (
+                serde_json::json!({
+                    "tags": ["under-investigation"],
+                    "limit": 12,
+                    "ending_before": "evt_005"
+                }),
+                tagged_event_ids(&[120, 110, 100, 90, 80, 70, 60, 50, 40, 30, 20, 10]),
+                true,
+            ),
+        ] {
+            let (event_ids, total_count, has_more) =
+                list_event_ids(&state, &merchant_id, constraints.clone()).await;
+
+            assert_eq!(event_ids, expected_event_ids, "{constraints}");
+            // The events are counted using the same content filters they were listed with
+            assert_eq!(total_count, Some(15), "{constraints}");
+            assert_eq!(has_more, expected_has_more, "{constraints}");
+        }
+
+        // Without any content filters, the events are counted by the database
+        let (event_ids, total_count, has_more) =
+            list_event_ids(&state, &merchant_id, serde_json::json!({ "limit": 2 })).await;
+        assert_eq!(event_ids, vec!["evt_149", "evt_148"]);
+        assert_eq!(total_count, Some(150));
+        assert!(has_more);
+
+        // The count endpoint counts the events the same way as the total count of listed events
+        let count_events = |constraints: serde_json::Value| {
+            Box::pin(super::count_initial_delivery_attempts(
+                state.clone(),
+                merchant_id.clone(),
+                constraints_from(constraints),
+            ))
+        };
+        let total_count = count_events(serde_json::json!({}))
+            .await
+            .unwrap()
+            .get_json_body()
+            .unwrap()
+            .total_count;
+        assert_eq!(total_count, 150);
+        let total_count = count_events(serde_json::json!({ "tags": ["under-investigation"] }))
+            .await
+            .unwrap()
+            .get_json_body()
+            .unwrap()
+            .total_count;
+        assert_eq!(total_count, 15);
+    }
+
+    /// Exports the initial delivery attempts of the merchant, returning the exported event IDs,
+    /// excluding the manifest line.
+    #[cfg(feature = "v1")]
+    async fn export_event_ids(
+        state: &crate::routes::SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: serde_json::Value,
+    ) -> Vec<String> {
+        let response = Box::pin(super::export_initial_delivery_attempts(
+            state.clone(),
+            api::webhook_events::EventListExportRequestInternal {
+                merchant_id: merchant_id.clone(),
+                constraints: constraints_from(constraints),
+                fields: None,
+            },
+        ))
+        .await
+        .unwrap();
+
+        match response {
+            crate::services::ApplicationResponse::FileData((export, _)) => {
+                String::from_utf8(export)
+                    .unwrap()
+                    .lines()
+                    .skip(1)
+                    .filter_map(|line| {
+                        serde_json::from_str::<serde_json::Value>(line)
+                            .ok()?
+                            .get("event_id")?
+                            .as_str()
+                            .map(ToOwned::to_owned)
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_delivery_stats_by_day_across_day_boundary() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let midnight = common_utils::date_time::now().date().midnight() - time::Duration::days(1);
+        let response = || {
+            Some(common_utils::crypto::Encryptable::new(
+                masking::Secret::new(String::new()),
+                masking::Secret::new(Vec::new()),
+            ))
+        };
+
+        let events = [
+            (
+                "evt_1",
+                midnight - time::Duration::milliseconds(1),
+                Some(true),
+                None,
+            ),
+            ("evt_2", midnight, Some(false), None),
+            (
+                "evt_3",
+                midnight + time::Duration::seconds(1),
+                Some(false),
+                response(),
+            ),
+            (
+                "evt_4",
+                midnight + time::Duration::seconds(2),
+                Some(true),
+                response(),
+            ),
+        ]
+        .into_iter()
+        .map(
+            |(event_id, created_at, is_overall_delivery_successful, response)| domain::Event {
+                created_at,
+                merchant_id: Some(merchant_id.clone()),
+                is_overall_delivery_successful,
+                response,
+                ..event_for_object(
+                    event_id,
+                    enums::EventType::PaymentSucceeded,
+                    enums::EventClass::Payments,
+                )
+            },
+        )
+        .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+
+        let stats = Box::pin(super::get_delivery_stats(
+            state,
+            merchant_id.clone(),
+            constraints_from(serde_json::json!({})),
+            enums::EventStatsGroupBy::Day,
+        ))
+        .await
+        .unwrap()
+        .get_json_body()
+        .unwrap()
+        .stats;
+
+        assert_eq!(
+            stats,
+            vec![
+                api::webhook_events::EventDeliveryStat {
+                    group: api::webhook_events::EventStatsGroupKey::Day {
+                        day: midnight - time::Duration::days(1),
+                    },
+                    succeeded: 1,
+                    failed: 0,
+                    pending: 0,
+                },
+                api::webhook_events::EventDeliveryStat {
+                    group: api::webhook_events::EventStatsGroupKey::Day { day: midnight },
+                    succeeded: 1,
+                    failed: 1,
+                    pending: 1,
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_export_by_partial_object_id() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        let created_at = common_utils::date_time::now() - time::Duration::hours(1);
+        let events = [
+            ("evt_1", "shared_object_id"),
+            ("evt_2", "other_object_id"),
+            ("evt_3", "shared_object_id"),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|((event_id, primary_object_id), index)| domain::Event {
+            created_at: created_at + time::Duration::seconds(index),
+            merchant_id: Some(merchant_id.clone()),
+            business_profile_id: Some(profile_id.clone()),
+            primary_object_id: primary_object_id.into(),
+            ..event_for_object(
+                event_id,
+                enums::EventType::PaymentSucceeded,
+                enums::EventClass::Payments,
+            )
+        })
+        .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+
+        // Searches by partial object ID are exported a page at a time using offsets, since they
+        // cannot be paginated using cursors
+        assert_eq!(
+            export_event_ids(
+                &state,
+                &merchant_id,
+                serde_json::json!({ "object_id_contains": "shared" }),
+            )
+            .await,
+            vec!["evt_3", "evt_1"]
+        );
+
+        // Searches by partial object ID are listed a page at a time, and all the matching events
+        // are counted
+        assert_eq!(
+            list_event_ids(
+                &state,
+                &merchant_id,
+                serde_json::json!({ "object_id_contains": "shared", "limit": 1 }),
+            )
+            .await,
+            (vec!["evt_3".to_string()], Some(2), true)
+        );
+        assert_eq!(
+            list_event_ids(
+                &state,
+                &merchant_id,
+                serde_json::json!({ "object_id_contains": "shared", "limit": 1, "offset": 1 }),
+            )
+            .await,
+            (vec!["evt_1".to_string()], Some(2), false)
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_object_id_listing_counts_only_matching_events() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        let created_at = common_utils::date_time::now() - time::Duration::hours(1);
+        let events = [
+            ("evt_1", "shared_object_id"),
+            ("evt_2", "other_object_id"),
+            ("evt_3", "shared_object_id"),
+            ("evt_4", "other_object_id"),
+            ("evt_5", "another_object_id"),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|((event_id, primary_object_id), index)| domain::Event {
+            created_at: created_at + time::Duration::seconds(index),
+            merchant_id: Some(merchant_id.clone()),
+            business_profile_id: Some(profile_id.clone()),
+            primary_object_id: primary_object_id.into(),
+            ..event_for_object(
+                event_id,
+                enums::EventType::PaymentSucceeded,
+                enums::EventClass::Payments,
+            )
+        })
+        .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+
+        // The unrelated events created within the same time window are not counted
+        assert_eq!(
+            list_event_ids(
+                &state,
+                &merchant_id,
+                serde_json::json!({ "object_id": "shared_object_id" }),
+            )
+            .await,
+            (
+                vec!["evt_3".to_string(), "evt_1".to_string()],
+                Some(2),
+                false
+            )
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_sync_rejects_expired_cursor() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let now = common_utils::date_time::now();
+        let events = [
+            ("evt_expired", now - time::Duration::days(100)),
+            ("evt_1", now - time::Duration::days(2)),
+            ("evt_2", now - time::Duration::days(1)),
+        ]
+        .into_iter()
+        .map(|(event_id, created_at)| domain::Event {
+            created_at,
+            merchant_id: Some(merchant_id.clone()),
+            ..event_for_object(
+                event_id,
+                enums::EventType::PaymentSucceeded,
+                enums::EventClass::Payments,
+            )
+        })
+        .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+        let sync = |since_event_id: Option<&str>| {
+            super::sync_events(
+                state.clone(),
+                api::webhook_events::EventSyncRequestInternal::new(
+                    merchant_id.clone(),
+                    api::webhook_events::EventSyncRequest {
+                        since_event_id: since_event_id.map(ToOwned::to_owned),
+                        limit: None,
+                    },
+                ),
+            )
+        };
+
+        let response = sync(Some("evt_1")).await.unwrap().get_json_body().unwrap();
+        assert_eq!(response.last_event_id.as_deref(), Some("evt_2"));
+
+        // Resuming from an event outside the listing window would skip the events created after
+        // it, so the sync must be restarted instead
+        let error = sync(Some("evt_expired")).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            crate::core::errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.contains("`since_event_id` cursor has expired")
+        ));
+        let response = sync(None).await.unwrap().get_json_body().unwrap();
+        assert_eq!(response.events.len(), 2);
+    }
+
+    #[cfg(feature = "v1")]
+    #[tokio::test]
+    async fn test_export_orphaned_events_across_pages() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        // The merchant has no business profiles, so all the events are orphaned
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_orphaned"))
+                .unwrap();
+        let created_at = common_utils::date_time::now() - time::Duration::hours(1);
+        let events = (0..150)
+            .map(|index| domain::Event {
+                created_at: created_at + time::Duration::seconds(index),
+                merchant_id: Some(merchant_id.clone()),
+                business_profile_id: Some(profile_id.clone()),
+                ..event_for_object(
+                    &format!("evt_{index:03}"),
+                    enums::EventType::PaymentSucceeded,
+                    enums::EventClass::Payments,
+                )
+            })
+            .collect();
+        let state = get_mock_state_with_events(&merchant_id, events).await;
+
+        // The orphaned events are exported a page at a time, continuing after the last exported
+        // event
+        assert_eq!(
+            export_event_ids(
+                &state,
+                &merchant_id,
+                serde_json::json!({ "orphaned": true })
+            )
+            .await,
+            (0..150)
+                .rev()
+                .map(|index| format!("evt_{index:03}"))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_idempotency_key_deduplicates_repeated_retries() {
+        use storage_impl::redis::kv_store::RedisConnInterface;
+
+        #[allow(clippy::expect_used)]
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let redis_conn = mockdb.get_redis_conn().unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let idempotency_key = uuid::Uuid::new_v4().to_string();
+
+        // The first retry with the key is accepted
+        assert!(super::super::utils::claim_retry_idempotency_key(
+            &redis_conn,
+            &merchant_id,
+            "evt_1",
+            &idempotency_key,
+        )
+        .await
+        .unwrap());
+
+        // Subsequent retries of the same event with the same key are deduplicated
+        assert!(!super::super::utils::claim_retry_idempotency_key(
+            &redis_conn,
+            &merchant_id,
+            "evt_1",
+            &idempotency_key,
+        )
+        .await
+        .unwrap());
+
+        // The key is scoped to the event being retried
+        assert!(super::super::utils::claim_retry_idempotency_key(
+            &redis_conn,
+            &merchant_id,
+            "evt_2",
+            &idempotency_key,
+        )
+        .await
+        .unwrap());
+    }
+
+    #[test]
+    fn test_set_class_enabled_marks_events_of_disabled_classes() {
+        let profile_id = |profile_id: &str| {
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                profile_id.to_owned(),
+            ))
+            .unwrap()
+        };
+        let event = |event_id: &str, event_class: enums::EventClass, profile: &str| {
+            let event_type = match event_class {
+                enums::EventClass::Disputes => enums::EventType::DisputeOpened,
+                _ => enums::EventType::PaymentSucceeded,
+            };
+            api::webhook_events::EventListItemResponse::foreign_try_from((
+                domain::Event {
+                    merchant_id: Some(
+                        common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                            "merchant_1",
+                        ))
+                        .unwrap(),
+                    ),
+                    business_profile_id: Some(profile_id(profile)),
+                    ..event_for_object(event_id, event_type, event_class)
+                },
+                outcome_context(),
+            ))
+            .unwrap()
+        };
+        let mut events = [
+            event("evt_payment", enums::EventClass::Payments, "pro_1"),
+            event("evt_dispute", enums::EventClass::Disputes, "pro_1"),
+            event(
+                "evt_other_profile_dispute",
+                enums::EventClass::Disputes,
+                "pro_2",
+            ),
+        ];
+        assert!(events.iter().all(|event| event.class_enabled));
+
+        let disabled_event_classes = HashMap::from([(
+            profile_id("pro_1"),
+            HashSet::from([enums::EventClass::Disputes]),
+        )]);
+        super::set_class_enabled(&mut events, &disabled_event_classes);
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.event_id.get_string_repr(), event.class_enabled))
+                .collect::<Vec<_>>(),
+            vec![
+                ("evt_payment", true),
+                ("evt_dispute", false),
+                ("evt_other_profile_dispute", true),
+            ]
+        );
+
+        let value = serde_json::to_value(&events[1]).unwrap();
+        assert_eq!(value.get("class_enabled"), Some(&serde_json::json!(false)));
+    }
+
+    #[test]
+    fn test_list_item_allows_stored_event_ids_not_matching_the_event_id_format() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap();
+        let legacy_event_id = "legacy_event_id".to_string();
+
+        let event = api::webhook_events::EventListItemResponse::foreign_try_from((
+            domain::Event {
+                merchant_id: Some(merchant_id),
+                business_profile_id: Some(profile_id),
+                initial_attempt_id: Some(legacy_event_id.clone()),
+                ..event_for_object(
+                    &legacy_event_id,
+                    enums::EventType::PaymentSucceeded,
+                    enums::EventClass::Payments,
+                )
+            },
+            outcome_context(),
+        ))
+        .unwrap();
+
+        assert_eq!(event.event_id.get_string_repr(), legacy_event_id);
+        assert_eq!(event.initial_attempt_id.get_string_repr(), legacy_event_id);
+    }
 }
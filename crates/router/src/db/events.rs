@@ -43,6 +43,44 @@ where
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    async fn list_initial_events_by_merchant_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_initial_events_by_merchant_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_orphaned_initial_events_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_profile_ids: HashSet<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -55,8 +93,17 @@ where
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -73,6 +120,30 @@ where
         state: &KeyManagerState,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    async fn list_initial_events_by_profile_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_initial_events_by_profile_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -85,8 +156,16 @@ where
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -99,6 +178,7 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -107,7 +187,32 @@ where
         created_before: time::PrimitiveDateTime,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
     ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn count_initial_events_by_primary_object_id_contains(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(common_enums::EventClass, i64)>, errors::StorageError>;
+
+    async fn count_initial_events_by_delivery_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        group_by: common_enums::EventStatsGroupBy,
+    ) -> CustomResult<Vec<storage::EventDeliveryStatusCount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -163,6 +268,7 @@ impl EventInterface for Store {
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -170,6 +276,134 @@ impl EventInterface for Store {
             &conn,
             merchant_id,
             primary_object_id,
+            sort_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_merchant_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_merchant_id_primary_object_ids(
+            &conn,
+            merchant_id,
+            primary_object_ids,
+            sort_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_merchant_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_merchant_id_primary_object_id_contains(
+            &conn,
+            merchant_id,
+            primary_object_id_substring,
+            created_after,
+            created_before,
+            limit,
+            offset,
+            sort_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_orphaned_initial_events_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_profile_ids: HashSet<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_orphaned_initial_attempts_by_merchant_id(
+            &conn,
+            merchant_id,
+            merchant_profile_ids,
+            created_after,
+            created_before,
+            limit,
+            starting_after,
+            sort_by,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -201,8 +435,17 @@ impl EventInterface for Store {
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -213,8 +456,17 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            starting_after,
+            ending_before,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            profile_ids,
+            sort_by,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -278,6 +530,7 @@ impl EventInterface for Store {
         state: &KeyManagerState,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -285,6 +538,89 @@ impl EventInterface for Store {
             &conn,
             profile_id,
             primary_object_id,
+            sort_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_profile_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_profile_id_primary_object_ids(
+            &conn,
+            profile_id,
+            primary_object_ids,
+            sort_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_profile_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_profile_id_primary_object_id_contains(
+            &conn,
+            profile_id,
+            primary_object_id_substring,
+            created_after,
+            created_before,
+            limit,
+            offset,
+            sort_by,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -316,20 +652,37 @@ impl EventInterface for Store {
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
         storage::Event::list_initial_attempts_by_profile_id_constraints(
             &conn,
             profile_id,
+            &merchant_key_store.merchant_id,
             created_after,
             created_before,
             limit,
             offset,
+            starting_after,
+            ending_before,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            sort_by,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -376,6 +729,7 @@ impl EventInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -384,6 +738,12 @@ impl EventInterface for Store {
         created_before: time::PrimitiveDateTime,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
     ) -> CustomResult<i64, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
         storage::Event::count_initial_attempts_by_constraints(
@@ -394,32 +754,88 @@ impl EventInterface for Store {
             created_before,
             event_types,
             is_delivered,
+            merchant_connector_id,
+            delivery_attempts,
+            only_initial_attempts,
+            never_delivered,
+            min_attempts,
+            profile_ids,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
-}
 
-#[async_trait::async_trait]
-impl EventInterface for MockDb {
-    async fn insert_event(
+    #[instrument(skip_all)]
+    async fn count_initial_events_by_primary_object_id_contains(
         &self,
-        state: &KeyManagerState,
-        event: domain::Event,
-        merchant_key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Event, errors::StorageError> {
-        let mut locked_events = self.events.lock().await;
-
-        let stored_event = Conversion::convert(event)
-            .await
-            .change_context(errors::StorageError::EncryptionError)?;
-
-        locked_events.push(stored_event.clone());
-
-        stored_event
-            .convert(
-                state,
-                merchant_key_store.key.get_inner(),
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(common_enums::EventClass, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::count_initial_attempts_by_primary_object_id_contains(
+            &conn,
+            merchant_id,
+            profile_id,
+            primary_object_id_substring,
+            created_after,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_initial_events_by_delivery_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        group_by: common_enums::EventStatsGroupBy,
+    ) -> CustomResult<Vec<storage::EventDeliveryStatusCount>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::count_initial_attempts_by_delivery_status(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            event_types,
+            group_by,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventInterface for MockDb {
+    async fn insert_event(
+        &self,
+        state: &KeyManagerState,
+        event: domain::Event,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError> {
+        let mut locked_events = self.events.lock().await;
+
+        let mut stored_event = Conversion::convert(event)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        // The ingestion time is set by the database when the event is inserted
+        stored_event
+            .ingested_at
+            .get_or_insert_with(common_utils::date_time::now);
+
+        locked_events.push(stored_event.clone());
+
+        stored_event
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -465,16 +881,20 @@ impl EventInterface for MockDb {
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
-        let events = locked_events
+        let filtered_events = locked_events
             .iter()
             .filter(|event| {
                 event.merchant_id == Some(merchant_id.to_owned())
                     && event.initial_attempt_id.as_ref() == Some(&event.event_id)
                     && event.primary_object_id == primary_object_id
             })
+            .collect::<Vec<_>>();
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
             .cloned()
             .collect::<Vec<_>>();
 
@@ -495,57 +915,28 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
-    async fn list_initial_events_by_merchant_id_constraints(
+    async fn list_initial_events_by_merchant_id_primary_object_ids(
         &self,
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
-        created_after: time::PrimitiveDateTime,
-        created_before: time::PrimitiveDateTime,
-        limit: Option<i64>,
-        offset: Option<i64>,
-        event_types: HashSet<common_enums::EventType>,
-        is_delivered: Option<bool>,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
-        let events_iter = locked_events.iter().filter(|event| {
-            let check = event.merchant_id == Some(merchant_id.to_owned())
-                && event.initial_attempt_id.as_ref() == Some(&event.event_id)
-                && (event.created_at >= created_after)
-                && (event.created_at <= created_before)
-                && (event_types.is_empty() || event_types.contains(&event.event_type))
-                && (event.is_overall_delivery_successful == is_delivered);
-
-            check
-        });
-
-        let offset: usize = if let Some(offset) = offset {
-            if offset < 0 {
-                Err(errors::StorageError::MockDbError)?;
-            }
-            offset
-                .try_into()
-                .map_err(|_| errors::StorageError::MockDbError)?
-        } else {
-            0
-        };
-
-        let limit: usize = if let Some(limit) = limit {
-            if limit < 0 {
-                Err(errors::StorageError::MockDbError)?;
-            }
-            limit
-                .try_into()
-                .map_err(|_| errors::StorageError::MockDbError)?
-        } else {
-            usize::MAX
-        };
-
-        let events = events_iter
-            .skip(offset)
-            .take(limit)
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && primary_object_ids.contains(&event.primary_object_id)
+            })
+            .collect::<Vec<_>>();
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
             .cloned()
             .collect::<Vec<_>>();
+
         let mut domain_events = Vec::with_capacity(events.len());
 
         for event in events {
@@ -563,22 +954,48 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
-    async fn list_events_by_merchant_id_initial_attempt_id(
+    async fn list_initial_events_by_merchant_id_primary_object_id_contains(
         &self,
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
-        initial_attempt_id: &str,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
-        let events = locked_events
+        let filtered_events = locked_events
             .iter()
             .filter(|event| {
                 event.merchant_id == Some(merchant_id.to_owned())
-                    && event.initial_attempt_id == Some(initial_attempt_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+                    && event
+                        .primary_object_id
+                        .contains(primary_object_id_substring)
             })
+            .collect::<Vec<_>>();
+        let offset = offset
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(0);
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
+            .skip(offset)
+            .take(limit)
             .cloned()
             .collect::<Vec<_>>();
+
         let mut domain_events = Vec::with_capacity(events.len());
 
         for event in events {
@@ -596,21 +1013,46 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
-    async fn list_initial_events_by_profile_id_primary_object_id(
+    async fn list_orphaned_initial_events_by_merchant_id(
         &self,
         state: &KeyManagerState,
-        profile_id: &common_utils::id_type::ProfileId,
-        primary_object_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_profile_ids: HashSet<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
-        let events = locked_events
+        let filtered_events = locked_events
             .iter()
             .filter(|event| {
-                event.business_profile_id == Some(profile_id.to_owned())
+                event.merchant_id == Some(merchant_id.to_owned())
                     && event.initial_attempt_id.as_ref() == Some(&event.event_id)
-                    && event.primary_object_id == primary_object_id
+                    && event
+                        .business_profile_id
+                        .as_ref()
+                        .is_some_and(|profile_id| !merchant_profile_ids.contains(profile_id))
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
             })
+            .collect::<Vec<_>>();
+
+        let limit: usize = if let Some(limit) = limit {
+            if limit < 0 {
+                Err(errors::StorageError::MockDbError)?;
+            }
+            limit
+                .try_into()
+                .map_err(|_| errors::StorageError::MockDbError)?
+        } else {
+            usize::MAX
+        };
+        let events = apply_mock_ordering(filtered_events, sort_by, starting_after, None)
+            .into_iter()
+            .take(limit)
             .cloned()
             .collect::<Vec<_>>();
 
@@ -631,29 +1073,69 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
-    async fn list_initial_events_by_profile_id_constraints(
+    async fn list_initial_events_by_merchant_id_constraints(
         &self,
         state: &KeyManagerState,
-        profile_id: &common_utils::id_type::ProfileId,
+        merchant_id: &common_utils::id_type::MerchantId,
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
-        let events_iter = locked_events.iter().filter(|event| {
-            let check = event.business_profile_id == Some(profile_id.to_owned())
-                && event.initial_attempt_id.as_ref() == Some(&event.event_id)
-                && (event.created_at >= created_after)
-                && (event.created_at <= created_before)
-                && (event_types.is_empty() || event_types.contains(&event.event_type))
-                && (event.is_overall_delivery_successful == is_delivered);
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                let check = event.merchant_id == Some(merchant_id.to_owned())
+                    && (!only_initial_attempts
+                        || event.initial_attempt_id.as_ref() == Some(&event.event_id))
+                    && delivery_attempts
+                        .as_ref()
+                        .map_or(true, |delivery_attempts| {
+                            event
+                                .delivery_attempt
+                                .is_some_and(|attempt| delivery_attempts.contains(&attempt))
+                        })
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+                    && (event_types.is_empty() || event_types.contains(&event.event_type))
+                    && is_delivered.map_or(true, |is_delivered| {
+                        event.is_overall_delivery_successful == Some(is_delivered)
+                    })
+                    && (!never_delivered || is_mock_never_delivered(&locked_events, event))
+                    && is_mock_min_attempts_matching(&locked_events, event, min_attempts)
+                    && merchant_connector_id
+                        .as_ref()
+                        .map_or(true, |merchant_connector_id| {
+                            event.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+                        })
+                    && profile_ids.as_ref().map_or(true, |profile_ids| {
+                        event
+                            .business_profile_id
+                            .as_ref()
+                            .is_some_and(|profile_id| profile_ids.contains(profile_id))
+                    });
+
+                check
+            })
+            .collect::<Vec<_>>();
 
-            check
-        });
+        let is_ending_before = ending_before.is_some();
+        let events_iter =
+            apply_mock_ordering(filtered_events, sort_by, starting_after, ending_before)
+                .into_iter();
 
         let offset: usize = if let Some(offset) = offset {
             if offset < 0 {
@@ -677,11 +1159,14 @@ impl EventInterface for MockDb {
             usize::MAX
         };
 
-        let events = events_iter
+        let mut events = events_iter
             .skip(offset)
             .take(limit)
             .cloned()
             .collect::<Vec<_>>();
+        if is_ending_before {
+            events.reverse();
+        }
         let mut domain_events = Vec::with_capacity(events.len());
 
         for event in events {
@@ -699,78 +1184,575 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
-    async fn update_event_by_merchant_id_event_id(
+    async fn list_events_by_merchant_id_initial_attempt_id(
         &self,
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
-        event_id: &str,
-        event: domain::EventUpdate,
+        initial_attempt_id: &str,
         merchant_key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Event, errors::StorageError> {
-        let mut locked_events = self.events.lock().await;
-        let event_to_update = locked_events
-            .iter_mut()
-            .find(|event| {
-                event.merchant_id == Some(merchant_id.to_owned()) && event.event_id == event_id
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.initial_attempt_id == Some(initial_attempt_id.to_owned())
             })
-            .ok_or(errors::StorageError::MockDbError)?;
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut domain_events = Vec::with_capacity(events.len());
 
-        match event {
-            domain::EventUpdate::UpdateResponse {
-                is_webhook_notified,
-                response,
-            } => {
-                event_to_update.is_webhook_notified = is_webhook_notified;
-                event_to_update.response = response.map(Into::into);
-            }
-            domain::EventUpdate::OverallDeliveryStatusUpdate {
-                is_overall_delivery_successful,
-            } => {
-                event_to_update.is_overall_delivery_successful =
-                    Some(is_overall_delivery_successful)
-            }
+        for event in events {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
         }
 
-        event_to_update
-            .clone()
-            .convert(
-                state,
-                merchant_key_store.key.get_inner(),
-                merchant_key_store.merchant_id.clone().into(),
-            )
-            .await
-            .change_context(errors::StorageError::DecryptionError)
+        Ok(domain_events)
     }
 
-    async fn count_initial_events_by_constraints(
+    async fn list_initial_events_by_profile_id_primary_object_id(
         &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        profile_id: Option<common_utils::id_type::ProfileId>,
-        created_after: time::PrimitiveDateTime,
-        created_before: time::PrimitiveDateTime,
-        event_types: HashSet<common_enums::EventType>,
-        is_delivered: Option<bool>,
-    ) -> CustomResult<i64, errors::StorageError> {
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id == Some(profile_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && event.primary_object_id == primary_object_id
+            })
+            .collect::<Vec<_>>();
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
 
-        let iter_events = locked_events.iter().filter(|event| {
-            let check = event.initial_attempt_id.as_ref() == Some(&event.event_id)
-                && (event.merchant_id == Some(merchant_id.to_owned()))
-                && (event.business_profile_id == profile_id)
-                && (event.created_at >= created_after)
-                && (event.created_at <= created_before)
-                && (event_types.is_empty() || event_types.contains(&event.event_type))
-                && (event.is_overall_delivery_successful == is_delivered);
+        let mut domain_events = Vec::with_capacity(events.len());
 
-            check
-        });
+        for event in events {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
 
-        let events = iter_events.cloned().collect::<Vec<_>>();
+        Ok(domain_events)
+    }
+
+    async fn list_initial_events_by_profile_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id == Some(profile_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && primary_object_ids.contains(&event.primary_object_id)
+            })
+            .collect::<Vec<_>>();
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn list_initial_events_by_profile_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id == Some(profile_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+                    && event
+                        .primary_object_id
+                        .contains(primary_object_id_substring)
+            })
+            .collect::<Vec<_>>();
+        let offset = offset
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(0);
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+        let events = apply_mock_ordering(filtered_events, sort_by, None, None)
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn list_initial_events_by_profile_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        ending_before: Option<(time::PrimitiveDateTime, String)>,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let filtered_events = locked_events
+            .iter()
+            .filter(|event| {
+                let check = event.business_profile_id == Some(profile_id.to_owned())
+                    && (!only_initial_attempts
+                        || event.initial_attempt_id.as_ref() == Some(&event.event_id))
+                    && delivery_attempts
+                        .as_ref()
+                        .map_or(true, |delivery_attempts| {
+                            event
+                                .delivery_attempt
+                                .is_some_and(|attempt| delivery_attempts.contains(&attempt))
+                        })
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+                    && (event_types.is_empty() || event_types.contains(&event.event_type))
+                    && is_delivered.map_or(true, |is_delivered| {
+                        event.is_overall_delivery_successful == Some(is_delivered)
+                    })
+                    && (!never_delivered || is_mock_never_delivered(&locked_events, event))
+                    && is_mock_min_attempts_matching(&locked_events, event, min_attempts)
+                    && merchant_connector_id
+                        .as_ref()
+                        .map_or(true, |merchant_connector_id| {
+                            event.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+                        });
+
+                check
+            })
+            .collect::<Vec<_>>();
+
+        let is_ending_before = ending_before.is_some();
+        let events_iter =
+            apply_mock_ordering(filtered_events, sort_by, starting_after, ending_before)
+                .into_iter();
+
+        let offset: usize = if let Some(offset) = offset {
+            if offset < 0 {
+                Err(errors::StorageError::MockDbError)?;
+            }
+            offset
+                .try_into()
+                .map_err(|_| errors::StorageError::MockDbError)?
+        } else {
+            0
+        };
+
+        let limit: usize = if let Some(limit) = limit {
+            if limit < 0 {
+                Err(errors::StorageError::MockDbError)?;
+            }
+            limit
+                .try_into()
+                .map_err(|_| errors::StorageError::MockDbError)?
+        } else {
+            usize::MAX
+        };
+
+        let mut events = events_iter
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect::<Vec<_>>();
+        if is_ending_before {
+            events.reverse();
+        }
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn update_event_by_merchant_id_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        event_id: &str,
+        event: domain::EventUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError> {
+        let mut locked_events = self.events.lock().await;
+        let event_to_update = locked_events
+            .iter_mut()
+            .find(|event| {
+                event.merchant_id == Some(merchant_id.to_owned()) && event.event_id == event_id
+            })
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        match event {
+            domain::EventUpdate::UpdateResponse {
+                is_webhook_notified,
+                response,
+            } => {
+                event_to_update.is_webhook_notified = is_webhook_notified;
+                event_to_update.response = response.map(Into::into);
+            }
+            domain::EventUpdate::OverallDeliveryStatusUpdate {
+                is_overall_delivery_successful,
+            } => {
+                event_to_update.is_overall_delivery_successful =
+                    Some(is_overall_delivery_successful)
+            }
+            domain::EventUpdate::TagsUpdate { tags } => event_to_update.tags = Some(tags),
+        }
+
+        event_to_update
+            .clone()
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn count_initial_events_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let iter_events = locked_events.iter().filter(|event| {
+            let check = (!only_initial_attempts
+                || event.initial_attempt_id.as_ref() == Some(&event.event_id))
+                && delivery_attempts
+                    .as_ref()
+                    .map_or(true, |delivery_attempts| {
+                        event
+                            .delivery_attempt
+                            .is_some_and(|attempt| delivery_attempts.contains(&attempt))
+                    })
+                && (event.merchant_id == Some(merchant_id.to_owned()))
+                && profile_id.as_ref().map_or(true, |profile_id| {
+                    event.business_profile_id.as_ref() == Some(profile_id)
+                })
+                && (event.created_at >= created_after)
+                && (event.created_at <= created_before)
+                && (event_types.is_empty() || event_types.contains(&event.event_type))
+                && is_delivered.map_or(true, |is_delivered| {
+                    event.is_overall_delivery_successful == Some(is_delivered)
+                })
+                && (!never_delivered || is_mock_never_delivered(&locked_events, event))
+                && is_mock_min_attempts_matching(&locked_events, event, min_attempts)
+                && merchant_connector_id
+                    .as_ref()
+                    .map_or(true, |merchant_connector_id| {
+                        event.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+                    })
+                && profile_ids.as_ref().map_or(true, |profile_ids| {
+                    event
+                        .business_profile_id
+                        .as_ref()
+                        .is_some_and(|profile_id| profile_ids.contains(profile_id))
+                });
+
+            check
+        });
+
+        let events = iter_events.cloned().collect::<Vec<_>>();
 
         i64::try_from(events.len())
             .change_context(errors::StorageError::MockDbError)
             .attach_printable("Failed to convert usize to i64")
     }
+
+    async fn count_initial_events_by_primary_object_id_contains(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        primary_object_id_substring: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(common_enums::EventClass, i64)>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let mut class_counts: Vec<(common_enums::EventClass, i64)> = Vec::new();
+        for event in locked_events.iter().filter(|event| {
+            event.merchant_id == Some(merchant_id.to_owned())
+                && profile_id.as_ref().map_or(true, |profile_id| {
+                    event.business_profile_id.as_ref() == Some(profile_id)
+                })
+                && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                && (event.created_at >= created_after)
+                && (event.created_at <= created_before)
+                && event
+                    .primary_object_id
+                    .contains(primary_object_id_substring)
+        }) {
+            match class_counts
+                .iter_mut()
+                .find(|(event_class, _)| *event_class == event.event_class)
+            {
+                Some((_, count)) => *count = count.saturating_add(1),
+                None => class_counts.push((event.event_class, 1)),
+            }
+        }
+
+        Ok(class_counts)
+    }
+
+    async fn count_initial_events_by_delivery_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        group_by: common_enums::EventStatsGroupBy,
+    ) -> CustomResult<Vec<storage::EventDeliveryStatusCount>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let mut counts = Vec::<storage::EventDeliveryStatusCount>::new();
+        for event in locked_events.iter().filter(|event| {
+            event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                && (event.merchant_id == Some(merchant_id.to_owned()))
+                && profile_id.as_ref().map_or(true, |profile_id| {
+                    event.business_profile_id.as_ref() == Some(profile_id)
+                })
+                && (event.created_at >= created_after)
+                && (event.created_at <= created_before)
+                && (event_types.is_empty() || event_types.contains(&event.event_type))
+        }) {
+            // Mirrors the grouping applied by the database query, which only sets the attribute
+            // the events are grouped by
+            let mut group = storage::EventDeliveryStatusCount {
+                event_type: None,
+                event_class: None,
+                business_profile_id: None,
+                created_day: None,
+                is_overall_delivery_successful: event.is_overall_delivery_successful,
+                attempts_count: 0,
+                responses_count: 0,
+            };
+            match group_by {
+                common_enums::EventStatsGroupBy::EventType => {
+                    group.event_type = Some(event.event_type)
+                }
+                common_enums::EventStatsGroupBy::EventClass => {
+                    group.event_class = Some(event.event_class)
+                }
+                common_enums::EventStatsGroupBy::Profile => {
+                    group.business_profile_id = event.business_profile_id.clone()
+                }
+                common_enums::EventStatsGroupBy::Day => {
+                    group.created_day = Some(event.created_at.date().midnight())
+                }
+            }
+
+            let group_index = counts
+                .iter()
+                .position(|count| {
+                    count.event_type == group.event_type
+                        && count.event_class == group.event_class
+                        && count.business_profile_id == group.business_profile_id
+                        && count.created_day == group.created_day
+                        && count.is_overall_delivery_successful
+                            == group.is_overall_delivery_successful
+                })
+                .unwrap_or_else(|| {
+                    counts.push(group);
+                    counts.len() - 1
+                });
+            if let Some(count) = counts.get_mut(group_index) {
+                count.attempts_count += 1;
+                if event.response.is_some() {
+                    count.responses_count += 1;
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+}
+
+/// Mirrors the ordering and keyset pagination applied by the database queries on
+/// `(created_at, event_id)`. Events preceding the `ending_before` cursor are returned in the
+/// reverse order, so that the page closest to the cursor is picked.
+fn apply_mock_ordering(
+    mut events: Vec<&storage::events::Event>,
+    sort_by: common_enums::EventSortBy,
+    starting_after: Option<(time::PrimitiveDateTime, String)>,
+    ending_before: Option<(time::PrimitiveDateTime, String)>,
+) -> Vec<&storage::events::Event> {
+    let is_sort_descending = matches!(sort_by, common_enums::EventSortBy::CreatedDesc);
+    let (cursor, is_descending) = match (starting_after, ending_before) {
+        (Some(cursor), _) => (Some(cursor), is_sort_descending),
+        (None, Some(cursor)) => (Some(cursor), !is_sort_descending),
+        (None, None) => (None, is_sort_descending),
+    };
+
+    if let Some((created_at, event_id)) = cursor {
+        events.retain(|event| {
+            let key = (event.created_at, &event.event_id);
+            if is_descending {
+                key < (created_at, &event_id)
+            } else {
+                key > (created_at, &event_id)
+            }
+        });
+    }
+
+    events.sort_by(|a, b| {
+        let ordering = (a.created_at, &a.event_id).cmp(&(b.created_at, &b.event_id));
+        if is_descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    events
+}
+
+/// Mirrors the never delivered filter applied by the database queries, which excludes the
+/// delivery chains containing a successful delivery attempt.
+fn is_mock_never_delivered(
+    events: &[storage::events::Event],
+    event: &storage::events::Event,
+) -> bool {
+    event.is_overall_delivery_successful == Some(false)
+        && event
+            .initial_attempt_id
+            .as_ref()
+            .is_some_and(|initial_attempt_id| {
+                !events.iter().any(|attempt| {
+                    attempt.is_webhook_notified
+                        && attempt.initial_attempt_id.as_ref() == Some(initial_attempt_id)
+                })
+            })
+}
+
+/// Mirrors the minimum delivery attempts filter applied by the database queries, which counts
+/// the delivery attempts across the delivery chain of the event.
+fn is_mock_min_attempts_matching(
+    events: &[storage::events::Event],
+    event: &storage::events::Event,
+    min_attempts: Option<u16>,
+) -> bool {
+    min_attempts
+        .filter(|min_attempts| *min_attempts > 1)
+        .map_or(true, |min_attempts| {
+            event
+                .initial_attempt_id
+                .as_ref()
+                .is_some_and(|initial_attempt_id| {
+                    events
+                        .iter()
+                        .filter(|attempt| {
+                            attempt.initial_attempt_id.as_ref() == Some(initial_attempt_id)
+                        })
+                        .count()
+                        >= usize::from(min_attempts)
+                })
+        })
 }
 
 #[cfg(test)]
@@ -881,8 +1863,14 @@ mod tests {
                             std::borrow::Cow::Borrowed(payment_id),
                         )
                         .unwrap(),
+                        connector: None,
                     }),
                     is_overall_delivery_successful: Some(false),
+                    merchant_connector_id: None,
+                    tags: None,
+                    triggered_by: None,
+                    ingested_at: None,
+                    primary_object_updated_at: None,
                 },
                 &merchant_key_store,
             )
@@ -908,5 +1896,1306 @@ mod tests {
         assert!(updated_event.is_webhook_notified);
         assert_eq!(updated_event.primary_object_id, payment_id);
         assert_eq!(updated_event.event_id, event_id);
+        assert_eq!(updated_event.tags, None);
+
+        let tagged_event = mockdb
+            .update_event_by_merchant_id_event_id(
+                key_manager_state,
+                &merchant_id,
+                event_id,
+                domain::EventUpdate::TagsUpdate {
+                    tags: vec!["under-investigation".to_string()],
+                },
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tagged_event.tags,
+            Some(vec!["under-investigation".to_string()])
+        );
+        // Tagging an event leaves its delivery status unchanged
+        assert!(tagged_event.is_webhook_notified);
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_events_count_matches_list() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let created_after = common_utils::date_time::now();
+        for (index, is_overall_delivery_successful) in [true, false, true].into_iter().enumerate() {
+            let event_id = format!("test_event_id_{index}");
+            let payment_id = format!("test_payment_id_{index}");
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.clone(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified: false,
+                        primary_object_id: payment_id.clone(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.clone()),
+                        initial_attempt_id: Some(event_id),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                        metadata: Some(EventMetadata::Payment {
+                            payment_id: common_utils::id_type::PaymentId::try_from(
+                                std::borrow::Cow::Owned(payment_id),
+                            )
+                            .unwrap(),
+                            connector: None,
+                        }),
+                        is_overall_delivery_successful: Some(is_overall_delivery_successful),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+        let created_before = common_utils::date_time::now();
+
+        let events = mockdb
+            .list_initial_events_by_merchant_id_constraints(
+                key_manager_state,
+                &merchant_id,
+                created_after,
+                created_before,
+                None,
+                None,
+                None,
+                None,
+                std::collections::HashSet::new(),
+                None,
+                None,
+                None,
+                true,
+                false,
+                None,
+                None,
+                enums::EventSortBy::default(),
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+        let total_count = mockdb
+            .count_initial_events_by_constraints(
+                &merchant_id,
+                None,
+                created_after,
+                created_before,
+                std::collections::HashSet::new(),
+                None,
+                None,
+                None,
+                true,
+                false,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(total_count, i64::try_from(events.len()).unwrap());
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_events_by_delivery_attempts() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let created_after = common_utils::date_time::now();
+        let initial_attempt_id = "test_event_id_initial".to_string();
+        for (event_id, delivery_attempt) in [
+            (
+                initial_attempt_id.clone(),
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+            ),
+            (
+                "test_event_id_automatic_retry".to_string(),
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+            ),
+            (
+                "test_event_id_manual_retry".to_string(),
+                enums::WebhookDeliveryAttempt::ManualRetry,
+            ),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.clone(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified: false,
+                        primary_object_id: "test_payment_id".into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id),
+                        initial_attempt_id: Some(initial_attempt_id.clone()),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(delivery_attempt),
+                        metadata: None,
+                        is_overall_delivery_successful: Some(false),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+        let created_before = common_utils::date_time::now();
+
+        for (delivery_attempts, only_initial_attempts, expected_event_ids) in [
+            (None, true, vec!["test_event_id_initial"]),
+            (
+                None,
+                false,
+                vec![
+                    "test_event_id_initial",
+                    "test_event_id_automatic_retry",
+                    "test_event_id_manual_retry",
+                ],
+            ),
+            (
+                Some(std::collections::HashSet::from([
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                ])),
+                false,
+                vec!["test_event_id_manual_retry"],
+            ),
+            (
+                Some(std::collections::HashSet::from([
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                ])),
+                true,
+                vec![],
+            ),
+            (
+                Some(std::collections::HashSet::from([
+                    enums::WebhookDeliveryAttempt::AutomaticRetry,
+                    enums::WebhookDeliveryAttempt::ManualRetry,
+                ])),
+                false,
+                vec![
+                    "test_event_id_automatic_retry",
+                    "test_event_id_manual_retry",
+                ],
+            ),
+        ] {
+            let events = mockdb
+                .list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    &merchant_id,
+                    created_after,
+                    created_before,
+                    None,
+                    None,
+                    None,
+                    None,
+                    std::collections::HashSet::new(),
+                    None,
+                    None,
+                    delivery_attempts.clone(),
+                    only_initial_attempts,
+                    false,
+                    None,
+                    None,
+                    enums::EventSortBy::CreatedAsc,
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+            let total_count = mockdb
+                .count_initial_events_by_constraints(
+                    &merchant_id,
+                    None,
+                    created_after,
+                    created_before,
+                    std::collections::HashSet::new(),
+                    None,
+                    None,
+                    delivery_attempts,
+                    only_initial_attempts,
+                    false,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| event.event_id.as_str())
+                    .collect::<Vec<_>>(),
+                expected_event_ids
+            );
+            assert_eq!(total_count, i64::try_from(events.len()).unwrap());
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_never_delivered_events() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let created_after = common_utils::date_time::now();
+        // The first delivery chain succeeds on its automatic retry, while every attempt of the
+        // second delivery chain fails.
+        for (event_id, initial_attempt_id, delivery_attempt, is_webhook_notified) in [
+            (
+                "test_event_id_late_success",
+                "test_event_id_late_success",
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+                false,
+            ),
+            (
+                "test_event_id_late_success_retry",
+                "test_event_id_late_success",
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+                true,
+            ),
+            (
+                "test_event_id_never_delivered",
+                "test_event_id_never_delivered",
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+                false,
+            ),
+            (
+                "test_event_id_never_delivered_retry",
+                "test_event_id_never_delivered",
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+                false,
+            ),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.into(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified,
+                        primary_object_id: "test_payment_id".into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.into()),
+                        initial_attempt_id: Some(initial_attempt_id.into()),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(delivery_attempt),
+                        metadata: None,
+                        is_overall_delivery_successful: Some(false),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+        let created_before = common_utils::date_time::now();
+
+        for (never_delivered, expected_event_ids) in [
+            (
+                false,
+                vec![
+                    "test_event_id_late_success",
+                    "test_event_id_never_delivered",
+                ],
+            ),
+            (true, vec!["test_event_id_never_delivered"]),
+        ] {
+            let events = mockdb
+                .list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    &merchant_id,
+                    created_after,
+                    created_before,
+                    None,
+                    None,
+                    None,
+                    None,
+                    std::collections::HashSet::new(),
+                    Some(false),
+                    None,
+                    None,
+                    true,
+                    never_delivered,
+                    None,
+                    None,
+                    enums::EventSortBy::CreatedAsc,
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+            let total_count = mockdb
+                .count_initial_events_by_constraints(
+                    &merchant_id,
+                    None,
+                    created_after,
+                    created_before,
+                    std::collections::HashSet::new(),
+                    Some(false),
+                    None,
+                    None,
+                    true,
+                    never_delivered,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| event.event_id.as_str())
+                    .collect::<Vec<_>>(),
+                expected_event_ids
+            );
+            assert_eq!(total_count, i64::try_from(events.len()).unwrap());
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_events_by_min_attempts() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let created_after = common_utils::date_time::now();
+        // The first delivery chain succeeds on its initial attempt, while the second delivery
+        // chain only succeeds on its third retry.
+        for (event_id, initial_attempt_id, delivery_attempt, is_webhook_notified) in [
+            (
+                "test_event_id_single_attempt",
+                "test_event_id_single_attempt",
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+                true,
+            ),
+            (
+                "test_event_id_flaky",
+                "test_event_id_flaky",
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+                false,
+            ),
+            (
+                "test_event_id_flaky_retry_1",
+                "test_event_id_flaky",
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+                false,
+            ),
+            (
+                "test_event_id_flaky_retry_2",
+                "test_event_id_flaky",
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+                false,
+            ),
+            (
+                "test_event_id_flaky_retry_3",
+                "test_event_id_flaky",
+                enums::WebhookDeliveryAttempt::AutomaticRetry,
+                true,
+            ),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.into(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified,
+                        primary_object_id: "test_payment_id".into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.into()),
+                        initial_attempt_id: Some(initial_attempt_id.into()),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(delivery_attempt),
+                        metadata: None,
+                        is_overall_delivery_successful: Some(true),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+        let created_before = common_utils::date_time::now();
+
+        for (min_attempts, expected_event_ids) in [
+            (
+                None,
+                vec!["test_event_id_single_attempt", "test_event_id_flaky"],
+            ),
+            (
+                Some(1),
+                vec!["test_event_id_single_attempt", "test_event_id_flaky"],
+            ),
+            (Some(2), vec!["test_event_id_flaky"]),
+            (Some(4), vec!["test_event_id_flaky"]),
+            (Some(5), vec![]),
+        ] {
+            let events = mockdb
+                .list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    &merchant_id,
+                    created_after,
+                    created_before,
+                    None,
+                    None,
+                    None,
+                    None,
+                    std::collections::HashSet::new(),
+                    None,
+                    None,
+                    None,
+                    true,
+                    false,
+                    min_attempts,
+                    None,
+                    enums::EventSortBy::CreatedAsc,
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+            let total_count = mockdb
+                .count_initial_events_by_constraints(
+                    &merchant_id,
+                    None,
+                    created_after,
+                    created_before,
+                    std::collections::HashSet::new(),
+                    None,
+                    None,
+                    None,
+                    true,
+                    false,
+                    min_attempts,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| event.event_id.as_str())
+                    .collect::<Vec<_>>(),
+                expected_event_ids
+            );
+            assert_eq!(total_count, i64::try_from(events.len()).unwrap());
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_consecutive_event_syncs() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let insert_events = |events: Vec<(&'static str, time::PrimitiveDateTime)>| {
+            let mockdb = &mockdb;
+            let merchant_id = &merchant_id;
+            let business_profile_id = &business_profile_id;
+            let merchant_key_store = &merchant_key_store;
+            async move {
+                for (event_id, created_at) in events {
+                    mockdb
+                        .insert_event(
+                            key_manager_state,
+                            domain::Event {
+                                event_id: event_id.into(),
+                                event_type: enums::EventType::PaymentSucceeded,
+                                event_class: enums::EventClass::Payments,
+                                is_webhook_notified: true,
+                                primary_object_id: "test_payment_id".into(),
+                                primary_object_type: enums::EventObjectType::PaymentDetails,
+                                created_at,
+                                merchant_id: Some(merchant_id.to_owned()),
+                                business_profile_id: Some(business_profile_id.to_owned()),
+                                primary_object_created_at: Some(created_at),
+                                idempotent_event_id: Some(event_id.into()),
+                                initial_attempt_id: Some(event_id.into()),
+                                request: None,
+                                response: None,
+                                delivery_attempt: Some(
+                                    enums::WebhookDeliveryAttempt::InitialAttempt,
+                                ),
+                                metadata: None,
+                                is_overall_delivery_successful: Some(true),
+                                merchant_connector_id: None,
+                                tags: None,
+                                triggered_by: None,
+                                ingested_at: None,
+                                primary_object_updated_at: None,
+                            },
+                            merchant_key_store,
+                        )
+                        .await
+                        .unwrap();
+                }
+            }
+        };
+        // Mirrors the listing performed by the events sync, which resumes strictly after the
+        // `(created_at, event_id)` of the event synced last.
+        let sync = |since_event: Option<(time::PrimitiveDateTime, String)>| {
+            let mockdb = &mockdb;
+            let merchant_id = &merchant_id;
+            let merchant_key_store = &merchant_key_store;
+            async move {
+                mockdb
+                    .list_initial_events_by_merchant_id_constraints(
+                        key_manager_state,
+                        merchant_id,
+                        since_event
+                            .as_ref()
+                            .map_or(datetime!(2024-01-01 0:00), |(created_at, _)| *created_at),
+                        common_utils::date_time::now(),
+                        Some(2),
+                        None,
+                        since_event,
+                        None,
+                        std::collections::HashSet::new(),
+                        None,
+                        None,
+                        None,
+                        true,
+                        false,
+                        None,
+                        None,
+                        enums::EventSortBy::CreatedAsc,
+                        merchant_key_store,
+                    )
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|event| (event.created_at, event.event_id))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        insert_events(vec![
+            ("evt_1", datetime!(2024-01-01 0:01)),
+            ("evt_2", datetime!(2024-01-01 0:02)),
+            ("evt_3", datetime!(2024-01-01 0:02)),
+        ])
+        .await;
+        let first_sync = sync(None).await;
+
+        // Events created at the same time as the last synced event, as well as events created
+        // afterwards, are picked up by the next sync.
+        insert_events(vec![
+            ("evt_4", datetime!(2024-01-01 0:02)),
+            ("evt_5", datetime!(2024-01-01 0:03)),
+        ])
+        .await;
+        let second_sync = sync(first_sync.last().cloned()).await;
+        let third_sync = sync(second_sync.last().cloned()).await;
+        let fourth_sync = sync(third_sync.last().cloned()).await;
+
+        let synced_event_ids = |events: &[(time::PrimitiveDateTime, String)]| {
+            events
+                .iter()
+                .map(|(_, event_id)| event_id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(synced_event_ids(&first_sync), vec!["evt_1", "evt_2"]);
+        assert_eq!(synced_event_ids(&second_sync), vec!["evt_3", "evt_4"]);
+        assert_eq!(synced_event_ids(&third_sync), vec!["evt_5"]);
+        assert!(fourth_sync.is_empty());
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_events_by_primary_object_id_substring() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        for (event_id, primary_object_id) in [
+            ("test_event_id_1", "pay_mbabizu24mvu3mela5njyhpit4"),
+            ("test_event_id_2", "pay_ghuj6vi4nntdlzzmzcuqxcpvdi"),
+            ("test_event_id_3", "pay_mbabizu2_other"),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.into(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified: false,
+                        primary_object_id: primary_object_id.into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id: Some(business_profile_id.to_owned()),
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.into()),
+                        initial_attempt_id: Some(event_id.into()),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                        metadata: None,
+                        is_overall_delivery_successful: Some(false),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+
+        let created_before = common_utils::date_time::now();
+        let created_after = created_before - time::Duration::hours(1);
+        for (primary_object_id_substring, limit, offset, expected_event_ids) in [
+            (
+                "mbabizu2",
+                None,
+                None,
+                vec!["test_event_id_1", "test_event_id_3"],
+            ),
+            ("mbabizu2", Some(1), Some(1), vec!["test_event_id_3"]),
+            ("nntdlzz", None, None, vec!["test_event_id_2"]),
+            ("unknown", None, None, vec![]),
+        ] {
+            let events = mockdb
+                .list_initial_events_by_merchant_id_primary_object_id_contains(
+                    key_manager_state,
+                    &merchant_id,
+                    primary_object_id_substring,
+                    created_after,
+                    created_before,
+                    limit,
+                    offset,
+                    enums::EventSortBy::CreatedAsc,
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| event.event_id.as_str())
+                    .collect::<Vec<_>>(),
+                expected_event_ids
+            );
+        }
+
+        assert_eq!(
+            mockdb
+                .count_initial_events_by_primary_object_id_contains(
+                    &merchant_id,
+                    Some(business_profile_id),
+                    "mbabizu2",
+                    created_after,
+                    created_before,
+                )
+                .await
+                .unwrap(),
+            vec![(enums::EventClass::Payments, 2)]
+        );
+
+        // Events created outside the time range are not listed
+        assert!(mockdb
+            .list_initial_events_by_merchant_id_primary_object_id_contains(
+                key_manager_state,
+                &merchant_id,
+                "mbabizu2",
+                created_after - time::Duration::hours(1),
+                created_after,
+                None,
+                None,
+                enums::EventSortBy::CreatedAsc,
+                &merchant_key_store,
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_list_orphaned_events() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let business_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile1")).unwrap();
+        let orphaned_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("other_profile"))
+                .unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        // The second event is deliberately associated with a business profile which does not
+        // belong to the merchant
+        for (event_id, business_profile_id) in [
+            ("test_event_id_1", Some(business_profile_id.to_owned())),
+            ("test_event_id_2", Some(orphaned_profile_id.to_owned())),
+            ("test_event_id_3", None),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    domain::Event {
+                        event_id: event_id.into(),
+                        event_type: enums::EventType::PaymentSucceeded,
+                        event_class: enums::EventClass::Payments,
+                        is_webhook_notified: false,
+                        primary_object_id: "primary_object_tet".into(),
+                        primary_object_type: enums::EventObjectType::PaymentDetails,
+                        created_at: datetime!(2023-02-01 0:00),
+                        merchant_id: Some(merchant_id.to_owned()),
+                        business_profile_id,
+                        primary_object_created_at: Some(common_utils::date_time::now()),
+                        idempotent_event_id: Some(event_id.into()),
+                        initial_attempt_id: Some(event_id.into()),
+                        request: None,
+                        response: None,
+                        delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                        metadata: None,
+                        is_overall_delivery_successful: Some(false),
+                        merchant_connector_id: None,
+                        tags: None,
+                        triggered_by: None,
+                        ingested_at: None,
+                        primary_object_updated_at: None,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+
+        let orphaned_events = mockdb
+            .list_orphaned_initial_events_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                std::collections::HashSet::from([business_profile_id]),
+                datetime!(2023-01-01 0:00),
+                datetime!(2023-03-01 0:00),
+                None,
+                None,
+                enums::EventSortBy::CreatedAsc,
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            orphaned_events
+                .iter()
+                .map(|event| event.event_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["test_event_id_2"]
+        );
+        assert_eq!(
+            orphaned_events
+                .first()
+                .and_then(|event| event.business_profile_id.as_ref()),
+            Some(&orphaned_profile_id)
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_find_unknown_event_id_is_not_found() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let key_manager_state = &state.into();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.to_owned()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        // Listing events by an unknown event ID relies on the lookup failing with a not found
+        // error, which is listed as an empty list of events with a total count of zero.
+        let error = mockdb
+            .find_event_by_merchant_id_event_id(
+                key_manager_state,
+                &merchant_id,
+                "evt_unknown",
+                &merchant_key_store,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(error.current_context().is_db_not_found());
     }
 }
@@ -753,6 +753,7 @@ impl EventInterface for KafkaStore {
         state: &KeyManagerState,
         merchant_id: &id_type::MerchantId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -760,6 +761,80 @@ impl EventInterface for KafkaStore {
                 state,
                 merchant_id,
                 primary_object_id,
+                sort_by,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_initial_events_by_merchant_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_merchant_id_primary_object_ids(
+                state,
+                merchant_id,
+                primary_object_ids,
+                sort_by,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_initial_events_by_merchant_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        primary_object_id_substring: &str,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_merchant_id_primary_object_id_contains(
+                state,
+                merchant_id,
+                primary_object_id_substring,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                sort_by,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_orphaned_initial_events_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        merchant_profile_ids: HashSet<id_type::ProfileId>,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        limit: Option<i64>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_orphaned_initial_events_by_merchant_id(
+                state,
+                merchant_id,
+                merchant_profile_ids,
+                created_after,
+                created_before,
+                limit,
+                starting_after,
+                sort_by,
                 merchant_key_store,
             )
             .await
@@ -773,8 +848,17 @@ impl EventInterface for KafkaStore {
         created_before: PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
+        ending_before: Option<(PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -785,8 +869,17 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                starting_after,
+                ending_before,
                 event_types,
                 is_delivered,
+                merchant_connector_id,
+                delivery_attempts,
+                only_initial_attempts,
+                never_delivered,
+                min_attempts,
+                profile_ids,
+                sort_by,
                 merchant_key_store,
             )
             .await
@@ -814,6 +907,7 @@ impl EventInterface for KafkaStore {
         state: &KeyManagerState,
         profile_id: &id_type::ProfileId,
         primary_object_id: &str,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -821,6 +915,53 @@ impl EventInterface for KafkaStore {
                 state,
                 profile_id,
                 primary_object_id,
+                sort_by,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_initial_events_by_profile_id_primary_object_ids(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        primary_object_ids: &[String],
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_profile_id_primary_object_ids(
+                state,
+                profile_id,
+                primary_object_ids,
+                sort_by,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_initial_events_by_profile_id_primary_object_id_contains(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        primary_object_id_substring: &str,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        sort_by: common_enums::EventSortBy,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_profile_id_primary_object_id_contains(
+                state,
+                profile_id,
+                primary_object_id_substring,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                sort_by,
                 merchant_key_store,
             )
             .await
@@ -834,8 +975,16 @@ impl EventInterface for KafkaStore {
         created_before: PrimitiveDateTime,
         limit: Option<i64>,
         offset: Option<i64>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
+        ending_before: Option<(PrimitiveDateTime, String)>,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        sort_by: common_enums::EventSortBy,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -846,8 +995,16 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                starting_after,
+                ending_before,
                 event_types,
                 is_delivered,
+                merchant_connector_id,
+                delivery_attempts,
+                only_initial_attempts,
+                never_delivered,
+                min_attempts,
+                sort_by,
                 merchant_key_store,
             )
             .await
@@ -872,6 +1029,7 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_constraints(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -880,6 +1038,12 @@ impl EventInterface for KafkaStore {
         created_before: PrimitiveDateTime,
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        delivery_attempts: Option<HashSet<common_enums::WebhookDeliveryAttempt>>,
+        only_initial_attempts: bool,
+        never_delivered: bool,
+        min_attempts: Option<u16>,
+        profile_ids: Option<HashSet<common_utils::id_type::ProfileId>>,
     ) -> CustomResult<i64, errors::StorageError> {
         self.diesel_store
             .count_initial_events_by_constraints(
@@ -889,6 +1053,52 @@ impl EventInterface for KafkaStore {
                 created_before,
                 event_types,
                 is_delivered,
+                merchant_connector_id,
+                delivery_attempts,
+                only_initial_attempts,
+                never_delivered,
+                min_attempts,
+                profile_ids,
+            )
+            .await
+    }
+
+    async fn count_initial_events_by_primary_object_id_contains(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        primary_object_id_substring: &str,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<Vec<(common_enums::EventClass, i64)>, errors::StorageError> {
+        self.diesel_store
+            .count_initial_events_by_primary_object_id_contains(
+                merchant_id,
+                profile_id,
+                primary_object_id_substring,
+                created_after,
+                created_before,
+            )
+            .await
+    }
+
+    async fn count_initial_events_by_delivery_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        group_by: common_enums::EventStatsGroupBy,
+    ) -> CustomResult<Vec<storage::EventDeliveryStatusCount>, errors::StorageError> {
+        self.diesel_store
+            .count_initial_events_by_delivery_status(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+                event_types,
+                group_by,
             )
             .await
     }
@@ -2495,6 +2495,30 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(web::resource("count").route(
+                        web::post().to(webhook_events::count_initial_webhook_delivery_attempts),
+                    ))
+                    .service(web::resource("export").route(
+                        web::post().to(webhook_events::export_initial_webhook_delivery_attempts),
+                    ))
+                    .service(
+                        web::resource("stats")
+                            .route(web::post().to(webhook_events::get_webhook_delivery_stats)),
+                    )
+                    .service(
+                        web::resource("endpoint_health")
+                            .route(web::post().to(webhook_events::get_webhook_endpoint_health)),
+                    )
+                    .service(
+                        web::resource("sync").route(web::post().to(webhook_events::sync_events)),
+                    )
+                    .service(web::resource("retry").route(
+                        web::post().to(webhook_events::bulk_retry_webhook_delivery_attempts),
+                    ))
+                    .service(
+                        web::resource("retrieve")
+                            .route(web::post().to(webhook_events::retrieve_webhook_events_batch)),
+                    )
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
@@ -2502,7 +2526,19 @@ impl WebhookEvents {
                             ))
                             .service(web::resource("retry").route(
                                 web::post().to(webhook_events::retry_webhook_delivery_attempt),
-                            )),
+                            ))
+                            .service(web::resource("replay").route(
+                                web::post().to(webhook_events::replay_webhook_delivery_attempt),
+                            ))
+                            .service(
+                                web::resource("tags")
+                                    .route(web::post().to(webhook_events::tag_webhook_event)),
+                            )
+                            .service(
+                                web::resource("retry_schedule").route(
+                                    web::get().to(webhook_events::get_webhook_retry_schedule),
+                                ),
+                            ),
                     ),
             )
     }
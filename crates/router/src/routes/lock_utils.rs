@@ -182,8 +182,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::IncomingRelayWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
+            | Flow::WebhookEventInitialDeliveryAttemptCount
+            | Flow::WebhookEventInitialDeliveryAttemptExport
+            | Flow::WebhookEventDeliveryStats
+            | Flow::WebhookEndpointHealth
+            | Flow::WebhookEventSync
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventBulkDeliveryRetry
+            | Flow::WebhookEventBatchRetrieve
+            | Flow::WebhookEventTag
+            | Flow::WebhookEventDeliveryReplay
+            | Flow::WebhookEventRetrySchedule
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -10,8 +10,15 @@ use crate::{
         authorization::permissions::Permission,
     },
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        BulkWebhookDeliveryRetryRequest, BulkWebhookDeliveryRetryRequestInternal,
+        EventBatchRetrieveRequest, EventBatchRetrieveRequestInternal, EventDeliveryStatsRequest,
+        EventDeliveryStatsRequestInternal, EventId, EventListConstraints, EventListExportRequest,
+        EventListExportRequestInternal, EventListRequestInternal, EventSyncRequest,
+        EventSyncRequestInternal, EventTagRequest, EventTagRequestInternal,
+        EventsCountRequestInternal, ProfileEventListRequestInternal,
+        WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryQuery,
+        WebhookDeliveryRetryRequestInternal, WebhookEndpointHealthRequestInternal,
+        WebhookReplayRequest, WebhookReplayRequestInternal, WebhookRetryScheduleRequestInternal,
     },
 };
 
@@ -75,22 +82,217 @@ pub async fn list_initial_webhook_delivery_attempts_with_jwtauth(
         state,
         &req,
         request_internal,
-        |state, auth: UserFromToken, mut request_internal, _| {
-            let merchant_id = auth.merchant_id;
-            let profile_id = auth.profile_id;
+        |state, auth: UserFromToken, request_internal, _| {
+            webhook_events::list_initial_delivery_attempts_for_profile(
+                state,
+                ProfileEventListRequestInternal {
+                    merchant_id: auth.merchant_id,
+                    profile_id: auth.profile_id,
+                    constraints: request_internal.constraints,
+                },
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 
-            request_internal.merchant_id = merchant_id;
-            request_internal.constraints.profile_id = Some(profile_id);
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventInitialDeliveryAttemptCount))]
+pub async fn count_initial_webhook_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventInitialDeliveryAttemptCount;
+    let merchant_id = path.into_inner();
+    let constraints = json_payload.into_inner();
 
-            webhook_events::list_initial_delivery_attempts(
+    let request_internal = EventsCountRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::count_initial_delivery_attempts(
                 state,
                 request_internal.merchant_id,
                 request_internal.constraints,
             )
         },
-        &auth::JWTAuth {
-            permission: Permission::ProfileWebhookEventRead,
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventInitialDeliveryAttemptExport))]
+pub async fn export_initial_webhook_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventListExportRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventInitialDeliveryAttemptExport;
+    let merchant_id = path.into_inner();
+    let EventListExportRequest {
+        constraints,
+        fields,
+    } = json_payload.into_inner();
+
+    let request_internal = EventListExportRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+        fields,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::export_initial_delivery_attempts(state, request_internal)
         },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryStats))]
+pub async fn get_webhook_delivery_stats(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventDeliveryStatsRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryStats;
+    let merchant_id = path.into_inner();
+    let EventDeliveryStatsRequest {
+        constraints,
+        group_by,
+    } = json_payload.into_inner();
+
+    let request_internal = EventDeliveryStatsRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+        group_by,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::get_delivery_stats(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+                request_internal.group_by,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointHealth))]
+pub async fn get_webhook_endpoint_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointHealth;
+    let merchant_id = path.into_inner();
+    let mut constraints = json_payload.into_inner();
+
+    let request_internal = WebhookEndpointHealthRequestInternal {
+        merchant_id: merchant_id.clone(),
+        profile_id: constraints.profile_id.take(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::get_endpoint_health(state, request_internal)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventSync))]
+pub async fn sync_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventSyncRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventSync;
+    let merchant_id = path.into_inner();
+
+    let request_internal =
+        EventSyncRequestInternal::new(merchant_id.clone(), json_payload.into_inner());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| webhook_events::sync_events(state, request_internal),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -100,7 +302,7 @@ pub async fn list_initial_webhook_delivery_attempts_with_jwtauth(
 pub async fn list_webhook_delivery_attempts(
     state: web::Data<AppState>,
     req: HttpRequest,
-    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    path: web::Path<(common_utils::id_type::MerchantId, EventId)>,
 ) -> impl Responder {
     let flow = Flow::WebhookEventDeliveryAttemptList;
     let (merchant_id, initial_attempt_id) = path.into_inner();
@@ -140,14 +342,27 @@ pub async fn list_webhook_delivery_attempts(
 pub async fn retry_webhook_delivery_attempt(
     state: web::Data<AppState>,
     req: HttpRequest,
-    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    path: web::Path<(common_utils::id_type::MerchantId, EventId)>,
+    query: web::Query<WebhookDeliveryRetryQuery>,
 ) -> impl Responder {
     let flow = Flow::WebhookEventDeliveryRetry;
     let (merchant_id, event_id) = path.into_inner();
+    let WebhookDeliveryRetryQuery {
+        dry_run,
+        include_attempts,
+        idempotency_key,
+        max_event_age_seconds,
+    } = query.into_inner();
 
     let request_internal = WebhookDeliveryRetryRequestInternal {
         merchant_id: merchant_id.clone(),
         event_id,
+        dry_run,
+        include_attempts,
+        idempotency_key,
+        max_event_age: max_event_age_seconds
+            .map(|seconds| time::Duration::seconds(i64::from(seconds))),
+        triggered_by: get_manual_retry_trigger(&req),
     };
 
     Box::pin(api::server_wrap(
@@ -160,6 +375,11 @@ pub async fn retry_webhook_delivery_attempt(
                 state,
                 request_internal.merchant_id,
                 request_internal.event_id,
+                request_internal.dry_run,
+                request_internal.include_attempts,
+                request_internal.idempotency_key,
+                request_internal.max_event_age,
+                request_internal.triggered_by,
             )
         },
         auth::auth_type(
@@ -174,3 +394,218 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryReplay))]
+#[cfg(feature = "v1")]
+pub async fn replay_webhook_delivery_attempt(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, EventId)>,
+    json_payload: web::Json<WebhookReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryReplay;
+    let (merchant_id, event_id) = path.into_inner();
+    let WebhookReplayRequest { override_url } = json_payload.into_inner();
+
+    let request_internal = WebhookReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_id,
+        override_url,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::replay_delivery_attempt(state, request_internal)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrySchedule))]
+#[cfg(feature = "v1")]
+pub async fn get_webhook_retry_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, EventId)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRetrySchedule;
+    let (merchant_id, event_id) = path.into_inner();
+
+    let request_internal = WebhookRetryScheduleRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| webhook_events::get_retry_schedule(state, request_internal),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBatchRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_events_batch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventBatchRetrieveRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBatchRetrieve;
+    let merchant_id = path.into_inner();
+    let EventBatchRetrieveRequest { event_ids } = json_payload.into_inner();
+
+    let request_internal = EventBatchRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_ids,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_events_batch(state, request_internal)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventTag))]
+#[cfg(feature = "v1")]
+pub async fn tag_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, EventId)>,
+    json_payload: web::Json<EventTagRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventTag;
+    let (merchant_id, event_id) = path.into_inner();
+    let EventTagRequest { tags } = json_payload.into_inner();
+
+    let request_internal = EventTagRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_id,
+        tags,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| webhook_events::tag_event(state, request_internal),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkDeliveryRetry))]
+#[cfg(feature = "v1")]
+pub async fn bulk_retry_webhook_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<BulkWebhookDeliveryRetryRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkDeliveryRetry;
+    let merchant_id = path.into_inner();
+    let BulkWebhookDeliveryRetryRequest {
+        event_ids,
+        constraints,
+        dry_run,
+        max_event_age_seconds,
+    } = json_payload.into_inner();
+
+    let request_internal = BulkWebhookDeliveryRetryRequestInternal {
+        merchant_id: merchant_id.clone(),
+        event_ids,
+        constraints,
+        dry_run,
+        max_event_age: max_event_age_seconds
+            .map(|seconds| time::Duration::seconds(i64::from(seconds))),
+        triggered_by: get_manual_retry_trigger(&req),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::bulk_retry_delivery_attempts(
+                state,
+                request_internal.merchant_id,
+                request_internal.event_ids,
+                request_internal.constraints,
+                request_internal.dry_run,
+                request_internal.max_event_age,
+                request_internal.triggered_by,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Returns the source of a manually triggered retry, depending on whether the request was
+/// authenticated using a dashboard session or an API key.
+#[cfg(feature = "v1")]
+fn get_manual_retry_trigger(req: &HttpRequest) -> api_models::enums::WebhookRetryTrigger {
+    if auth::is_jwt_auth(req.headers()) {
+        api_models::enums::WebhookRetryTrigger::ManualDashboard
+    } else {
+        api_models::enums::WebhookRetryTrigger::Api
+    }
+}
@@ -514,16 +514,20 @@ pub async fn send_request(
         cloned_request
             .send()
             .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+            .map_err(|error| {
+                let client_error = match &error {
+                    error if error.is_timeout() => {
+                        metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
+                        errors::ApiClientError::RequestTimeoutReceived
+                    }
+                    error if is_connection_closed_before_message_could_complete(error) => {
+                        metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
+                        errors::ApiClientError::ConnectionClosedIncompleteMessage
+                    }
+                    error => errors::ApiClientError::RequestNotSent(error.to_string()),
+                };
+                // The underlying error is retained in the report so that it can be inspected
+                report!(error).change_context(client_error)
             })
             .attach_printable("Unable to send request to connector")
     });
@@ -532,16 +536,20 @@ pub async fn send_request(
         request
             .send()
             .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+            .map_err(|error| {
+                let client_error = match &error {
+                    error if error.is_timeout() => {
+                        metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
+                        errors::ApiClientError::RequestTimeoutReceived
+                    }
+                    error if is_connection_closed_before_message_could_complete(error) => {
+                        metrics::REQUEST_BUILD_FAILURE.add(1, &[]);
+                        errors::ApiClientError::ConnectionClosedIncompleteMessage
+                    }
+                    error => errors::ApiClientError::RequestNotSent(error.to_string()),
+                };
+                // The underlying error is retained in the report so that it can be inspected
+                report!(error).change_context(client_error)
             })
             .attach_printable("Unable to send request to connector")
     };
@@ -1,6 +1,18 @@
 pub use api_models::webhook_events::{
-    EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    BulkWebhookDeliveryRetryRequest, BulkWebhookDeliveryRetryRequestInternal,
+    BulkWebhookDeliveryRetryResponse, BulkWebhookDeliveryRetryResult, EndpointDeliveryAttempt,
+    EventBatchRetrieveRequest, EventBatchRetrieveRequestInternal, EventBatchRetrieveResponse,
+    EventDeliveryStatsRequest, EventDeliveryStatsRequestInternal, EventDeliveryStatsResponse,
+    EventId, EventListConstraints, EventListConstraintsInternal, EventListExportRequest,
+    EventListExportRequestInternal, EventListItemResponse, EventListRequestInternal,
+    EventRetrieveResponse, EventRetrySchedule, EventStatsGroupKey, EventSyncRequest,
+    EventSyncRequestInternal, EventSyncResponse, EventTagRequest, EventTagRequestInternal,
+    EventsCountRequestInternal, EventsCountResponse, OutgoingWebhookRequestContent,
+    OutgoingWebhookResponseContent, ProfileEventListRequestInternal, TotalEventsResponse,
+    WebhookAttemptSummary, WebhookDeliveryAttemptListRequestInternal,
+    WebhookDeliveryRetryDeduplicatedResponse, WebhookDeliveryRetryDryRunResponse,
+    WebhookDeliveryRetryQuery, WebhookDeliveryRetryRequestInternal, WebhookDeliveryRetryResponse,
+    WebhookDeliveryRetryStatus, WebhookEndpointHealthRequestInternal,
+    WebhookEndpointHealthResponse, WebhookReplayRequest, WebhookReplayRequestInternal,
+    WebhookOutcomeContext, WebhookRetrySchedule, WebhookRetryScheduleRequestInternal,
 };
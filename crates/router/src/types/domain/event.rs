@@ -5,7 +5,7 @@ use common_utils::{
     types::keymanager::{KeyManagerState, ToEncryptable},
 };
 use diesel_models::{
-    enums::{EventClass, EventObjectType, EventType, WebhookDeliveryAttempt},
+    enums::{EventClass, EventObjectType, EventType, WebhookDeliveryAttempt, WebhookRetryTrigger},
     events::{EventMetadata, EventUpdateInternal},
 };
 use error_stack::ResultExt;
@@ -71,6 +71,24 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// Merchant Connector Account identifier associated with the object of the event.
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// The tags added to the event by operators, such as during incident triage.
+    pub tags: Option<Vec<String>>,
+
+    /// The source that triggered the delivery attempt, for retries and replays.
+    pub triggered_by: Option<WebhookRetryTrigger>,
+
+    /// The time at which the event was persisted, set by the database when the event is inserted.
+    /// This is unavailable for events persisted before the ingestion time was recorded.
+    pub ingested_at: Option<time::PrimitiveDateTime>,
+
+    /// The time at which the object of the event was last changed, before the event was created.
+    /// This is unavailable for objects which do not record their change time, and for events
+    /// created before the change time was recorded.
+    pub primary_object_updated_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -82,6 +100,9 @@ pub enum EventUpdate {
     OverallDeliveryStatusUpdate {
         is_overall_delivery_successful: bool,
     },
+    TagsUpdate {
+        tags: Vec<String>,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -94,6 +115,7 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
                 is_overall_delivery_successful: None,
+                tags: None,
             },
             EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
@@ -101,6 +123,13 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified: None,
                 response: None,
                 is_overall_delivery_successful: Some(is_overall_delivery_successful),
+                tags: None,
+            },
+            EventUpdate::TagsUpdate { tags } => Self {
+                is_webhook_notified: None,
+                response: None,
+                is_overall_delivery_successful: None,
+                tags: Some(tags),
             },
         }
     }
@@ -130,6 +159,11 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            merchant_connector_id: self.merchant_connector_id,
+            tags: self.tags,
+            triggered_by: self.triggered_by,
+            ingested_at: self.ingested_at,
+            primary_object_updated_at: self.primary_object_updated_at,
         })
     }
 
@@ -180,6 +214,11 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            merchant_connector_id: item.merchant_connector_id,
+            tags: item.tags,
+            triggered_by: item.triggered_by,
+            ingested_at: item.ingested_at,
+            primary_object_updated_at: item.primary_object_updated_at,
         })
     }
 
@@ -202,6 +241,11 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            merchant_connector_id: self.merchant_connector_id,
+            tags: self.tags,
+            triggered_by: self.triggered_by,
+            ingested_at: self.ingested_at,
+            primary_object_updated_at: self.primary_object_updated_at,
         })
     }
 }
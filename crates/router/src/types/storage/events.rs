@@ -1 +1 @@
-pub use diesel_models::events::{Event, EventMetadata, EventNew};
+pub use diesel_models::events::{Event, EventDeliveryStatusCount, EventMetadata, EventNew};
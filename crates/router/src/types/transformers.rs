@@ -1913,42 +1913,275 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
     fn foreign_try_from(
         item: api_types::webhook_events::EventListConstraints,
     ) -> Result<Self, Self::Error> {
-        if item.object_id.is_some()
-            && (item.created_after.is_some()
-                || item.created_before.is_some()
-                || item.limit.is_some()
-                || item.offset.is_some()
-                || item.event_classes.is_some()
-                || item.event_types.is_some())
+        // The event identifier uniquely identifies the event, all the other constraints are
+        // ignored.
+        if let Some(event_id) = item.event_id {
+            return Ok(Self::EventIdFilter { event_id });
+        }
+
+        let now = common_utils::date_time::now();
+        let default_created_after = item.get_default_created_after(now);
+        let item = item
+            .validate()
+            .and_then(|()| item.resolve_time_range(now))
+            .map_err(|error| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string()
+                })
+            })?;
+
+        if item.get_orphaned() {
+            let conflicting_constraints = item
+                .get_constraints_conflicting_with_orphaned()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize event list constraints")?;
+            if !conflicting_constraints.is_empty() {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "`orphaned` cannot be specified along with {}",
+                        conflicting_constraints
+                            .iter()
+                            .map(|constraint| format!("`{constraint}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                }));
+            }
+
+            return Ok(Self::OrphanedFilter {
+                created_after: item.created_after,
+                created_before: item.created_before,
+                limit: Some(i64::from(item.get_limit())),
+                starting_after: item.starting_after,
+                sort_by: item.sort_by.unwrap_or_default(),
+            });
+        }
+
+        let object_filters = [
+            ("object_id", item.object_id.is_some()),
+            ("object_ids", item.object_ids.is_some()),
+            ("object_id_contains", item.object_id_contains.is_some()),
+            ("initial_attempt_id", item.initial_attempt_id.is_some()),
+        ];
+        // The events matching a partial object ID are listed a page at a time within a time range
+        let generic_filters = [
+            (
+                "created_after",
+                item.created_after.is_some() && item.object_id_contains.is_none(),
+            ),
+            (
+                "created_before",
+                item.created_before.is_some() && item.object_id_contains.is_none(),
+            ),
+            (
+                "limit",
+                item.limit.is_some() && item.object_id_contains.is_none(),
+            ),
+            (
+                "offset",
+                item.offset.is_some() && item.object_id_contains.is_none(),
+            ),
+            ("starting_after", item.starting_after.is_some()),
+            ("ending_before", item.ending_before.is_some()),
+            // `event_classes` can additionally be specified along with `object_id`
+            (
+                "event_classes",
+                item.event_classes.is_some() && item.object_id.is_none(),
+            ),
+            ("event_types", item.event_types.is_some()),
+            (
+                "merchant_connector_id",
+                item.merchant_connector_id.is_some(),
+            ),
+            ("connector", item.connector.is_some()),
+            ("delivery_attempt", item.delivery_attempt.is_some()),
+            (
+                "only_initial_attempts",
+                item.only_initial_attempts.is_some(),
+            ),
+            ("never_delivered", item.never_delivered.is_some()),
+            ("min_attempts", item.min_attempts.is_some()),
+            ("profile_ids", item.profile_ids.is_some()),
+            (
+                "response_status_code_gte",
+                item.response_status_code_gte.is_some(),
+            ),
+            (
+                "response_status_code_lte",
+                item.response_status_code_lte.is_some(),
+            ),
+            ("latency_ms_gte", item.latency_ms_gte.is_some()),
+            ("sla_breached", item.sla_breached.is_some()),
+            (
+                "response_body_size_gte",
+                item.response_body_size_gte.is_some(),
+            ),
+            ("error_category", item.error_category.is_some()),
+            ("has_error", item.has_error.is_some()),
+            (
+                "error_message_contains",
+                item.error_message_contains.is_some(),
+            ),
+            ("response_received", item.response_received.is_some()),
+            (
+                "response_content_type",
+                item.response_content_type.is_some(),
+            ),
+            ("payload_version", item.payload_version.is_some()),
+            ("expiring_before", item.expiring_before.is_some()),
+            ("ingested_after", item.ingested_after.is_some()),
+            ("ingested_before", item.ingested_before.is_some()),
+            ("event_delay_ms_gte", item.event_delay_ms_gte.is_some()),
+            ("created_hour_of_day", item.created_hour_of_day.is_some()),
+            ("created_day_of_week", item.created_day_of_week.is_some()),
+            ("tags", item.tags.is_some()),
+            ("triggered_by", item.triggered_by.is_some()),
+        ];
+        let object_filters_count = object_filters
+            .iter()
+            .filter(|(_, is_specified)| *is_specified)
+            .count();
+
+        if object_filters_count > 1 {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only one of {} can be specified",
+                    join_constraint_names(&object_filters)
+                )
+            }));
+        }
+
+        if object_filters_count > 0
+            && generic_filters
+                .iter()
+                .any(|(_, is_specified)| *is_specified)
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message:
-                    "Either only `object_id` must be specified, or one or more of \
-                          `created_after`, `created_before`, `limit`, `offset`, `event_classes` and `event_types` must be specified"
-                        .to_string()
+                message: format!(
+                    "Either only one of {} must be specified, or one or more of {} must be \
+                     specified. `event_classes` can additionally be specified along with \
+                     `object_id`, and `created_after`, `created_before`, `limit` and `offset` \
+                     along with `object_id_contains`",
+                    join_constraint_names(&object_filters),
+                    join_constraint_names(&generic_filters)
+                )
             }));
         }
 
-        match item.object_id {
-            Some(object_id) => Ok(Self::ObjectIdFilter { object_id }),
-            None => Ok(Self::GenericFilter {
-                created_after: item.created_after,
+        if item.starting_after.is_some() && item.ending_before.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only one of `starting_after` and `ending_before` can be specified"
+                    .to_string()
+            }));
+        }
+
+        let sort_by = item.sort_by.unwrap_or_default();
+        let delivery_states = item.get_delivery_states();
+        let limit = item.get_limit();
+        let only_initial_attempts = item.get_only_initial_attempts();
+        let never_delivered = item.get_never_delivered();
+
+        match (
+            item.object_id,
+            item.object_ids,
+            item.object_id_contains,
+            item.initial_attempt_id,
+        ) {
+            (Some(object_id), _, _, _) => Ok(Self::ObjectIdFilter {
+                object_id,
+                event_classes: item.event_classes,
+                sort_by,
+            }),
+            (None, Some(object_ids), _, _) => Ok(Self::ObjectIdsFilter {
+                object_ids,
+                sort_by,
+            }),
+            (None, None, Some(object_id_contains), _) => Ok(Self::ObjectIdContainsFilter {
+                object_id_contains,
+                created_after: item.created_after.or(default_created_after),
                 created_before: item.created_before,
-                limit: item.limit.map(i64::from),
+                limit: Some(i64::from(limit)),
                 offset: item.offset.map(i64::from),
+                sort_by,
+            }),
+            (None, None, None, Some(initial_attempt_id)) => Ok(Self::InitialAttemptIdFilter {
+                initial_attempt_id,
+                sort_by,
+            }),
+            (None, None, None, None) => Ok(Self::GenericFilter {
+                created_after: item.created_after.or(default_created_after),
+                created_before: item.created_before,
+                limit: Some(i64::from(limit)),
+                offset: item.offset.map(i64::from),
+                starting_after: item.starting_after,
+                ending_before: item.ending_before,
                 event_classes: item.event_classes,
                 event_types: item.event_types,
-                is_delivered: item.is_delivered,
+                delivery_states,
+                merchant_connector_id: item.merchant_connector_id,
+                connector: item.connector,
+                delivery_attempts: item.delivery_attempt,
+                only_initial_attempts,
+                never_delivered,
+                min_attempts: item.min_attempts,
+                profile_ids: item.profile_ids,
+                response_status_code_gte: item.response_status_code_gte,
+                response_status_code_lte: item.response_status_code_lte,
+                latency_ms_gte: item.latency_ms_gte,
+                sla_breached: item.sla_breached,
+                response_body_size_gte: item.response_body_size_gte,
+                error_category: item.error_category,
+                has_error: item.has_error,
+                error_message_contains: item.error_message_contains,
+                response_received: item.response_received,
+                response_content_type: item.response_content_type,
+                payload_version: item.payload_version,
+                expiring_before: item.expiring_before,
+                ingested_after: item.ingested_after,
+                ingested_before: item.ingested_before,
+                event_delay_ms_gte: item.event_delay_ms_gte,
+                created_hour_of_day: item.created_hour_of_day,
+                created_day_of_week: item.created_day_of_week,
+                tags: item.tags,
+                triggered_by: item.triggered_by,
+                sort_by,
+                default_window_applied: default_created_after.is_some(),
             }),
         }
     }
 }
 
+/// Joins the names of the specified event list constraints for use in error messages, as in
+/// "`a`, `b` and `c`".
+#[cfg(feature = "olap")]
+fn join_constraint_names(constraints: &[(&str, bool)]) -> String {
+    let names = constraints
+        .iter()
+        .map(|(name, _)| format!("`{name}`"))
+        .collect::<Vec<_>>();
+
+    match names.split_last() {
+        Some((last, [])) => last.to_owned(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
 #[cfg(feature = "olap")]
-impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemResponse {
+impl
+    ForeignTryFrom<(
+        domain::Event,
+        api_models::webhook_events::WebhookOutcomeContext,
+    )> for api_models::webhook_events::EventListItemResponse
+{
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
-    fn try_from(item: domain::Event) -> Result<Self, Self::Error> {
+    fn foreign_try_from(
+        (item, outcome_context): (
+            domain::Event,
+            api_models::webhook_events::WebhookOutcomeContext,
+        ),
+    ) -> Result<Self, Self::Error> {
         use crate::utils::OptionExt;
 
         // We only allow retrieving events with merchant_id, business_profile_id
@@ -1965,34 +2198,109 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
         let initial_attempt_id = item
             .initial_attempt_id
             .get_required_value("initial_attempt_id")
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .map(api_models::webhook_events::EventId::from_stored)?;
+        let event_id = api_models::webhook_events::EventId::from_stored(item.event_id);
+
+        let request_content: Option<api_models::webhook_events::OutgoingWebhookRequestContent> =
+            item.request.as_ref().and_then(|request| {
+                request
+                    .peek()
+                    .parse_struct("OutgoingWebhookRequestContent")
+                    .ok()
+            });
+        let response_content: Option<api_models::webhook_events::OutgoingWebhookResponseContent> =
+            item.response.as_ref().and_then(|response| {
+                response
+                    .peek()
+                    .parse_struct("OutgoingWebhookResponseContent")
+                    .ok()
+            });
+        let sla_breached = response_content
+            .as_ref()
+            .and_then(|response| response.is_sla_breached());
+        let (amount, currency) = request_content
+            .as_ref()
+            .map(|request| request.payment_amount(item.event_class))
+            .unwrap_or_default();
+        let stored_bytes = request_content.as_ref().map(|request| {
+            request.stored_bytes().saturating_add(
+                response_content
+                    .as_ref()
+                    .map_or(0, |response| response.stored_bytes()),
+            )
+        });
 
         Ok(Self {
-            event_id: item.event_id,
+            event_id,
             merchant_id,
             profile_id,
             object_id: item.primary_object_id,
             event_type: item.event_type,
             event_class: item.event_class,
+            // Whether the class is enabled depends on the business profile, and is populated by
+            // every API returning events once the business profiles are looked up.
+            class_enabled: true,
             is_delivery_successful: item.is_overall_delivery_successful,
+            outcome: api_models::webhook_events::get_webhook_outcome(
+                item.is_overall_delivery_successful,
+                item.delivery_attempt,
+                item.created_at,
+                outcome_context,
+            ),
+            merchant_connector_id: item.merchant_connector_id,
+            connector: item
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get_connector())
+                .map(ToOwned::to_owned),
             initial_attempt_id,
+            delivery_attempt: item.delivery_attempt,
             created: item.created_at,
+            ingested_at: item.ingested_at,
+            event_delay_ms: api_models::webhook_events::get_event_delay_ms(
+                item.created_at,
+                item.primary_object_updated_at,
+            ),
+            delivery_url: request_content.and_then(|request| request.delivery_url),
+            stored_bytes,
+            expires_at: api_models::webhook_events::get_expires_at(
+                item.event_class,
+                item.created_at,
+            ),
+            sla_breached,
+            amount,
+            currency,
+            tags: item.tags,
         })
     }
 }
 
 #[cfg(feature = "olap")]
-impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveResponse {
+impl
+    ForeignTryFrom<(
+        domain::Event,
+        api_models::webhook_events::WebhookOutcomeContext,
+    )> for api_models::webhook_events::EventRetrieveResponse
+{
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
-    fn try_from(item: domain::Event) -> Result<Self, Self::Error> {
+    fn foreign_try_from(
+        (item, outcome_context): (
+            domain::Event,
+            api_models::webhook_events::WebhookOutcomeContext,
+        ),
+    ) -> Result<Self, Self::Error> {
         use crate::utils::OptionExt;
 
         // We only allow retrieving events with all required fields in `EventListItemResponse`, and
         // `request` and `response` populated.
         // We cannot retrieve events with only some of these fields populated.
         let event_information =
-            api_models::webhook_events::EventListItemResponse::try_from(item.clone())?;
+            api_models::webhook_events::EventListItemResponse::foreign_try_from((
+                item.clone(),
+                outcome_context,
+            ))?;
 
         let request = item
             .request
@@ -2013,9 +2321,23 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
 
         Ok(Self {
             event_information,
+            idempotency_key: request.get_idempotency_key(),
+            payload_version: request.get_payload_version(),
+            request_body_sha256: (!request.is_truncated
+                && !request.body_omitted
+                && !request.body_redacted)
+                .then(|| request.body_sha256()),
+            triggered_by: api_models::webhook_events::get_retry_trigger(
+                item.delivery_attempt,
+                item.triggered_by,
+            ),
+            body_persisted: !request.body_omitted,
             request,
             response,
-            delivery_attempt: item.delivery_attempt,
+            attempt_number: None,
+            time_to_first_success_ms: None,
+            attempts: None,
+            retry_schedule: Default::default(),
         })
     }
 }
@@ -2106,6 +2428,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            disabled_event_classes: item.disabled_event_classes,
         }
     }
 }
@@ -2122,6 +2445,7 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            disabled_event_classes: item.disabled_event_classes,
         }
     }
 }
@@ -119,6 +119,11 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            merchant_connector_id: initial_event.merchant_connector_id,
+            tags: None,
+            triggered_by: Some(storage::enums::WebhookRetryTrigger::Scheduler),
+            ingested_at: None,
+            primary_object_updated_at: initial_event.primary_object_updated_at,
         };
 
         let event = db
@@ -128,13 +133,22 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                 logger::error!(?error, "Failed to insert event in events table");
             })?;
 
-        match &event.request {
-            Some(request) => {
-                let request_content: OutgoingWebhookRequestContent = request
+        let stored_request_content = event
+            .request
+            .as_ref()
+            .map(|request| {
+                request
                     .get_inner()
                     .peek()
-                    .parse_struct("OutgoingWebhookRequestContent")?;
-
+                    .parse_struct::<OutgoingWebhookRequestContent>("OutgoingWebhookRequestContent")
+            })
+            .transpose()?
+            .filter(|request_content| {
+                !request_content.body_omitted && !request_content.body_redacted
+            });
+
+        match stored_request_content {
+            Some(request_content) => {
                 Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                     state.clone(),
                     business_profile,
@@ -148,8 +162,8 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                 .await;
             }
 
-            // Event inserted by old version of application, fetch current information about
-            // resource
+            // Event inserted by old version of application, or event whose request body was not
+            // persisted or was redacted, fetch current information about resource
             None => {
                 let merchant_account = db
                     .find_merchant_account_by_merchant_id(
@@ -286,6 +300,24 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    let mapping = get_webhook_delivery_retry_mapping(db).await;
+    let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
+        mapping,
+        merchant_id,
+        retry_count,
+    );
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Get the outgoing webhook retry configuration stored in configs with the key
+/// `pt_mapping_outgoing_webhooks`, falling back to the default configuration when it is not
+/// configured or cannot be read.
+#[cfg(any(feature = "v1", feature = "olap"))]
+#[instrument(skip_all)]
+pub(crate) async fn get_webhook_delivery_retry_mapping(
+    db: &dyn StorageInterface,
+) -> process_data::OutgoingWebhookRetryProcessTrackerMapping {
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -297,7 +329,7 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
                 .parse_struct("OutgoingWebhookRetryProcessTrackerMapping")
                 .change_context(errors::StorageError::DeserializationFailed)
         });
-    let mapping = result.map_or_else(
+    result.map_or_else(
         |error| {
             if error.current_context().is_db_not_found() {
                 logger::debug!("Outgoing webhooks retry config `{key}` not found, ignoring");
@@ -313,15 +345,7 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
             logger::debug!(?mapping, "Using custom outgoing webhooks retry config");
             mapping
         },
-    );
-
-    let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
-        mapping,
-        merchant_id,
-        retry_count,
-    );
-
-    scheduler_utils::get_time_from_delta(time_delta)
+    )
 }
 
 /// Schedule the webhook delivery task for retry
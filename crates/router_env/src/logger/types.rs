@@ -515,10 +515,30 @@ pub enum Flow {
     DeleteTheme,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
+    /// Count initial webhook delivery attempts
+    WebhookEventInitialDeliveryAttemptCount,
+    /// Export initial webhook delivery attempts
+    WebhookEventInitialDeliveryAttemptExport,
+    /// Retrieve delivery stats of webhook events
+    WebhookEventDeliveryStats,
+    /// Retrieve the delivery health of webhook endpoints
+    WebhookEndpointHealth,
+    /// Incrementally sync webhook events
+    WebhookEventSync,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Manually retry the delivery for multiple webhook events
+    WebhookEventBulkDeliveryRetry,
+    /// Retrieve multiple webhook events at once
+    WebhookEventBatchRetrieve,
+    /// Tag a webhook event
+    WebhookEventTag,
+    /// Replay a webhook event to an alternate URL
+    WebhookEventDeliveryReplay,
+    /// Preview the remaining automatic retries of a webhook event
+    WebhookEventRetrySchedule,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -350,6 +350,55 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     }
 }
 
+/// Returns the times at which the remaining retries of an outgoing webhook are expected to run,
+/// given the `retry_count` of the retry task and the time at which its next retry is scheduled.
+/// Each of the subsequent retries is expected to run after the configured delay following the
+/// previous retry, until the configured retries are exhausted.
+pub fn get_outgoing_webhook_retry_schedule(
+    mapping: process_data::OutgoingWebhookRetryProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+    next_retry_at: time::PrimitiveDateTime,
+) -> Vec<time::PrimitiveDateTime> {
+    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    std::iter::successors(
+        Some((retry_count, next_retry_at)),
+        |(retry_count, retry_at)| {
+            let retry_count = retry_count.checked_add(1)?;
+            get_delay(retry_count, &retry_mapping.frequencies).map(|delay| {
+                (
+                    retry_count,
+                    retry_at.saturating_add(time::Duration::seconds(delay.into())),
+                )
+            })
+        },
+    )
+    .map(|(_, retry_at)| retry_at)
+    .collect()
+}
+
+/// Returns the duration after the initial delivery attempt of an outgoing webhook within which
+/// its automatic retries are expected to run, which is the sum of the configured delays of all
+/// the retries.
+pub fn get_outgoing_webhook_retry_window(
+    mapping: process_data::OutgoingWebhookRetryProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> time::Duration {
+    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    std::iter::once(retry_mapping.start_after)
+        .chain((1..).map_while(|retry_count| get_delay(retry_count, &retry_mapping.frequencies)))
+        .map(|delay| time::Duration::seconds(delay.into()))
+        .fold(time::Duration::ZERO, time::Duration::saturating_add)
+}
+
 pub fn get_pcr_payments_retry_schedule_time(
     mapping: process_data::RevenueRecoveryPaymentProcessTrackerMapping,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -455,4 +504,68 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_outgoing_webhook_retry_schedule() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        // 5 retries in total: the first after `start_after`, and the next 4 at 5 minute intervals
+        let mapping = || process_data::OutgoingWebhookRetryProcessTrackerMapping {
+            default_mapping: process_data::RetryMapping {
+                start_after: 60,
+                frequencies: vec![(300, 4)],
+            },
+            custom_merchant_mapping: std::collections::HashMap::new(),
+        };
+        let next_retry_at = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+
+        // With 2 of the 5 retries used, the 3 remaining retries are scheduled
+        assert_eq!(
+            get_outgoing_webhook_retry_schedule(mapping(), &merchant_id, 2, next_retry_at),
+            vec![
+                next_retry_at,
+                next_retry_at + time::Duration::minutes(5),
+                next_retry_at + time::Duration::minutes(10),
+            ]
+        );
+
+        // The last retry has no further retries scheduled after it
+        assert_eq!(
+            get_outgoing_webhook_retry_schedule(mapping(), &merchant_id, 4, next_retry_at),
+            vec![next_retry_at]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_outgoing_webhook_retry_window() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let mut mapping = process_data::OutgoingWebhookRetryProcessTrackerMapping::default();
+
+        // The default retries run over the day following the initial delivery attempt
+        assert_eq!(
+            get_outgoing_webhook_retry_window(Default::default(), &merchant_id),
+            time::Duration::seconds(86_460)
+        );
+
+        // The retries configured for the merchant take precedence over the default retries
+        mapping.custom_merchant_mapping.insert(
+            merchant_id.clone(),
+            process_data::RetryMapping {
+                start_after: 60,
+                frequencies: vec![(300, 4)],
+            },
+        );
+        assert_eq!(
+            get_outgoing_webhook_retry_window(mapping, &merchant_id),
+            time::Duration::minutes(21)
+        );
+    }
 }
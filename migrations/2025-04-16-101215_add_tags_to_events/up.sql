@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE events ADD COLUMN IF NOT EXISTS tags TEXT[];
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE events ADD COLUMN IF NOT EXISTS ingested_at TIMESTAMP;
+
+-- The default is set separately, so that events persisted before the column was added are not
+-- backfilled with the time of the migration
+ALTER TABLE events ALTER COLUMN ingested_at SET DEFAULT now();